@@ -6,25 +6,8 @@ use borsh::{BorshDeserialize, BorshSerialize};
 // -----------------------------------------------------------------------------
 // Custom types
 // -----------------------------------------------------------------------------
-#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
-pub enum Side {
-    Bid,
-    Ask,
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
-pub enum OrderType {
-    Limit,
-    ImmediateOrCancel,
-    PostOnly,
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
-pub enum SelfTradeBehavior {
-    DecrementTake,
-    CancelProvide,
-    AbortTransaction,
-}
+// Same order semantics as Serum, Borsh-encoded here with a `u8` tag.
+pub use crate::serum::instructions::{OrderType, SelfTradeBehavior, Side};
 
 // -----------------------------------------------------------------------------
 // Discriminators
@@ -1,5 +1,6 @@
 //! Boop on-chain events.
 
+use crate::common::anchor::split_event;
 use crate::common::ParseError;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
@@ -234,13 +235,7 @@ impl<'a> TryFrom<&'a [u8]> for BoopEvent {
     type Error = ParseError;
 
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-
-        let (disc, payload) = data.split_at(8);
-        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
-
+        let (discriminator, payload) = split_event(data)?;
         Ok(match discriminator {
             AUTHORITY_TRANSFER_CANCELLED_EVENT_EVENT => Self::AuthorityTransferCancelledEvent,
             AUTHORITY_TRANSFER_COMPLETED_EVENT_EVENT => Self::AuthorityTransferCompletedEvent(AuthorityTransferCompletedEvent::try_from_slice(payload)?),
@@ -41,21 +41,9 @@ pub struct SwapEvent {
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for ByrealClmmEvent {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-        let (disc, payload) = data.split_at(8);
-        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
-        Ok(match discriminator {
-            SWAP_EVENT => Self::SwapEvent(SwapEvent::try_from_slice(payload)?),
-            other => return Err(ParseError::Unknown(other)),
-        })
-    }
-}
+anchor_events!(ByrealClmmEvent {
+    SWAP_EVENT => SwapEvent(SwapEvent),
+});
 
 /// Convenience wrapper that forwards to `TryFrom`.
 pub fn unpack(data: &[u8]) -> Result<ByrealClmmEvent, ParseError> {
@@ -0,0 +1,62 @@
+//! Anchor event framing.
+//!
+//! Anchor programs emit an event in one of two forms:
+//!
+//! * `emit!` writes a `Program data:` log line whose base64 payload is
+//!   `discriminator || borsh(event)`;
+//! * `emit_cpi!` invokes the program itself with instruction data
+//!   `EVENT_IX_TAG || discriminator || borsh(event)`.
+//!
+//! [`split_event`] accepts both, so an event decoder only has to match on the
+//! discriminator. `anchor_events!` builds that match from a table.
+
+use super::ParseError;
+
+/// Instruction discriminator of Anchor's self-CPI event instruction, the
+/// little-endian `u64` `0x1d9acb512ea545e4`.
+pub const EVENT_IX_TAG: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
+
+/// Whether `data` is the instruction data of an Anchor self-CPI event.
+pub fn is_event_cpi(data: &[u8]) -> bool {
+    data.len() >= 16 && data[..8] == EVENT_IX_TAG
+}
+
+/// Splits an event into its 8-byte discriminator and Borsh payload, stripping
+/// the self-CPI envelope when present.
+pub fn split_event(data: &[u8]) -> Result<([u8; 8], &[u8]), ParseError> {
+    let data = if is_event_cpi(data) { &data[8..] } else { data };
+    if data.len() < 8 {
+        return Err(ParseError::TooShort(data.len()));
+    }
+    let (disc, payload) = data.split_at(8);
+    Ok((disc.try_into().expect("slice len 8"), payload))
+}
+
+// -----------------------------------------------------------------------------
+// Helper macro for event enums keyed by an Anchor discriminator
+// -----------------------------------------------------------------------------
+/// Implements `TryFrom<&[u8]>` for an event enum from its discriminator table.
+///
+/// Unknown discriminators are a [`ParseError::Unknown`] unless the table ends
+/// with `_ => Variant`, in which case they decode to that unit variant.
+macro_rules! anchor_events {
+    (@impl $event:ident, { $( $disc:ident => $variant:ident($ty:ty), )+ } $other:pat => $fallback:expr) => {
+        impl<'a> TryFrom<&'a [u8]> for $event {
+            type Error = $crate::common::ParseError;
+
+            fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+                let (disc, payload) = $crate::common::anchor::split_event(data)?;
+                Ok(match disc {
+                    $( $disc => Self::$variant(<$ty as ::borsh::BorshDeserialize>::try_from_slice(payload)?), )+
+                    $other => $fallback,
+                })
+            }
+        }
+    };
+    ($event:ident { $( $disc:ident => $variant:ident($ty:ty) ),+ $(,)? }) => {
+        anchor_events!(@impl $event, { $( $disc => $variant($ty), )+ } other => return Err($crate::common::ParseError::Unknown(other)));
+    };
+    ($event:ident { $( $disc:ident => $variant:ident($ty:ty), )+ _ => $unknown:ident $(,)? }) => {
+        anchor_events!(@impl $event, { $( $disc => $variant($ty), )+ } _ => Self::$unknown);
+    };
+}
@@ -0,0 +1,157 @@
+//! Minimal reader for the bincode layout used by the native programs.
+//!
+//! Native (non-Anchor) programs serialize their instruction data with bincode
+//! rather than Borsh: enum tags are `u32`, and `String`/`Vec` lengths are `u64`.
+//! Fixed-size fields (integers, `bool`, `Pubkey`) share the same layout.
+
+use borsh::io::{Error, ErrorKind};
+use solana_program::pubkey::Pubkey;
+
+use super::ParseError;
+
+/// Forward-only cursor over a bincode-encoded payload.
+#[derive(Debug, Clone)]
+pub struct BincodeReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> BincodeReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    /// Bytes not consumed yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.data[self.offset..]
+    }
+
+    pub fn take(&mut self, len: usize) -> Result<&'a [u8], ParseError> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or(ParseError::TooShort(self.data.len()))?;
+        let bytes = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    pub fn array<const N: usize>(&mut self) -> Result<[u8; N], ParseError> {
+        Ok(self.take(N)?.try_into().expect("slice len N"))
+    }
+
+    pub fn u8(&mut self) -> Result<u8, ParseError> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub fn bool(&mut self) -> Result<bool, ParseError> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            other => Err(invalid(format!("invalid bool {other}"))),
+        }
+    }
+
+    pub fn u16(&mut self) -> Result<u16, ParseError> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    pub fn u32(&mut self) -> Result<u32, ParseError> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    pub fn u64(&mut self) -> Result<u64, ParseError> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    pub fn u128(&mut self) -> Result<u128, ParseError> {
+        Ok(u128::from_le_bytes(self.array()?))
+    }
+
+    pub fn i64(&mut self) -> Result<i64, ParseError> {
+        Ok(i64::from_le_bytes(self.array()?))
+    }
+
+    pub fn pubkey(&mut self) -> Result<Pubkey, ParseError> {
+        Ok(Pubkey::new_from_array(self.array()?))
+    }
+
+    /// `u64` length-prefixed UTF-8 string.
+    pub fn string(&mut self) -> Result<String, ParseError> {
+        let len = self.len()?;
+        let bytes = self.take(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|e| invalid(e.to_string()))
+    }
+
+    /// `u8` tag followed by the value when the tag is `1`.
+    pub fn option<T>(&mut self, read: impl FnOnce(&mut Self) -> Result<T, ParseError>) -> Result<Option<T>, ParseError> {
+        match self.u8()? {
+            0 => Ok(None),
+            1 => read(self).map(Some),
+            other => Err(invalid(format!("invalid option tag {other}"))),
+        }
+    }
+
+    /// `u64` length-prefixed sequence.
+    pub fn vec<T>(&mut self, mut read: impl FnMut(&mut Self) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        let len = self.len()?;
+        // Every element takes at least one byte; cap the allocation by what's left.
+        let mut items = Vec::with_capacity(len.min(self.remaining().len()));
+        for _ in 0..len {
+            items.push(read(self)?);
+        }
+        Ok(items)
+    }
+
+    /// `short_vec` sequence: compact-u16 length followed by the elements.
+    pub fn short_vec<T>(&mut self, mut read: impl FnMut(&mut Self) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        let len = self.compact_u16()? as usize;
+        let mut items = Vec::with_capacity(len.min(self.remaining().len()));
+        for _ in 0..len {
+            items.push(read(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Compact-u16: 7 bits per byte, little-endian, at most 3 bytes.
+    pub fn compact_u16(&mut self) -> Result<u16, ParseError> {
+        let mut value = 0u32;
+        for i in 0..3 {
+            let byte = self.u8()?;
+            value |= u32::from(byte & 0x7f) << (7 * i);
+            if byte & 0x80 == 0 {
+                return u16::try_from(value).map_err(|_| invalid(format!("compact-u16 {value} overflows u16")));
+            }
+        }
+        Err(invalid("compact-u16 longer than 3 bytes".to_string()))
+    }
+
+    /// LEB128-style variable-length `u64` (`serde_varint`).
+    pub fn varint_u64(&mut self) -> Result<u64, ParseError> {
+        let mut value = 0u64;
+        let mut shift = 0u32;
+        loop {
+            let byte = self.u8()?;
+            let bits = u64::from(byte & 0x7f);
+            if shift >= 64 || (shift == 63 && bits > 1) {
+                return Err(invalid("varint overflows u64".to_string()));
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            shift += 7;
+        }
+    }
+
+    fn len(&mut self) -> Result<usize, ParseError> {
+        let len = self.u64()?;
+        usize::try_from(len).map_err(|_| invalid(format!("length {len} overflows usize")))
+    }
+}
+
+/// Wraps a malformed-payload message into [`ParseError::Decode`].
+pub fn invalid(msg: String) -> ParseError {
+    ParseError::Decode(Error::new(ErrorKind::InvalidData, msg))
+}
@@ -1,6 +1,9 @@
 #![allow(deprecated)]
 #[macro_use]
 pub mod accounts;
+#[macro_use]
+pub mod anchor;
+pub mod bincode;
 
 use thiserror::Error;
 
@@ -15,8 +18,6 @@ pub enum ParseError {
     AnchorUnknown([u8; 8]),
     #[error("unknown Raydium discriminator {0:?}")]
     RaydiumUnknown(u8),
-    #[error("unknown Pump.fun discriminator {0:?}")]
-    PumpFunUnknown([u8; 8]),
     #[error("unknown SPL discriminator {0}")]
     SplUnknown(u8),
     #[error("invalid payload length: expected {expected} bytes, got {got}")]
@@ -25,6 +26,8 @@ pub enum ParseError {
     TokenMetadataUnknown(u8),
     #[error("unknown Token Metadata V1 sub-discriminator {0}")]
     TokenMetadataSubdiscriminatorUnknown(u8),
+    #[error("no decoder registered for program {0:?}")]
+    UnknownProgram([u8; 32]),
     #[error("Borsh decode error: {0}")]
     Decode(#[from] borsh::io::Error),
 }
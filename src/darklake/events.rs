@@ -202,29 +202,15 @@ pub enum DarklakeEvent {
     Unknown,
 }
 
-impl<'a> TryFrom<&'a [u8]> for DarklakeEvent {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-
-        let (disc, payload) = data.split_at(8);
-        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
-
-        Ok(match discriminator {
-            ADD_LIQUIDITY_EVENT => Self::AddLiquidity(AddLiquidityEvent::try_from_slice(payload)?),
-            CANCEL_EVENT => Self::Cancel(CancelEvent::try_from_slice(payload)?),
-            INITIALIZE_POOL_EVENT => Self::InitializePool(InitializePoolEvent::try_from_slice(payload)?),
-            REMOVE_LIQUIDITY_EVENT => Self::RemoveLiquidity(RemoveLiquidityEvent::try_from_slice(payload)?),
-            SETTLE_EVENT => Self::Settle(SettleEvent::try_from_slice(payload)?),
-            SLASH_EVENT => Self::Slash(SlashEvent::try_from_slice(payload)?),
-            SWAP_EVENT => Self::Swap(SwapEvent::try_from_slice(payload)?),
-            other => return Err(ParseError::Unknown(other)),
-        })
-    }
-}
+anchor_events!(DarklakeEvent {
+    ADD_LIQUIDITY_EVENT => AddLiquidity(AddLiquidityEvent),
+    CANCEL_EVENT => Cancel(CancelEvent),
+    INITIALIZE_POOL_EVENT => InitializePool(InitializePoolEvent),
+    REMOVE_LIQUIDITY_EVENT => RemoveLiquidity(RemoveLiquidityEvent),
+    SETTLE_EVENT => Settle(SettleEvent),
+    SLASH_EVENT => Slash(SlashEvent),
+    SWAP_EVENT => Swap(SwapEvent),
+});
 
 pub fn unpack_event(data: &[u8]) -> Result<DarklakeEvent, ParseError> {
     DarklakeEvent::try_from(data)
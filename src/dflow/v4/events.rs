@@ -42,24 +42,11 @@ pub struct SwapEvent {
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for DflowV4Event {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-
-        let (disc, payload) = data.split_at(8);
-        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
-
-        Ok(match discriminator {
-            FEE => Self::Fee(FeeEvent::try_from_slice(payload)?),
-            SWAP => Self::Swap(SwapEvent::try_from_slice(payload)?),
-            _ => Self::Unknown,
-        })
-    }
-}
+anchor_events!(DflowV4Event {
+    FEE => Fee(FeeEvent),
+    SWAP => Swap(SwapEvent),
+    _ => Unknown,
+});
 
 /// Convenience wrapper that forwards to `TryFrom`.
 pub fn unpack(data: &[u8]) -> Result<DflowV4Event, ParseError> {
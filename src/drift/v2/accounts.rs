@@ -40,3 +40,88 @@ accounts!(
         instructions
     }
 );
+
+accounts!(
+    DepositAccounts,
+    get_deposit_accounts,
+    {
+        state,
+        user,
+        user_stats,
+        authority,
+        spot_market_vault,
+        user_token_account,
+        token_program
+    }
+);
+
+accounts!(
+    WithdrawAccounts,
+    get_withdraw_accounts,
+    {
+        state,
+        user,
+        user_stats,
+        authority,
+        spot_market_vault,
+        drift_signer,
+        user_token_account,
+        token_program
+    }
+);
+
+accounts!(
+    PlacePerpOrderAccounts,
+    get_place_perp_order_accounts,
+    {
+        state,
+        user,
+        authority
+    }
+);
+
+accounts!(
+    PlaceSpotOrderAccounts,
+    get_place_spot_order_accounts,
+    {
+        state,
+        user,
+        authority
+    }
+);
+
+accounts!(
+    PlaceOrdersAccounts,
+    get_place_orders_accounts,
+    {
+        state,
+        user,
+        authority
+    }
+);
+
+accounts!(
+    FillPerpOrderAccounts,
+    get_fill_perp_order_accounts,
+    {
+        state,
+        authority,
+        filler,
+        filler_stats,
+        user,
+        user_stats
+    }
+);
+
+accounts!(
+    LiquidatePerpAccounts,
+    get_liquidate_perp_accounts,
+    {
+        state,
+        authority,
+        liquidator,
+        liquidator_stats,
+        user,
+        user_stats
+    }
+);
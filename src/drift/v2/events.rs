@@ -4,11 +4,20 @@ use crate::common::ParseError;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
+pub use super::instructions::{MarketType, OrderTriggerCondition, OrderType, PositionDirection};
+
 // -----------------------------------------------------------------------------
 // Discriminators
 // -----------------------------------------------------------------------------
 pub const SPOT_INTEREST_RECORD: [u8; 8] = [183, 186, 203, 186, 225, 187, 95, 130];
 pub const SWAP_RECORD: [u8; 8] = [162, 187, 123, 194, 138, 56, 250, 241];
+pub const NEW_USER_RECORD: [u8; 8] = [236, 186, 113, 219, 42, 51, 149, 249];
+pub const DEPOSIT_RECORD: [u8; 8] = [180, 241, 218, 207, 102, 135, 44, 134];
+pub const FUNDING_RATE_RECORD: [u8; 8] = [68, 3, 255, 26, 133, 91, 147, 254];
+pub const ORDER_RECORD: [u8; 8] = [104, 19, 64, 56, 89, 21, 2, 90];
+pub const ORDER_ACTION_RECORD: [u8; 8] = [224, 52, 67, 71, 194, 237, 109, 1];
+pub const LIQUIDATION_RECORD: [u8; 8] = [127, 17, 0, 108, 182, 13, 231, 53];
+pub const SETTLE_PNL_RECORD: [u8; 8] = [57, 68, 105, 26, 119, 198, 213, 89];
 
 // -----------------------------------------------------------------------------
 // Event enumeration
@@ -17,9 +26,227 @@ pub const SWAP_RECORD: [u8; 8] = [162, 187, 123, 194, 138, 56, 250, 241];
 pub enum DriftEvent {
     SpotInterestRecord(SpotInterestRecord),
     SwapRecord(SwapRecord),
+    NewUserRecord(NewUserRecord),
+    DepositRecord(DepositRecord),
+    FundingRateRecord(FundingRateRecord),
+    OrderRecord(OrderRecord),
+    OrderActionRecord(Box<OrderActionRecord>),
+    LiquidationRecord(Box<LiquidationRecord>),
+    SettlePnlRecord(SettlePnlRecord),
     Unknown,
 }
 
+// -----------------------------------------------------------------------------
+// Custom types
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum OrderAction {
+    Place,
+    Cancel,
+    Fill,
+    Trigger,
+    Expire,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum OrderActionExplanation {
+    None,
+    InsufficientFreeCollateral,
+    OraclePriceBreachedLimitPrice,
+    MarketOrderFilledToLimitPrice,
+    OrderExpired,
+    Liquidation,
+    OrderFilledWithAMM,
+    OrderFilledWithAMMJit,
+    OrderFilledWithMatch,
+    OrderFilledWithMatchJit,
+    MarketExpired,
+    RiskingIncreasingOrder,
+    ReduceOnlyOrderIncreasedPosition,
+    OrderFillWithSerum,
+    NoBorrowLiquidity,
+    OrderFillWithPhoenix,
+    OrderFilledWithAMMJitLPSplit,
+    OrderFilledWithLPJit,
+    DeriskLp,
+    OrderFilledWithOpenbookV2,
+    TransferPerpPosition,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum OrderStatus {
+    Init,
+    Open,
+    Filled,
+    Canceled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum DepositDirection {
+    Deposit,
+    Withdraw,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum DepositExplanation {
+    None,
+    Transfer,
+    Borrow,
+    RepayBorrow,
+    Reward,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum LiquidationType {
+    LiquidatePerp,
+    LiquidateSpot,
+    LiquidateBorrowForPerpPnl,
+    LiquidatePerpPnlForDeposit,
+    PerpBankruptcy,
+    SpotBankruptcy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum SettlePnlExplanation {
+    None,
+    ExpiredPosition,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Order {
+    /// The slot the order was placed
+    pub slot: u64,
+    /// The limit price for the order (can be 0 for market orders)
+    /// For orders with an auction, this price isn't used until the auction is complete
+    /// precision: PRICE_PRECISION
+    pub price: u64,
+    /// The size of the order
+    /// precision for perps: BASE_PRECISION
+    /// precision for spot: token mint precision
+    pub base_asset_amount: u64,
+    /// The amount of the order filled
+    /// precision for perps: BASE_PRECISION
+    /// precision for spot: token mint precision
+    pub base_asset_amount_filled: u64,
+    /// The amount of quote filled for the order
+    /// precision: QUOTE_PRECISION
+    pub quote_asset_amount_filled: u64,
+    /// At what price the order will be triggered. Only relevant for trigger orders
+    /// precision: PRICE_PRECISION
+    pub trigger_price: u64,
+    /// The start price for the auction. Only relevant for market/oracle orders
+    /// precision: PRICE_PRECISION
+    pub auction_start_price: i64,
+    /// The end price for the auction. Only relevant for market/oracle orders
+    /// precision: PRICE_PRECISION
+    pub auction_end_price: i64,
+    /// The time when the order will expire
+    pub max_ts: i64,
+    /// If set, the order limit price is the oracle price + this offset
+    /// precision: PRICE_PRECISION
+    pub oracle_price_offset: i32,
+    /// The id for the order. Each users has their own order id space
+    pub order_id: u32,
+    /// The perp/spot market index
+    pub market_index: u16,
+    /// Whether the order is open or unused
+    pub status: OrderStatus,
+    /// The type of order
+    pub order_type: OrderType,
+    /// Whether market is spot or perp
+    pub market_type: MarketType,
+    /// User generated order id. Can make it easier to place/cancel orders
+    pub user_order_id: u8,
+    /// What the users position was when the order was placed
+    pub existing_position_direction: PositionDirection,
+    /// Whether the user is going long or short. LONG = bid, SHORT = ask
+    pub direction: PositionDirection,
+    /// Whether the order is allowed to only reduce position size
+    pub reduce_only: bool,
+    /// Whether the order must be a maker
+    pub post_only: bool,
+    /// Whether the order must be canceled the same slot it is placed
+    pub immediate_or_cancel: bool,
+    /// Whether the order is triggered above or below the trigger price. Only relevant for trigger orders
+    pub trigger_condition: OrderTriggerCondition,
+    /// How many slots the auction lasts
+    pub auction_duration: u8,
+    /// Last 8 bits of the slot the order was posted on-chain (not order slot for signed msg orders)
+    pub posted_slot_tail: u8,
+    /// Bitflags for further classification
+    /// 0: is_signed_message
+    pub bit_flags: u8,
+    pub padding: [u8; 1],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct LiquidatePerpRecord {
+    pub market_index: u16,
+    pub oracle_price: i64,
+    pub base_asset_amount: i64,
+    pub quote_asset_amount: i64,
+    /// precision: AMM_RESERVE_PRECISION
+    pub lp_shares: u64,
+    pub fill_record_id: u64,
+    pub user_order_id: u32,
+    pub liquidator_order_id: u32,
+    /// precision: QUOTE_PRECISION
+    pub liquidator_fee: u64,
+    /// precision: QUOTE_PRECISION
+    pub if_fee: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct LiquidateSpotRecord {
+    pub asset_market_index: u16,
+    pub asset_price: i64,
+    pub asset_transfer: u128,
+    pub liability_market_index: u16,
+    pub liability_price: i64,
+    /// precision: token mint precision
+    pub liability_transfer: u128,
+    /// precision: token mint precision
+    pub if_fee: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct LiquidateBorrowForPerpPnlRecord {
+    pub perp_market_index: u16,
+    pub market_oracle_price: i64,
+    pub pnl_transfer: u128,
+    pub liability_market_index: u16,
+    pub liability_price: i64,
+    pub liability_transfer: u128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct LiquidatePerpPnlForDepositRecord {
+    pub perp_market_index: u16,
+    pub market_oracle_price: i64,
+    pub pnl_transfer: u128,
+    pub asset_market_index: u16,
+    pub asset_price: i64,
+    pub asset_transfer: u128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PerpBankruptcyRecord {
+    pub market_index: u16,
+    pub pnl: i128,
+    pub if_payment: u128,
+    pub clawback_user: Option<Pubkey>,
+    pub clawback_user_payment: Option<u128>,
+    pub cumulative_funding_rate_delta: i128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct SpotBankruptcyRecord {
+    pub market_index: u16,
+    pub borrow_amount: u128,
+    pub if_payment: u128,
+    pub cumulative_deposit_interest_delta: u128,
+}
+
 // -----------------------------------------------------------------------------
 // Payload structs
 // -----------------------------------------------------------------------------
@@ -49,28 +276,147 @@ pub struct SwapRecord {
     pub fee: u64,
 }
 
-// -----------------------------------------------------------------------------
-// Borsh deserialisation helper
-// -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for DriftEvent {
-    type Error = ParseError;
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct DepositRecord {
+    pub ts: i64,
+    pub user_authority: Pubkey,
+    pub user: Pubkey,
+    pub direction: DepositDirection,
+    pub deposit_record_id: u64,
+    pub amount: u64,
+    pub market_index: u16,
+    pub oracle_price: i64,
+    pub market_deposit_balance: u128,
+    pub market_withdraw_balance: u128,
+    pub market_cumulative_deposit_interest: u128,
+    pub market_cumulative_borrow_interest: u128,
+    pub total_deposits_after: u64,
+    pub total_withdraws_after: u64,
+    pub explanation: DepositExplanation,
+    pub transfer_user: Option<Pubkey>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FundingRateRecord {
+    pub ts: i64,
+    pub record_id: u64,
+    pub market_index: u16,
+    pub funding_rate: i64,
+    pub funding_rate_long: i128,
+    pub funding_rate_short: i128,
+    pub cumulative_funding_rate_long: i128,
+    pub cumulative_funding_rate_short: i128,
+    pub oracle_price_twap: i64,
+    pub mark_price_twap: u64,
+    pub period_revenue: i64,
+    pub base_asset_amount_with_amm: i128,
+    pub base_asset_amount_with_unsettled_lp: i128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct LiquidationRecord {
+    pub ts: i64,
+    pub liquidation_type: LiquidationType,
+    pub user: Pubkey,
+    pub liquidator: Pubkey,
+    pub margin_requirement: u128,
+    pub total_collateral: i128,
+    pub margin_freed: u64,
+    pub liquidation_id: u16,
+    pub bankrupt: bool,
+    pub canceled_order_ids: Vec<u32>,
+    pub liquidate_perp: LiquidatePerpRecord,
+    pub liquidate_spot: LiquidateSpotRecord,
+    pub liquidate_borrow_for_perp_pnl: LiquidateBorrowForPerpPnlRecord,
+    pub liquidate_perp_pnl_for_deposit: LiquidatePerpPnlForDepositRecord,
+    pub perp_bankruptcy: PerpBankruptcyRecord,
+    pub spot_bankruptcy: SpotBankruptcyRecord,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct NewUserRecord {
+    pub ts: i64,
+    pub user_authority: Pubkey,
+    pub user: Pubkey,
+    pub sub_account_id: u16,
+    pub name: [u8; 32],
+    pub referrer: Pubkey,
+}
 
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct OrderActionRecord {
+    pub ts: i64,
+    pub action: OrderAction,
+    pub action_explanation: OrderActionExplanation,
+    pub market_index: u16,
+    pub market_type: MarketType,
+    pub filler: Option<Pubkey>,
+    pub filler_reward: Option<u64>,
+    pub fill_record_id: Option<u64>,
+    pub base_asset_amount_filled: Option<u64>,
+    pub quote_asset_amount_filled: Option<u64>,
+    pub taker_fee: Option<u64>,
+    pub maker_fee: Option<i64>,
+    pub referrer_reward: Option<u32>,
+    pub quote_asset_amount_surplus: Option<i64>,
+    pub spot_fulfillment_method_fee: Option<u64>,
+    pub taker: Option<Pubkey>,
+    pub taker_order_id: Option<u32>,
+    pub taker_order_direction: Option<PositionDirection>,
+    pub taker_order_base_asset_amount: Option<u64>,
+    pub taker_order_cumulative_base_asset_amount_filled: Option<u64>,
+    pub taker_order_cumulative_quote_asset_amount_filled: Option<u64>,
+    pub maker: Option<Pubkey>,
+    pub maker_order_id: Option<u32>,
+    pub maker_order_direction: Option<PositionDirection>,
+    pub maker_order_base_asset_amount: Option<u64>,
+    pub maker_order_cumulative_base_asset_amount_filled: Option<u64>,
+    pub maker_order_cumulative_quote_asset_amount_filled: Option<u64>,
+    pub oracle_price: i64,
+    pub bit_flags: u8,
+    pub taker_existing_quote_entry_amount: Option<u64>,
+    pub taker_existing_base_asset_amount: Option<u64>,
+    pub maker_existing_quote_entry_amount: Option<u64>,
+    pub maker_existing_base_asset_amount: Option<u64>,
+    pub trigger_price: Option<u64>,
+}
 
-        let (disc, payload) = data.split_at(8);
-        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct OrderRecord {
+    pub ts: i64,
+    pub user: Pubkey,
+    pub order: Order,
+}
 
-        Ok(match discriminator {
-            SPOT_INTEREST_RECORD => Self::SpotInterestRecord(SpotInterestRecord::try_from_slice(payload)?),
-            SWAP_RECORD => Self::SwapRecord(SwapRecord::try_from_slice(payload)?),
-            _ => Self::Unknown,
-        })
-    }
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct SettlePnlRecord {
+    pub ts: i64,
+    pub user: Pubkey,
+    pub market_index: u16,
+    pub pnl: i128,
+    pub base_asset_amount: i64,
+    pub quote_asset_amount_after: i64,
+    pub quote_entry_amount: i64,
+    pub settle_price: i64,
+    pub explanation: SettlePnlExplanation,
 }
 
+// -----------------------------------------------------------------------------
+// Borsh deserialisation helper
+// -----------------------------------------------------------------------------
+anchor_events!(DriftEvent {
+    SPOT_INTEREST_RECORD => SpotInterestRecord(SpotInterestRecord),
+    SWAP_RECORD => SwapRecord(SwapRecord),
+    NEW_USER_RECORD => NewUserRecord(NewUserRecord),
+    DEPOSIT_RECORD => DepositRecord(DepositRecord),
+    FUNDING_RATE_RECORD => FundingRateRecord(FundingRateRecord),
+    ORDER_RECORD => OrderRecord(OrderRecord),
+    ORDER_ACTION_RECORD => OrderActionRecord(Box<OrderActionRecord>),
+    LIQUIDATION_RECORD => LiquidationRecord(Box<LiquidationRecord>),
+    SETTLE_PNL_RECORD => SettlePnlRecord(SettlePnlRecord),
+    _ => Unknown,
+});
+
 /// Convenience wrapper that forwards to `TryFrom`.
 pub fn unpack(data: &[u8]) -> Result<DriftEvent, ParseError> {
     DriftEvent::try_from(data)
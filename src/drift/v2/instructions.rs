@@ -12,6 +12,64 @@ pub enum SwapReduceOnly {
     Out,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum OrderType {
+    Market,
+    Limit,
+    TriggerMarket,
+    TriggerLimit,
+    Oracle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum MarketType {
+    Spot,
+    Perp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum PositionDirection {
+    Long,
+    Short,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum OrderTriggerCondition {
+    Above,
+    Below,
+    TriggeredAbove,
+    TriggeredBelow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum PostOnlyParam {
+    None,
+    MustPostOnly,
+    TryPostOnly,
+    Slide,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct OrderParams {
+    pub order_type: OrderType,
+    pub market_type: MarketType,
+    pub direction: PositionDirection,
+    pub user_order_id: u8,
+    pub base_asset_amount: u64,
+    pub price: u64,
+    pub market_index: u16,
+    pub reduce_only: bool,
+    pub post_only: PostOnlyParam,
+    pub bit_flags: u8,
+    pub max_ts: Option<i64>,
+    pub trigger_price: Option<u64>,
+    pub trigger_condition: OrderTriggerCondition,
+    pub oracle_price_offset: Option<i32>,
+    pub auction_duration: Option<u8>,
+    pub auction_start_price: Option<i64>,
+    pub auction_end_price: Option<i64>,
+}
+
 // -----------------------------------------------------------------------------
 // Payload structs
 // -----------------------------------------------------------------------------
@@ -30,11 +88,61 @@ pub struct EndSwapInstruction {
     pub reduce_only: Option<SwapReduceOnly>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct DepositInstruction {
+    pub market_index: u16,
+    pub amount: u64,
+    pub reduce_only: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct WithdrawInstruction {
+    pub market_index: u16,
+    pub amount: u64,
+    pub reduce_only: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PlacePerpOrderInstruction {
+    pub params: OrderParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PlaceSpotOrderInstruction {
+    pub params: OrderParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PlaceOrdersInstruction {
+    pub params: Vec<OrderParams>,
+}
+
+/// The taker and makers are passed as remaining accounts.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FillPerpOrderInstruction {
+    pub order_id: Option<u32>,
+    pub maker_order_id: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct LiquidatePerpInstruction {
+    pub market_index: u16,
+    pub liquidator_max_base_asset_amount: u64,
+    pub limit_price: Option<u64>,
+}
+
 // -----------------------------------------------------------------------------
 // Discriminators
 // -----------------------------------------------------------------------------
 pub const BEGIN_SWAP: [u8; 8] = [174, 109, 228, 1, 242, 105, 232, 105];
 pub const END_SWAP: [u8; 8] = [177, 184, 27, 193, 34, 13, 210, 145];
+pub const DEPOSIT: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
+pub const WITHDRAW: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
+pub const PLACE_PERP_ORDER: [u8; 8] = [69, 161, 93, 202, 120, 126, 76, 185];
+pub const PLACE_SPOT_ORDER: [u8; 8] = [45, 79, 81, 160, 248, 90, 91, 220];
+pub const PLACE_ORDERS: [u8; 8] = [60, 63, 50, 123, 12, 197, 60, 190];
+pub const FILL_PERP_ORDER: [u8; 8] = [13, 188, 248, 103, 134, 217, 106, 240];
+pub const LIQUIDATE_PERP: [u8; 8] = [75, 35, 119, 247, 191, 18, 139, 2];
 
 // -----------------------------------------------------------------------------
 // Instruction enumeration
@@ -43,6 +151,13 @@ pub const END_SWAP: [u8; 8] = [177, 184, 27, 193, 34, 13, 210, 145];
 pub enum DriftInstruction {
     BeginSwap(BeginSwapInstruction),
     EndSwap(EndSwapInstruction),
+    Deposit(DepositInstruction),
+    Withdraw(WithdrawInstruction),
+    PlacePerpOrder(PlacePerpOrderInstruction),
+    PlaceSpotOrder(PlaceSpotOrderInstruction),
+    PlaceOrders(PlaceOrdersInstruction),
+    FillPerpOrder(FillPerpOrderInstruction),
+    LiquidatePerp(LiquidatePerpInstruction),
     Unknown,
 }
 
@@ -63,6 +178,13 @@ impl<'a> TryFrom<&'a [u8]> for DriftInstruction {
         Ok(match discriminator {
             BEGIN_SWAP => Self::BeginSwap(BeginSwapInstruction::try_from_slice(payload)?),
             END_SWAP => Self::EndSwap(EndSwapInstruction::try_from_slice(payload)?),
+            DEPOSIT => Self::Deposit(DepositInstruction::try_from_slice(payload)?),
+            WITHDRAW => Self::Withdraw(WithdrawInstruction::try_from_slice(payload)?),
+            PLACE_PERP_ORDER => Self::PlacePerpOrder(PlacePerpOrderInstruction::try_from_slice(payload)?),
+            PLACE_SPOT_ORDER => Self::PlaceSpotOrder(PlaceSpotOrderInstruction::try_from_slice(payload)?),
+            PLACE_ORDERS => Self::PlaceOrders(PlaceOrdersInstruction::try_from_slice(payload)?),
+            FILL_PERP_ORDER => Self::FillPerpOrder(FillPerpOrderInstruction::try_from_slice(payload)?),
+            LIQUIDATE_PERP => Self::LiquidatePerp(LiquidatePerpInstruction::try_from_slice(payload)?),
             other => return Err(ParseError::Unknown(other)),
         })
     }
@@ -57,26 +57,12 @@ pub enum DumpfunEvent {
     Unknown,
 }
 
-impl<'a> TryFrom<&'a [u8]> for DumpfunEvent {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-
-        let (disc, payload) = data.split_at(8);
-        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
-
-        Ok(match discriminator {
-            BUY_TOKEN_EVENT_EVENT => Self::BuyTokenEvent(BuyTokenEvent::try_from_slice(payload)?),
-            DRAIN_POOL_EVENT_EVENT => Self::DrainPoolEvent(DrainPoolEvent::try_from_slice(payload)?),
-            SELL_TOKEN_EVENT_EVENT => Self::SellTokenEvent(SellTokenEvent::try_from_slice(payload)?),
-            TOKEN_CREATED_EVENT_EVENT => Self::TokenCreatedEvent(TokenCreatedEvent::try_from_slice(payload)?),
-            other => return Err(ParseError::Unknown(other)),
-        })
-    }
-}
+anchor_events!(DumpfunEvent {
+    BUY_TOKEN_EVENT_EVENT => BuyTokenEvent(BuyTokenEvent),
+    DRAIN_POOL_EVENT_EVENT => DrainPoolEvent(DrainPoolEvent),
+    SELL_TOKEN_EVENT_EVENT => SellTokenEvent(SellTokenEvent),
+    TOKEN_CREATED_EVENT_EVENT => TokenCreatedEvent(TokenCreatedEvent),
+});
 
 pub fn unpack_event(data: &[u8]) -> Result<DumpfunEvent, ParseError> {
     DumpfunEvent::try_from(data)
@@ -31,23 +31,9 @@ pub struct SellLog {
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for HeavenLog {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-
-        let (disc, payload) = data.split_at(8);
-        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
-
-        Ok(match discriminator {
-            SELL_LOG => Self::Sell(SellLog::try_from_slice(payload)?),
-            other => return Err(ParseError::Unknown(other)),
-        })
-    }
-}
+anchor_events!(HeavenLog {
+    SELL_LOG => Sell(SellLog),
+});
 
 /// Convenience wrapper that forwards to `TryFrom`.
 pub fn unpack(data: &[u8]) -> Result<HeavenLog, ParseError> {
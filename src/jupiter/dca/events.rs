@@ -96,26 +96,15 @@ pub struct DepositEvent {
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for JupiterDcaEvent {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-        let (disc, payload) = data.split_at(8);
-        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
-        Ok(match discriminator {
-            COLLECTED_FEE => Self::CollectedFee(CollectedFeeEvent::try_from_slice(payload)?),
-            FILLED => Self::Filled(FilledEvent::try_from_slice(payload)?),
-            OPENED => Self::Opened(OpenedEvent::try_from_slice(payload)?),
-            CLOSED => Self::Closed(ClosedEvent::try_from_slice(payload)?),
-            WITHDRAW => Self::Withdraw(WithdrawEvent::try_from_slice(payload)?),
-            DEPOSIT => Self::Deposit(DepositEvent::try_from_slice(payload)?),
-            _ => Self::Unknown,
-        })
-    }
-}
+anchor_events!(JupiterDcaEvent {
+    COLLECTED_FEE => CollectedFee(CollectedFeeEvent),
+    FILLED => Filled(FilledEvent),
+    OPENED => Opened(OpenedEvent),
+    CLOSED => Closed(ClosedEvent),
+    WITHDRAW => Withdraw(WithdrawEvent),
+    DEPOSIT => Deposit(DepositEvent),
+    _ => Unknown,
+});
 
 /// Convenience wrapper that forwards to `TryFrom`.
 pub fn unpack(data: &[u8]) -> Result<JupiterDcaEvent, ParseError> {
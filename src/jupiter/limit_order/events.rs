@@ -45,22 +45,10 @@ pub struct CreateOrderEvent {
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for JupiterLimitOrderEvent {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 16 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-        let disc: [u8; 8] = data[0..8].try_into().expect("slice len 8");
-        let payload = &data[16..];
-        Ok(match disc {
-            CANCEL_ORDER_EVENT => Self::CancelOrder(CancelOrderEvent::try_from_slice(payload)?),
-            CREATE_ORDER_EVENT => Self::CreateOrder(CreateOrderEvent::try_from_slice(payload)?),
-            other => return Err(ParseError::Unknown(other)),
-        })
-    }
-}
+anchor_events!(JupiterLimitOrderEvent {
+    CANCEL_ORDER_EVENT => CancelOrder(CancelOrderEvent),
+    CREATE_ORDER_EVENT => CreateOrder(CreateOrderEvent),
+});
 
 /// Convenience wrapper that forwards to `TryFrom`.
 pub fn unpack(data: &[u8]) -> Result<JupiterLimitOrderEvent, ParseError> {
@@ -45,22 +45,10 @@ pub struct FeeEvent {
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for JupiterV4Event {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-        let disc: [u8; 8] = data[0..8].try_into().expect("slice len 8");
-        let payload = &data[8..];
-        Ok(match disc {
-            SWAP => Self::Swap(SwapEvent::try_from_slice(payload)?),
-            FEE => Self::Fee(FeeEvent::try_from_slice(payload)?),
-            other => return Err(ParseError::Unknown(other)),
-        })
-    }
-}
+anchor_events!(JupiterV4Event {
+    SWAP => Swap(SwapEvent),
+    FEE => Fee(FeeEvent),
+});
 
 /// Convenience wrapper that forwards to `TryFrom`.
 pub fn unpack(data: &[u8]) -> Result<JupiterV4Event, ParseError> {
@@ -7,9 +7,8 @@ use solana_program::pubkey::Pubkey;
 // -----------------------------------------------------------------------------
 // Discriminators (first 8 bytes of the emitted log’s data)
 // -----------------------------------------------------------------------------
-const SWAP: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29]; // e445a52e51cb9a1d
-const SWAP_LEN: usize = 112;
-const SWAP_FEE_LEN: usize = 72;
+const SWAP_EVENT: [u8; 8] = [64, 198, 205, 232, 38, 8, 113, 226]; // 40c6cde8260871e2
+const FEE_EVENT: [u8; 8] = [73, 79, 78, 127, 184, 213, 13, 220]; // 494f4e7fb8d50ddc
 
 // -----------------------------------------------------------------------------
 // High-level event enum (concise; rich docs live in each struct)
@@ -50,33 +49,10 @@ pub struct FeeEvent {
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for JupiterV6Event {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 16 {
-            // 8 bytes discriminator + 8 bytes Anchor discriminator
-            return Err(ParseError::TooShort(data.len()));
-        }
-
-        let disc: [u8; 8] = data[0..8].try_into().expect("slice len 8");
-        let payload = &data[16..]; // skip both discriminators
-
-        Ok(match disc {
-            SWAP => match payload.len() {
-                SWAP_LEN => Self::Swap(SwapEvent::try_from_slice(payload)?),
-                SWAP_FEE_LEN => Self::Fee(FeeEvent::try_from_slice(payload)?),
-                other => {
-                    return Err(ParseError::InvalidLength {
-                        expected: SWAP_LEN.max(SWAP_FEE_LEN),
-                        got: other,
-                    })
-                }
-            },
-            other => return Err(ParseError::Unknown(other)),
-        })
-    }
-}
+anchor_events!(JupiterV6Event {
+    SWAP_EVENT => Swap(SwapEvent),
+    FEE_EVENT => Fee(FeeEvent),
+});
 
 /// Convenience wrapper that forwards to `TryFrom`.
 pub fn unpack(data: &[u8]) -> Result<JupiterV6Event, ParseError> {
@@ -33,44 +33,293 @@ pub struct CreateTokenAccountInstruction {
 }
 
 /// Route by using program owned token accounts and open orders accounts.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct SharedAccountsExactOutRouteInstruction {
     pub id: u8,
-    /// Raw remaining instruction data (route plan and amounts).
-    pub data: Vec<u8>,
+    pub route_plan: Vec<RoutePlanStep>,
+    pub out_amount: u64,
+    pub quoted_in_amount: u64,
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
 }
 
 /// Route by using program owned token accounts and open orders accounts.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct SharedAccountsRouteInstruction {
     pub id: u8,
-    /// Raw remaining instruction data (route plan and amounts).
-    pub data: Vec<u8>,
+    pub route_plan: Vec<RoutePlanStep>,
+    pub in_amount: u64,
+    pub quoted_out_amount: u64,
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Input amount is read from the token ledger instead of instruction data.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct SharedAccountsRouteWithTokenLedgerInstruction {
     pub id: u8,
-    /// Raw remaining instruction data (route plan and amounts).
-    pub data: Vec<u8>,
+    pub route_plan: Vec<RoutePlanStep>,
+    pub quoted_out_amount: u64,
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
 }
 
 /// `route_plan` Topologically sorted trade DAG
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct RouteInstruction {
-    pub data: Vec<u8>,
+    pub route_plan: Vec<RoutePlanStep>,
+    pub in_amount: u64,
+    pub quoted_out_amount: u64,
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct ExactOutRouteInstruction {
-    /// Raw route plan and arguments.
-    pub data: Vec<u8>,
+    pub route_plan: Vec<RoutePlanStep>,
+    pub out_amount: u64,
+    pub quoted_in_amount: u64,
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Input amount is read from the token ledger instead of instruction data.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct RouteWithTokenLedgerInstruction {
-    /// Raw route plan and arguments.
-    pub data: Vec<u8>,
+    pub route_plan: Vec<RoutePlanStep>,
+    pub quoted_out_amount: u64,
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
+}
+
+// -----------------------------------------------------------------------------
+// Route plan types
+// -----------------------------------------------------------------------------
+
+/// One hop of the route plan.
+///
+/// `input_index`/`output_index` point into the list of intermediate token
+/// accounts; `percent` is the share of the input routed through this hop.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct RoutePlanStep {
+    pub swap: Swap,
+    pub percent: u8,
+    pub input_index: u8,
+    pub output_index: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum Side {
+    Bid,
+    Ask,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum AccountsType {
+    TransferHookA,
+    TransferHookB,
+    TransferHookReward,
+    TransferHookInput,
+    TransferHookIntermediate,
+    TransferHookOutput,
+    SupplementalTickArrays,
+    SupplementalTickArraysOne,
+    SupplementalTickArraysTwo,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct RemainingAccountsSlice {
+    pub accounts_type: AccountsType,
+    pub length: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct RemainingAccountsInfo {
+    pub slices: Vec<RemainingAccountsSlice>,
+}
+
+/// AMM venue (and direction, where relevant) used by a route plan step.
+///
+/// Variant order mirrors the on-chain enum; do not reorder.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum Swap {
+    Saber,
+    SaberAddDecimalsDeposit,
+    SaberAddDecimalsWithdraw,
+    TokenSwap,
+    Sencha,
+    Step,
+    Cropper,
+    Raydium,
+    Crema {
+        a_to_b: bool,
+    },
+    Lifinity,
+    Mercurial,
+    Cykura,
+    Serum {
+        side: Side,
+    },
+    MarinadeDeposit,
+    MarinadeUnstake,
+    Aldrin {
+        side: Side,
+    },
+    AldrinV2 {
+        side: Side,
+    },
+    Whirlpool {
+        a_to_b: bool,
+    },
+    Invariant {
+        x_to_y: bool,
+    },
+    Meteora,
+    GooseFX,
+    DeltaFi {
+        stable: bool,
+    },
+    Balansol,
+    MarcoPolo {
+        x_to_y: bool,
+    },
+    Dradex {
+        side: Side,
+    },
+    LifinityV2,
+    RaydiumClmm,
+    Openbook {
+        side: Side,
+    },
+    Phoenix {
+        side: Side,
+    },
+    Symmetry {
+        from_token_id: u64,
+        to_token_id: u64,
+    },
+    TokenSwapV2,
+    HeliumTreasuryManagementRedeemV0,
+    StakeDexStakeWrappedSol,
+    StakeDexSwapViaStake {
+        bridge_stake_seed: u32,
+    },
+    GooseFXV2,
+    Perps,
+    PerpsAddLiquidity,
+    PerpsRemoveLiquidity,
+    MeteoraDlmm,
+    OpenBookV2 {
+        side: Side,
+    },
+    RaydiumClmmV2,
+    StakeDexPrefundWithdrawStakeAndDepositStake {
+        bridge_stake_seed: u32,
+    },
+    Clone {
+        pool_index: u8,
+        quantity_is_input: bool,
+        quantity_is_collateral: bool,
+    },
+    SanctumS {
+        src_lst_value_calc_accs: u8,
+        dst_lst_value_calc_accs: u8,
+        src_lst_index: u32,
+        dst_lst_index: u32,
+    },
+    SanctumSAddLiquidity {
+        lst_value_calc_accs: u8,
+        lst_index: u32,
+    },
+    SanctumSRemoveLiquidity {
+        lst_value_calc_accs: u8,
+        lst_index: u32,
+    },
+    RaydiumCP,
+    WhirlpoolSwapV2 {
+        a_to_b: bool,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    },
+    OneIntro,
+    PumpWrappedBuy,
+    PumpWrappedSell,
+    PerpsV2,
+    PerpsV2AddLiquidity,
+    PerpsV2RemoveLiquidity,
+    MoonshotWrappedBuy,
+    MoonshotWrappedSell,
+    StabbleStableSwap,
+    StabbleWeightedSwap,
+    Obric {
+        x_to_y: bool,
+    },
+    FoxBuyFromEstimatedCost,
+    FoxClaimPartial {
+        is_y: bool,
+    },
+    SolFi {
+        is_quote_to_base: bool,
+    },
+    SolayerDelegateNoInit,
+    SolayerUndelegateNoInit,
+    TokenMill {
+        side: Side,
+    },
+    DaosFunBuy,
+    DaosFunSell,
+    ZeroFi,
+    StakeDexWithdrawWrappedSol,
+    VirtualsBuy,
+    VirtualsSell,
+    Perena {
+        in_index: u8,
+        out_index: u8,
+    },
+    PumpSwapBuy,
+    PumpSwapSell,
+    Gamma,
+    MeteoraDlmmSwapV2 {
+        remaining_accounts_info: RemainingAccountsInfo,
+    },
+    Woofi,
+    MeteoraDammV2,
+    MeteoraDynamicBondingCurveSwap,
+    StabbleStableSwapV2,
+    StabbleWeightedSwapV2,
+    RaydiumLaunchlabBuy {
+        share_fee_rate: u64,
+    },
+    RaydiumLaunchlabSell {
+        share_fee_rate: u64,
+    },
+    BoopdotfunWrappedBuy,
+    BoopdotfunWrappedSell,
+    Plasma {
+        side: Side,
+    },
+    GoonFi {
+        is_bid: bool,
+        blacklist_bump: u8,
+    },
+    HumidiFi {
+        swap_id: u64,
+        is_base_to_quote: bool,
+    },
+    MeteoraDynamicBondingCurveSwapWithRemainingAccounts,
+    TesseraV {
+        side: Side,
+    },
+    PumpWrappedBuyV2,
+    PumpWrappedSellV2,
+    PumpSwapBuyV2,
+    PumpSwapSellV2,
+    Heaven {
+        a_to_b: bool,
+    },
+    SolFiV2 {
+        is_quote_to_base: bool,
+    },
+    Aquifer,
 }
 
 // -----------------------------------------------------------------------------
@@ -136,33 +385,14 @@ impl<'a> TryFrom<&'a [u8]> for JupiterV6Instruction {
             CREATE_PROGRAM_OPEN_ORDERS => Self::CreateProgramOpenOrders(CreateProgramOpenOrdersInstruction::try_from_slice(payload)?),
             CREATE_TOKEN_LEDGER => Self::CreateTokenLedger,
             CREATE_TOKEN_ACCOUNT => Self::CreateTokenAccount(CreateTokenAccountInstruction::try_from_slice(payload)?),
-            EXACT_OUT_ROUTE => Self::ExactOutRoute(ExactOutRouteInstruction { data: payload.to_vec() }),
-            ROUTE => Self::Route(RouteInstruction { data: payload.to_vec() }),
-            ROUTE_WITH_TOKEN_LEDGER => Self::RouteWithTokenLedger(RouteWithTokenLedgerInstruction { data: payload.to_vec() }),
+            EXACT_OUT_ROUTE => Self::ExactOutRoute(ExactOutRouteInstruction::try_from_slice(payload)?),
+            ROUTE => Self::Route(RouteInstruction::try_from_slice(payload)?),
+            ROUTE_WITH_TOKEN_LEDGER => Self::RouteWithTokenLedger(RouteWithTokenLedgerInstruction::try_from_slice(payload)?),
             SET_TOKEN_LEDGER => Self::SetTokenLedger,
-            SHARED_ACCOUNTS_EXACT_OUT_ROUTE => {
-                if payload.is_empty() {
-                    return Err(ParseError::InvalidLength { expected: 1, got: 0 });
-                }
-                let id = payload[0];
-                let data = payload[1..].to_vec();
-                Self::SharedAccountsExactOutRoute(SharedAccountsExactOutRouteInstruction { id, data })
-            }
-            SHARED_ACCOUNTS_ROUTE => {
-                if payload.is_empty() {
-                    return Err(ParseError::InvalidLength { expected: 1, got: 0 });
-                }
-                let id = payload[0];
-                let data = payload[1..].to_vec();
-                Self::SharedAccountsRoute(SharedAccountsRouteInstruction { id, data })
-            }
+            SHARED_ACCOUNTS_EXACT_OUT_ROUTE => Self::SharedAccountsExactOutRoute(SharedAccountsExactOutRouteInstruction::try_from_slice(payload)?),
+            SHARED_ACCOUNTS_ROUTE => Self::SharedAccountsRoute(SharedAccountsRouteInstruction::try_from_slice(payload)?),
             SHARED_ACCOUNTS_ROUTE_WITH_TOKEN_LEDGER => {
-                if payload.is_empty() {
-                    return Err(ParseError::InvalidLength { expected: 1, got: 0 });
-                }
-                let id = payload[0];
-                let data = payload[1..].to_vec();
-                Self::SharedAccountsRouteWithTokenLedger(SharedAccountsRouteWithTokenLedgerInstruction { id, data })
+                Self::SharedAccountsRouteWithTokenLedger(SharedAccountsRouteWithTokenLedgerInstruction::try_from_slice(payload)?)
             }
             other => return Err(ParseError::Unknown(other)),
         })
@@ -31,6 +31,7 @@ pub mod plasma;
 pub mod pumpfun;
 pub mod pumpswap;
 pub mod raydium;
+pub mod registry;
 pub mod sanctum;
 pub mod saros;
 pub mod serum;
@@ -39,3 +40,5 @@ pub mod spl;
 pub mod stabble;
 pub mod tensor;
 pub mod tesserav;
+
+pub use registry::{decode_event, decode_instruction, DecodedEvent, DecodedInstruction};
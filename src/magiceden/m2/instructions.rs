@@ -149,6 +149,7 @@ pub struct BuyV2Instruction {
     pub token_size: u64,
     pub buyer_state_expiry: i64,
     pub buyer_creator_royalty_bp: u16,
+    /// Opaque to the public IDL; kept as raw bytes.
     pub extra_args: Vec<u8>,
 }
 
@@ -1,5 +1,7 @@
 //! Magic Eden M3 on-chain accounts.
 
+use crate::common::bincode::invalid;
+use crate::common::ParseError;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
@@ -13,9 +15,32 @@ pub struct Allowlist {
 pub const POOL_ACCOUNT: [u8; 8] = [241, 154, 109, 4, 17, 177, 109, 188];
 pub const SELL_STATE_ACCOUNT: [u8; 8] = [183, 195, 195, 180, 139, 112, 255, 193];
 
+/// Price step applied by a pool after every fill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurveType {
+    /// `curve_delta` is added to or subtracted from the spot price.
+    Linear,
+    /// The spot price is scaled by `curve_delta` basis points.
+    Exponential,
+}
+
+impl TryFrom<u8> for CurveType {
+    type Error = ParseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Linear),
+            1 => Ok(Self::Exponential),
+            other => Err(invalid(format!("invalid curve type {other}"))),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, BorshSerialize, BorshDeserialize)]
 pub struct Pool {
+    /// In lamports, or atoms of `payment_mint`.
     pub spot_price: u64,
+    /// A [`CurveType`] discriminant.
     pub curve_type: u8,
     pub curve_delta: u64,
     pub reinvest_fulfill_buy: bool,
@@ -46,3 +71,30 @@ pub struct SellState {
     pub asset_amount: u64,
     pub cosigner_annotation: [u8; 32],
 }
+
+impl Pool {
+    pub fn curve(&self) -> Result<CurveType, ParseError> {
+        CurveType::try_from(self.curve_type)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MmmAccount {
+    Pool(Box<Pool>),
+    SellState(SellState),
+}
+
+/// Decodes an M3 account. Accounts are allocated with trailing reserved
+/// space, which is ignored.
+pub fn unpack(data: &[u8]) -> Result<MmmAccount, ParseError> {
+    if data.len() < 8 {
+        return Err(ParseError::TooShort(data.len()));
+    }
+    let (disc, mut rest) = data.split_at(8);
+    let disc: [u8; 8] = disc.try_into().unwrap();
+    Ok(match disc {
+        POOL_ACCOUNT => MmmAccount::Pool(Box::new(Pool::deserialize(&mut rest)?)),
+        SELL_STATE_ACCOUNT => MmmAccount::SellState(SellState::deserialize(&mut rest)?),
+        _ => return Err(ParseError::Unknown(disc)),
+    })
+}
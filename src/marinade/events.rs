@@ -143,28 +143,14 @@ pub struct Fee {
 // -----------------------------------------------------------------------------
 // Borsh deserialisation (Anchor events have 8-byte discriminator)
 // -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for MarinadeEvent {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-
-        let (disc, payload) = data.split_at(8);
-        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
-
-        Ok(match discriminator {
-            DEPOSIT_EVENT => Self::Deposit(DepositEvent::try_from_slice(payload)?),
-            DEPOSIT_STAKE_ACCOUNT_EVENT => Self::DepositStakeAccount(DepositStakeAccountEvent::try_from_slice(payload)?),
-            LIQUID_UNSTAKE_EVENT => Self::LiquidUnstake(LiquidUnstakeEvent::try_from_slice(payload)?),
-            ADD_LIQUIDITY_EVENT => Self::AddLiquidity(AddLiquidityEvent::try_from_slice(payload)?),
-            REMOVE_LIQUIDITY_EVENT => Self::RemoveLiquidity(RemoveLiquidityEvent::try_from_slice(payload)?),
-            WITHDRAW_STAKE_ACCOUNT_EVENT => Self::WithdrawStakeAccount(WithdrawStakeAccountEvent::try_from_slice(payload)?),
-            other => return Err(ParseError::Unknown(other)),
-        })
-    }
-}
+anchor_events!(MarinadeEvent {
+    DEPOSIT_EVENT => Deposit(DepositEvent),
+    DEPOSIT_STAKE_ACCOUNT_EVENT => DepositStakeAccount(DepositStakeAccountEvent),
+    LIQUID_UNSTAKE_EVENT => LiquidUnstake(LiquidUnstakeEvent),
+    ADD_LIQUIDITY_EVENT => AddLiquidity(AddLiquidityEvent),
+    REMOVE_LIQUIDITY_EVENT => RemoveLiquidity(RemoveLiquidityEvent),
+    WITHDRAW_STAKE_ACCOUNT_EVENT => WithdrawStakeAccount(WithdrawStakeAccountEvent),
+});
 
 pub fn unpack(data: &[u8]) -> Result<MarinadeEvent, ParseError> {
     MarinadeEvent::try_from(data)
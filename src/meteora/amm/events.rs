@@ -363,37 +363,27 @@ pub struct PartnerClaimFees {
     pub partner: Pubkey,
 }
 
-impl<'a> TryFrom<&'a [u8]> for AmmEvent {
-    type Error = ParseError;
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-        let (disc, payload) = data.split_at(8);
-        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
-        Ok(match discriminator {
-            ADD_LIQUIDITY => Self::AddLiquidity(AddLiquidity::try_from_slice(payload)?),
-            REMOVE_LIQUIDITY => Self::RemoveLiquidity(RemoveLiquidity::try_from_slice(payload)?),
-            BOOTSTRAP_LIQUIDITY => Self::BootstrapLiquidity(BootstrapLiquidity::try_from_slice(payload)?),
-            SWAP => Self::Swap(Swap::try_from_slice(payload)?),
-            SET_POOL_FEES => Self::SetPoolFees(SetPoolFees::try_from_slice(payload)?),
-            POOL_INFO => Self::PoolInfo(PoolInfo::try_from_slice(payload)?),
-            TRANSFER_ADMIN => Self::TransferAdmin(TransferAdmin::try_from_slice(payload)?),
-            OVERRIDE_CURVE_PARAM => Self::OverrideCurveParam(OverrideCurveParam::try_from_slice(payload)?),
-            POOL_CREATED => Self::PoolCreated(PoolCreated::try_from_slice(payload)?),
-            POOL_ENABLED => Self::PoolEnabled(PoolEnabled::try_from_slice(payload)?),
-            MIGRATE_FEE_ACCOUNT => Self::MigrateFeeAccount(MigrateFeeAccount::try_from_slice(payload)?),
-            CREATE_LOCK_ESCROW => Self::CreateLockEscrow(CreateLockEscrow::try_from_slice(payload)?),
-            LOCK => Self::Lock(Lock::try_from_slice(payload)?),
-            CLAIM_FEE => Self::ClaimFee(ClaimFee::try_from_slice(payload)?),
-            CREATE_CONFIG => Self::CreateConfig(CreateConfig::try_from_slice(payload)?),
-            CLOSE_CONFIG => Self::CloseConfig(CloseConfig::try_from_slice(payload)?),
-            WITHDRAW_PROTOCOL_FEES => Self::WithdrawProtocolFees(WithdrawProtocolFees::try_from_slice(payload)?),
-            PARTNER_CLAIM_FEES => Self::PartnerClaimFees(PartnerClaimFees::try_from_slice(payload)?),
-            _ => Self::Unknown,
-        })
-    }
-}
+anchor_events!(AmmEvent {
+    ADD_LIQUIDITY => AddLiquidity(AddLiquidity),
+    REMOVE_LIQUIDITY => RemoveLiquidity(RemoveLiquidity),
+    BOOTSTRAP_LIQUIDITY => BootstrapLiquidity(BootstrapLiquidity),
+    SWAP => Swap(Swap),
+    SET_POOL_FEES => SetPoolFees(SetPoolFees),
+    POOL_INFO => PoolInfo(PoolInfo),
+    TRANSFER_ADMIN => TransferAdmin(TransferAdmin),
+    OVERRIDE_CURVE_PARAM => OverrideCurveParam(OverrideCurveParam),
+    POOL_CREATED => PoolCreated(PoolCreated),
+    POOL_ENABLED => PoolEnabled(PoolEnabled),
+    MIGRATE_FEE_ACCOUNT => MigrateFeeAccount(MigrateFeeAccount),
+    CREATE_LOCK_ESCROW => CreateLockEscrow(CreateLockEscrow),
+    LOCK => Lock(Lock),
+    CLAIM_FEE => ClaimFee(ClaimFee),
+    CREATE_CONFIG => CreateConfig(CreateConfig),
+    CLOSE_CONFIG => CloseConfig(CloseConfig),
+    WITHDRAW_PROTOCOL_FEES => WithdrawProtocolFees(WithdrawProtocolFees),
+    PARTNER_CLAIM_FEES => PartnerClaimFees(PartnerClaimFees),
+    _ => Unknown,
+});
 pub fn parse_event(data: &[u8]) -> Result<AmmEvent, ParseError> {
     AmmEvent::try_from(data)
 }
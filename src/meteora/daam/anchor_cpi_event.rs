@@ -36,7 +36,6 @@ pub const EVTSWAP: [u8; 8] = [27, 60, 21, 213, 138, 170, 187, 147];
 pub const EVTUPDATEREWARDDURATION: [u8; 8] = [149, 135, 65, 231, 129, 153, 65, 57];
 pub const EVTUPDATEREWARDFUNDER: [u8; 8] = [76, 154, 208, 13, 40, 115, 246, 146];
 pub const EVTWITHDRAWINELIGIBLEREWARD: [u8; 8] = [248, 215, 184, 78, 31, 180, 179, 168];
-const ANCHOR_DISC: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
 
 // -----------------------------------------------------------------------------
 // Event enumeration
@@ -315,50 +314,33 @@ pub struct EvtWithdrawIneligibleReward {
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for MeteoraDammAnchorCpiEvent {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-        let (disc, payload) = if data.len() >= 16 && data[0..8] == ANCHOR_DISC {
-            let disc: [u8; 8] = data[8..16].try_into().expect("slice len 8");
-            (disc, &data[16..])
-        } else {
-            let disc: [u8; 8] = data[0..8].try_into().expect("slice len 8");
-            (disc, &data[8..])
-        };
-        Ok(match disc {
-            EVTADDLIQUIDITY => Self::EvtAddLiquidity(EvtAddLiquidity::try_from_slice(payload)?),
-            EVTCLAIMPARTNERFEE => Self::EvtClaimPartnerFee(EvtClaimPartnerFee::try_from_slice(payload)?),
-            EVTCLAIMPOSITIONFEE => Self::EvtClaimPositionFee(EvtClaimPositionFee::try_from_slice(payload)?),
-            EVTCLAIMPROTOCOLFEE => Self::EvtClaimProtocolFee(EvtClaimProtocolFee::try_from_slice(payload)?),
-            EVTCLAIMREWARD => Self::EvtClaimReward(EvtClaimReward::try_from_slice(payload)?),
-            EVTCLOSECLAIMFEEOPERATOR => Self::EvtCloseClaimFeeOperator(EvtCloseClaimFeeOperator::try_from_slice(payload)?),
-            EVTCLOSECONFIG => Self::EvtCloseConfig(EvtCloseConfig::try_from_slice(payload)?),
-            EVTCLOSEPOSITION => Self::EvtClosePosition(EvtClosePosition::try_from_slice(payload)?),
-            EVTCREATECLAIMFEEOPERATOR => Self::EvtCreateClaimFeeOperator(EvtCreateClaimFeeOperator::try_from_slice(payload)?),
-            EVTCREATECONFIG => Self::EvtCreateConfig(EvtCreateConfig::try_from_slice(payload)?),
-            EVTCREATEDYNAMICCONFIG => Self::EvtCreateDynamicConfig(EvtCreateDynamicConfig::try_from_slice(payload)?),
-            EVTCREATEPOSITION => Self::EvtCreatePosition(EvtCreatePosition::try_from_slice(payload)?),
-            EVTCREATETOKENBADGE => Self::EvtCreateTokenBadge(EvtCreateTokenBadge::try_from_slice(payload)?),
-            EVTFUNDREWARD => Self::EvtFundReward(EvtFundReward::try_from_slice(payload)?),
-            EVTINITIALIZEPOOL => Self::EvtInitializePool(EvtInitializePool::try_from_slice(payload)?),
-            EVTINITIALIZEREWARD => Self::EvtInitializeReward(EvtInitializeReward::try_from_slice(payload)?),
-            EVTLOCKPOSITION => Self::EvtLockPosition(EvtLockPosition::try_from_slice(payload)?),
-            EVTPERMANENTLOCKPOSITION => Self::EvtPermanentLockPosition(EvtPermanentLockPosition::try_from_slice(payload)?),
-            EVTREMOVELIQUIDITY => Self::EvtRemoveLiquidity(EvtRemoveLiquidity::try_from_slice(payload)?),
-            EVTSETPOOLSTATUS => Self::EvtSetPoolStatus(EvtSetPoolStatus::try_from_slice(payload)?),
-            EVTSPLITPOSITION => Self::EvtSplitPosition(EvtSplitPosition::try_from_slice(payload)?),
-            EVTSWAP => Self::EvtSwap(EvtSwap::try_from_slice(payload)?),
-            EVTUPDATEREWARDDURATION => Self::EvtUpdateRewardDuration(EvtUpdateRewardDuration::try_from_slice(payload)?),
-            EVTUPDATEREWARDFUNDER => Self::EvtUpdateRewardFunder(EvtUpdateRewardFunder::try_from_slice(payload)?),
-            EVTWITHDRAWINELIGIBLEREWARD => Self::EvtWithdrawIneligibleReward(EvtWithdrawIneligibleReward::try_from_slice(payload)?),
-            other => return Err(ParseError::Unknown(other)),
-        })
-    }
-}
+anchor_events!(MeteoraDammAnchorCpiEvent {
+    EVTADDLIQUIDITY => EvtAddLiquidity(EvtAddLiquidity),
+    EVTCLAIMPARTNERFEE => EvtClaimPartnerFee(EvtClaimPartnerFee),
+    EVTCLAIMPOSITIONFEE => EvtClaimPositionFee(EvtClaimPositionFee),
+    EVTCLAIMPROTOCOLFEE => EvtClaimProtocolFee(EvtClaimProtocolFee),
+    EVTCLAIMREWARD => EvtClaimReward(EvtClaimReward),
+    EVTCLOSECLAIMFEEOPERATOR => EvtCloseClaimFeeOperator(EvtCloseClaimFeeOperator),
+    EVTCLOSECONFIG => EvtCloseConfig(EvtCloseConfig),
+    EVTCLOSEPOSITION => EvtClosePosition(EvtClosePosition),
+    EVTCREATECLAIMFEEOPERATOR => EvtCreateClaimFeeOperator(EvtCreateClaimFeeOperator),
+    EVTCREATECONFIG => EvtCreateConfig(EvtCreateConfig),
+    EVTCREATEDYNAMICCONFIG => EvtCreateDynamicConfig(EvtCreateDynamicConfig),
+    EVTCREATEPOSITION => EvtCreatePosition(EvtCreatePosition),
+    EVTCREATETOKENBADGE => EvtCreateTokenBadge(EvtCreateTokenBadge),
+    EVTFUNDREWARD => EvtFundReward(EvtFundReward),
+    EVTINITIALIZEPOOL => EvtInitializePool(EvtInitializePool),
+    EVTINITIALIZEREWARD => EvtInitializeReward(EvtInitializeReward),
+    EVTLOCKPOSITION => EvtLockPosition(EvtLockPosition),
+    EVTPERMANENTLOCKPOSITION => EvtPermanentLockPosition(EvtPermanentLockPosition),
+    EVTREMOVELIQUIDITY => EvtRemoveLiquidity(EvtRemoveLiquidity),
+    EVTSETPOOLSTATUS => EvtSetPoolStatus(EvtSetPoolStatus),
+    EVTSPLITPOSITION => EvtSplitPosition(EvtSplitPosition),
+    EVTSWAP => EvtSwap(EvtSwap),
+    EVTUPDATEREWARDDURATION => EvtUpdateRewardDuration(EvtUpdateRewardDuration),
+    EVTUPDATEREWARDFUNDER => EvtUpdateRewardFunder(EvtUpdateRewardFunder),
+    EVTWITHDRAWINELIGIBLEREWARD => EvtWithdrawIneligibleReward(EvtWithdrawIneligibleReward),
+});
 
 pub fn unpack(data: &[u8]) -> Result<MeteoraDammAnchorCpiEvent, ParseError> {
     MeteoraDammAnchorCpiEvent::try_from(data)
@@ -7,66 +7,263 @@ use solana_program::pubkey::Pubkey;
 // -----------------------------------------------------------------------------
 // Discriminators (first 8 bytes of the emitted log’s data)
 // -----------------------------------------------------------------------------
+const ADD_LIQUIDITY: [u8; 8] = [31, 94, 125, 90, 227, 52, 61, 186];
+const CLAIM_FEE: [u8; 8] = [75, 122, 154, 48, 140, 74, 123, 163];
+const CLAIM_FEE2: [u8; 8] = [232, 171, 242, 97, 58, 77, 35, 45];
+const CLAIM_REWARD: [u8; 8] = [148, 116, 134, 204, 22, 171, 85, 95];
+const CLAIM_REWARD2: [u8; 8] = [27, 143, 244, 33, 80, 43, 110, 146];
+const COMPOSITION_FEE: [u8; 8] = [128, 151, 123, 106, 17, 102, 113, 142];
+const DECREASE_POSITION_LENGTH: [u8; 8] = [52, 118, 235, 85, 172, 169, 15, 128];
+const DYNAMIC_FEE_PARAMETER_UPDATE: [u8; 8] = [88, 88, 178, 135, 194, 146, 91, 243];
+const FEE_PARAMETER_UPDATE: [u8; 8] = [48, 76, 241, 117, 144, 215, 242, 44];
+const FUND_REWARD: [u8; 8] = [246, 228, 58, 130, 145, 170, 79, 204];
+const GO_TO_ABIN: [u8; 8] = [59, 138, 76, 68, 138, 131, 176, 67];
+const INCREASE_OBSERVATION: [u8; 8] = [99, 249, 17, 121, 166, 156, 207, 215];
+const INCREASE_POSITION_LENGTH: [u8; 8] = [157, 239, 42, 204, 30, 56, 223, 46];
+const INITIALIZE_REWARD: [u8; 8] = [211, 153, 88, 62, 149, 60, 177, 70];
+const LB_PAIR_CREATE: [u8; 8] = [185, 74, 252, 125, 27, 215, 188, 111];
+const POSITION_CLOSE: [u8; 8] = [255, 196, 16, 107, 28, 202, 53, 128];
+const POSITION_CREATE: [u8; 8] = [144, 142, 252, 84, 157, 53, 37, 121];
+const REBALANCING: [u8; 8] = [0, 109, 117, 179, 61, 91, 199, 200];
+const REMOVE_LIQUIDITY: [u8; 8] = [116, 244, 97, 232, 103, 31, 152, 58];
 const SWAP: [u8; 8] = [81, 108, 227, 190, 205, 208, 10, 196];
-const ANCHOR_DISC: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
-// const ADD_LIQUIDITY: [u8; 8] = [31, 94, 125, 90, 227, 52, 61, 186];
-// const CLAIM_FEE: [u8; 8] = [75, 122, 154, 48, 140, 74, 123, 163];
-// const CLAIM_FEE2: [u8; 8] = [232, 171, 242, 97, 58, 77, 35, 45];
-// const CLAIM_REWARD: [u8; 8] = [148, 116, 134, 204, 22, 171, 85, 95];
-// const CLAIM_REWARD2: [u8; 8] = [27, 143, 244, 33, 80, 43, 110, 146];
-// const COMPOSITION_FEE: [u8; 8] = [128, 151, 123, 106, 17, 102, 113, 142];
-// const DECREASE_POSITION_LENGTH: [u8; 8] = [52, 118, 235, 85, 172, 169, 15, 128];
-// const DYNAMIC_FEE_PARAMETER_UPDATE: [u8; 8] = [88, 88, 178, 135, 194, 146, 91, 243];
-// const FEE_PARAMETER_UPDATE: [u8; 8] = [48, 76, 241, 117, 144, 215, 242, 44];
-// const FUND_REWARD: [u8; 8] = [246, 228, 58, 130, 145, 170, 79, 204];
-// const GO_TO_ABIN: [u8; 8] = [59, 138, 76, 68, 138, 131, 176, 67];
-// const INCREASE_OBSERVATION: [u8; 8] = [99, 249, 17, 121, 166, 156, 207, 215];
-// const INCREASE_POSITION_LENGTH: [u8; 8] = [157, 239, 42, 204, 30, 56, 223, 46];
-// const INITIALIZE_REWARD: [u8; 8] = [211, 153, 88, 62, 149, 60, 177, 70];
-// const LB_PAIR_CREATE: [u8; 8] = [185, 74, 252, 125, 27, 215, 188, 111];
-// const POSITION_CLOSE: [u8; 8] = [255, 196, 16, 107, 28, 202, 53, 128];
-// const POSITION_CREATE: [u8; 8] = [144, 142, 252, 84, 157, 53, 37, 121];
-// const REBALANCING: [u8; 8] = [0, 109, 117, 179, 61, 91, 199, 200];
-// const REMOVE_LIQUIDITY: [u8; 8] = [116, 244, 97, 232, 103, 31, 152, 58];
-// const UPDATE_POSITION_LOCK_RELEASE_POINT: [u8; 8] = [133, 214, 66, 224, 64, 12, 7, 191];
-// const UPDATE_POSITION_OPERATOR: [u8; 8] = [39, 115, 48, 204, 246, 47, 66, 57];
-// const UPDATE_REWARD_DURATION: [u8; 8] = [223, 245, 224, 153, 49, 29, 163, 172];
-// const UPDATE_REWARD_FUNDER: [u8; 8] = [224, 178, 174, 74, 252, 165, 85, 180];
-// const WITHDRAW_INELIGIBLE_REWARD: [u8; 8] = [231, 189, 65, 149, 102, 215, 154, 244];
+const UPDATE_POSITION_LOCK_RELEASE_POINT: [u8; 8] = [133, 214, 66, 224, 64, 12, 7, 191];
+const UPDATE_POSITION_OPERATOR: [u8; 8] = [39, 115, 48, 204, 246, 47, 66, 57];
+const UPDATE_REWARD_DURATION: [u8; 8] = [223, 245, 224, 153, 49, 29, 163, 172];
+const UPDATE_REWARD_FUNDER: [u8; 8] = [224, 178, 174, 74, 252, 165, 85, 180];
+const WITHDRAW_INELIGIBLE_REWARD: [u8; 8] = [231, 189, 65, 149, 102, 215, 154, 244];
 
 // -----------------------------------------------------------------------------
 // High-level event enum
 // -----------------------------------------------------------------------------
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MeteoraDlmmAnchorCpiEvent {
+    AddLiquidity(AddLiquidityEvent),
+    ClaimFee(ClaimFeeEvent),
+    ClaimFee2(ClaimFee2Event),
+    ClaimReward(ClaimRewardEvent),
+    ClaimReward2(ClaimReward2Event),
+    CompositionFee(CompositionFeeEvent),
+    DecreasePositionLength(DecreasePositionLengthEvent),
+    DynamicFeeParameterUpdate(DynamicFeeParameterUpdateEvent),
+    FeeParameterUpdate(FeeParameterUpdateEvent),
+    FundReward(FundRewardEvent),
+    GoToABin(GoToABinEvent),
+    IncreaseObservation(IncreaseObservationEvent),
+    IncreasePositionLength(IncreasePositionLengthEvent),
+    InitializeReward(InitializeRewardEvent),
+    LbPairCreate(LbPairCreateEvent),
+    PositionClose(PositionCloseEvent),
+    PositionCreate(PositionCreateEvent),
+    Rebalancing(RebalancingEvent),
+    RemoveLiquidity(RemoveLiquidityEvent),
     Swap(SwapEvent),
-    // AddLiquidity,
-    // ClaimFee,
-    // ClaimFee2,
-    // ClaimReward,
-    // ClaimReward2,
-    // CompositionFee,
-    // DecreasePositionLength,
-    // DynamicFeeParameterUpdate,
-    // FeeParameterUpdate,
-    // FundReward,
-    // GoToABin,
-    // IncreaseObservation,
-    // IncreasePositionLength,
-    // InitializeReward,
-    // LbPairCreate,
-    // PositionClose,
-    // PositionCreate,
-    // Rebalancing,
-    // RemoveLiquidity,
-    // UpdatePositionLockReleasePoint,
-    // UpdatePositionOperator,
-    // UpdateRewardDuration,
-    // UpdateRewardFunder,
-    // WithdrawIneligibleReward,
+    UpdatePositionLockReleasePoint(UpdatePositionLockReleasePointEvent),
+    UpdatePositionOperator(UpdatePositionOperatorEvent),
+    UpdateRewardDuration(UpdateRewardDurationEvent),
+    UpdateRewardFunder(UpdateRewardFunderEvent),
+    WithdrawIneligibleReward(WithdrawIneligibleRewardEvent),
     Unknown,
 }
 
+// -----------------------------------------------------------------------------
+// Payload structs
+// -----------------------------------------------------------------------------
+/// Emitted when liquidity is added to a position.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct AddLiquidityEvent {
+    pub lb_pair: Pubkey,
+    pub from: Pubkey,
+    pub position: Pubkey,
+    pub amounts: [u64; 2],
+    pub active_bin_id: i32,
+}
+
+/// Emitted when a position claims its swap fees.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ClaimFeeEvent {
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub fee_x: u64,
+    pub fee_y: u64,
+}
+
+/// Emitted when a position claims its swap fees (v2 instructions).
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ClaimFee2Event {
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub fee_x: u64,
+    pub fee_y: u64,
+    pub active_bin_id: i32,
+}
+
+/// Emitted when a position claims a farming reward.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ClaimRewardEvent {
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub reward_index: u64,
+    pub total_reward: u64,
+}
+
+/// Emitted when a position claims a farming reward (v2 instructions).
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ClaimReward2Event {
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub reward_index: u64,
+    pub total_reward: u64,
+    pub active_bin_id: i32,
+}
+
+/// Emitted when an imbalanced deposit into the active bin is charged a fee.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CompositionFeeEvent {
+    pub from: Pubkey,
+    pub bin_id: i16,
+    pub token_x_fee_amount: u64,
+    pub token_y_fee_amount: u64,
+    pub protocol_token_x_fee_amount: u64,
+    pub protocol_token_y_fee_amount: u64,
+}
+
+/// Emitted when bins are removed from a position.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct DecreasePositionLengthEvent {
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub length_to_remove: u16,
+    pub side: u8,
+}
+
+/// Emitted when the variable fee parameters of a pair change.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct DynamicFeeParameterUpdateEvent {
+    pub lb_pair: Pubkey,
+    /// Filter period determine high frequency trading time window.
+    pub filter_period: u16,
+    /// Decay period determine when the volatile fee start decay / decrease.
+    pub decay_period: u16,
+    /// Reduction factor controls the volatile fee rate decrement rate.
+    pub reduction_factor: u16,
+    /// Used to scale the variable fee component depending on the dynamic of the market
+    pub variable_fee_control: u32,
+    /// Maximum number of bin crossed can be accumulated. Used to cap volatile fee rate.
+    pub max_volatility_accumulator: u32,
+}
+
+/// Emitted when the base fee parameters of a pair change.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FeeParameterUpdateEvent {
+    pub lb_pair: Pubkey,
+    pub protocol_share: u16,
+    pub base_factor: u16,
+}
+
+/// Emitted when a reward vault is funded.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FundRewardEvent {
+    pub lb_pair: Pubkey,
+    pub funder: Pubkey,
+    pub reward_index: u64,
+    pub amount: u64,
+}
+
+/// Emitted when the active bin is moved without a swap.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct GoToABinEvent {
+    pub lb_pair: Pubkey,
+    pub from_bin_id: i32,
+    pub to_bin_id: i32,
+}
+
+/// Emitted when the oracle is resized.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct IncreaseObservationEvent {
+    pub oracle: Pubkey,
+    pub new_observation_length: u64,
+}
+
+/// Emitted when bins are added to a position.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct IncreasePositionLengthEvent {
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub length_to_add: u16,
+    pub side: u8,
+}
+
+/// Emitted when a farming reward is set up for a pair.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct InitializeRewardEvent {
+    pub lb_pair: Pubkey,
+    pub reward_mint: Pubkey,
+    pub funder: Pubkey,
+    pub reward_index: u64,
+    pub reward_duration: u64,
+}
+
+/// Emitted when a pair is created.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct LbPairCreateEvent {
+    pub lb_pair: Pubkey,
+    pub bin_step: u16,
+    pub token_x: Pubkey,
+    pub token_y: Pubkey,
+}
+
+/// Emitted when a position is closed.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PositionCloseEvent {
+    pub position: Pubkey,
+    pub owner: Pubkey,
+}
+
+/// Emitted when a position is opened.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PositionCreateEvent {
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+}
+
+/// Emitted when a position is rebalanced across bins.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct RebalancingEvent {
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub active_bin_id: i32,
+    pub x_withdrawn_amount: u64,
+    pub x_added_amount: u64,
+    pub y_withdrawn_amount: u64,
+    pub y_added_amount: u64,
+    pub x_fee_amount: u64,
+    pub y_fee_amount: u64,
+    pub old_min_id: i32,
+    pub old_max_id: i32,
+    pub new_min_id: i32,
+    pub new_max_id: i32,
+    pub rewards: [u64; 2],
+}
+
+/// Emitted when liquidity is removed from a position.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct RemoveLiquidityEvent {
+    pub lb_pair: Pubkey,
+    pub from: Pubkey,
+    pub position: Pubkey,
+    pub amounts: [u64; 2],
+    pub active_bin_id: i32,
+}
+
 /// Emitted when swap occurs.
 #[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct SwapEvent {
@@ -83,54 +280,80 @@ pub struct SwapEvent {
     pub host_fee: u64,
 }
 
+/// Emitted when the lock release point of a position changes.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct UpdatePositionLockReleasePointEvent {
+    pub position: Pubkey,
+    pub current_point: u64,
+    pub new_lock_release_point: u64,
+    pub old_lock_release_point: u64,
+    pub sender: Pubkey,
+}
+
+/// Emitted when the operator of a position changes.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct UpdatePositionOperatorEvent {
+    pub position: Pubkey,
+    pub old_operator: Pubkey,
+    pub new_operator: Pubkey,
+}
+
+/// Emitted when the duration of a farming reward changes.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct UpdateRewardDurationEvent {
+    pub lb_pair: Pubkey,
+    pub reward_index: u64,
+    pub old_reward_duration: u64,
+    pub new_reward_duration: u64,
+}
+
+/// Emitted when the funder of a farming reward changes.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct UpdateRewardFunderEvent {
+    pub lb_pair: Pubkey,
+    pub reward_index: u64,
+    pub old_funder: Pubkey,
+    pub new_funder: Pubkey,
+}
+
+/// Emitted when rewards accrued while no liquidity was active are withdrawn.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct WithdrawIneligibleRewardEvent {
+    pub lb_pair: Pubkey,
+    pub reward_mint: Pubkey,
+    pub amount: u64,
+}
+
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for MeteoraDlmmAnchorCpiEvent {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-
-        let (disc, payload) = if data.len() >= 16 && data[0..8] == ANCHOR_DISC {
-            let disc: [u8; 8] = data[8..16].try_into().expect("slice len 8");
-            (disc, &data[16..])
-        } else {
-            let disc: [u8; 8] = data[0..8].try_into().expect("slice len 8");
-            (disc, &data[8..])
-        };
-        Ok(match disc {
-            SWAP => Self::Swap(SwapEvent::try_from_slice(payload)?),
-            // ADD_LIQUIDITY => Self::AddLiquidity,
-            // CLAIM_FEE => Self::ClaimFee,
-            // CLAIM_FEE2 => Self::ClaimFee2,
-            // CLAIM_REWARD => Self::ClaimReward,
-            // CLAIM_REWARD2 => Self::ClaimReward2,
-            // COMPOSITION_FEE => Self::CompositionFee,
-            // DECREASE_POSITION_LENGTH => Self::DecreasePositionLength,
-            // DYNAMIC_FEE_PARAMETER_UPDATE => Self::DynamicFeeParameterUpdate,
-            // FEE_PARAMETER_UPDATE => Self::FeeParameterUpdate,
-            // FUND_REWARD => Self::FundReward,
-            // GO_TO_ABIN => Self::GoToABin,
-            // INCREASE_OBSERVATION => Self::IncreaseObservation,
-            // INCREASE_POSITION_LENGTH => Self::IncreasePositionLength,
-            // INITIALIZE_REWARD => Self::InitializeReward,
-            // LB_PAIR_CREATE => Self::LbPairCreate,
-            // POSITION_CLOSE => Self::PositionClose,
-            // POSITION_CREATE => Self::PositionCreate,
-            // REBALANCING => Self::Rebalancing,
-            // REMOVE_LIQUIDITY => Self::RemoveLiquidity,
-            // UPDATE_POSITION_LOCK_RELEASE_POINT => Self::UpdatePositionLockReleasePoint,
-            // UPDATE_POSITION_OPERATOR => Self::UpdatePositionOperator,
-            // UPDATE_REWARD_DURATION => Self::UpdateRewardDuration,
-            // UPDATE_REWARD_FUNDER => Self::UpdateRewardFunder,
-            // WITHDRAW_INELIGIBLE_REWARD => Self::WithdrawIneligibleReward,
-            other => return Err(ParseError::Unknown(other)),
-        })
-    }
-}
+anchor_events!(MeteoraDlmmAnchorCpiEvent {
+    ADD_LIQUIDITY => AddLiquidity(AddLiquidityEvent),
+    CLAIM_FEE => ClaimFee(ClaimFeeEvent),
+    CLAIM_FEE2 => ClaimFee2(ClaimFee2Event),
+    CLAIM_REWARD => ClaimReward(ClaimRewardEvent),
+    CLAIM_REWARD2 => ClaimReward2(ClaimReward2Event),
+    COMPOSITION_FEE => CompositionFee(CompositionFeeEvent),
+    DECREASE_POSITION_LENGTH => DecreasePositionLength(DecreasePositionLengthEvent),
+    DYNAMIC_FEE_PARAMETER_UPDATE => DynamicFeeParameterUpdate(DynamicFeeParameterUpdateEvent),
+    FEE_PARAMETER_UPDATE => FeeParameterUpdate(FeeParameterUpdateEvent),
+    FUND_REWARD => FundReward(FundRewardEvent),
+    GO_TO_ABIN => GoToABin(GoToABinEvent),
+    INCREASE_OBSERVATION => IncreaseObservation(IncreaseObservationEvent),
+    INCREASE_POSITION_LENGTH => IncreasePositionLength(IncreasePositionLengthEvent),
+    INITIALIZE_REWARD => InitializeReward(InitializeRewardEvent),
+    LB_PAIR_CREATE => LbPairCreate(LbPairCreateEvent),
+    POSITION_CLOSE => PositionClose(PositionCloseEvent),
+    POSITION_CREATE => PositionCreate(PositionCreateEvent),
+    REBALANCING => Rebalancing(RebalancingEvent),
+    REMOVE_LIQUIDITY => RemoveLiquidity(RemoveLiquidityEvent),
+    SWAP => Swap(SwapEvent),
+    UPDATE_POSITION_LOCK_RELEASE_POINT => UpdatePositionLockReleasePoint(UpdatePositionLockReleasePointEvent),
+    UPDATE_POSITION_OPERATOR => UpdatePositionOperator(UpdatePositionOperatorEvent),
+    UPDATE_REWARD_DURATION => UpdateRewardDuration(UpdateRewardDurationEvent),
+    UPDATE_REWARD_FUNDER => UpdateRewardFunder(UpdateRewardFunderEvent),
+    WITHDRAW_INELIGIBLE_REWARD => WithdrawIneligibleReward(WithdrawIneligibleRewardEvent),
+});
 
 pub fn unpack(data: &[u8]) -> Result<MeteoraDlmmAnchorCpiEvent, ParseError> {
     MeteoraDlmmAnchorCpiEvent::try_from(data)
@@ -3,6 +3,7 @@ use substreams_solana::b58;
 pub mod accounts;
 pub mod anchor_cpi_event;
 pub mod instructions;
+pub mod state;
 
 /// Meteora DLMM Program
 ///
@@ -0,0 +1,415 @@
+//! Meteora DLMM on-chain account state.
+//!
+//! Accounts are Anchor zero-copy (`bytemuck`, `#[repr(C)]`) structs whose
+//! padding is spelled out as fields, so they decode as a sequential layout
+//! after the 8-byte discriminator. Trailing space (position extensions,
+//! oracle samples) is only read where noted.
+
+use crate::common::ParseError;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Number of bins in a [`BinArray`] and in a [`PositionV2`].
+pub const MAX_BIN_PER_ARRAY: usize = 70;
+const BASIS_POINT_MAX: f64 = 10_000.0;
+
+// -----------------------------------------------------------------------------
+// Discriminators
+// -----------------------------------------------------------------------------
+pub const LB_PAIR: [u8; 8] = [33, 11, 49, 98, 181, 101, 177, 13];
+pub const BIN_ARRAY: [u8; 8] = [92, 142, 92, 220, 5, 148, 70, 181];
+pub const BIN_ARRAY_BITMAP_EXTENSION: [u8; 8] = [80, 111, 124, 113, 55, 237, 18, 5];
+pub const POSITION_V2: [u8; 8] = [117, 176, 212, 199, 245, 180, 133, 182];
+pub const ORACLE: [u8; 8] = [139, 194, 131, 179, 140, 179, 229, 244];
+pub const PRESET_PARAMETER2: [u8; 8] = [171, 236, 148, 115, 162, 113, 222, 174];
+
+// -----------------------------------------------------------------------------
+// Custom types
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct StaticParameters {
+    /// Used for base fee calculation. base_fee_rate = base_factor * bin_step * 10 * 10^base_fee_power_factor
+    pub base_factor: u16,
+    /// Filter period determine high frequency trading time window.
+    pub filter_period: u16,
+    /// Decay period determine when the volatile fee start decay / decrease.
+    pub decay_period: u16,
+    /// Reduction factor controls the volatile fee rate decrement rate.
+    pub reduction_factor: u16,
+    /// Used to scale the variable fee component depending on the dynamic of the market
+    pub variable_fee_control: u32,
+    /// Maximum number of bin crossed can be accumulated. Used to cap volatile fee rate.
+    pub max_volatility_accumulator: u32,
+    /// Min bin id supported by the pool based on the configured bin step.
+    pub min_bin_id: i32,
+    /// Max bin id supported by the pool based on the configured bin step.
+    pub max_bin_id: i32,
+    /// Portion of swap fees retained by the protocol by controlling protocol_share parameter. protocol_swap_fee = protocol_share * total_swap_fee
+    pub protocol_share: u16,
+    /// Base fee power factor
+    pub base_fee_power_factor: u8,
+    /// Padding for bytemuck safe alignment
+    pub padding: [u8; 5],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct VariableParameters {
+    /// Volatility accumulator measure the number of bin crossed since reference bin ID. Normally (without filter period taken into consideration), reference bin ID is the active bin of last swap.
+    /// It affects the variable fee rate
+    pub volatility_accumulator: u32,
+    /// Volatility reference is decayed volatility accumulator. It is always <= volatility_accumulator
+    pub volatility_reference: u32,
+    /// Active bin id of last swap.
+    pub index_reference: i32,
+    /// Padding for bytemuck safe alignment
+    pub padding: [u8; 4],
+    /// Last timestamp the variable parameters was updated
+    pub last_update_timestamp: i64,
+    /// Padding for bytemuck safe alignment
+    pub padding_1: [u8; 8],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ProtocolFee {
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct RewardInfo {
+    /// Reward token mint.
+    pub mint: Pubkey,
+    /// Reward vault token account.
+    pub vault: Pubkey,
+    /// Authority account that allows to fund rewards
+    pub funder: Pubkey,
+    pub reward_duration: u64,
+    pub reward_duration_end: u64,
+    pub reward_rate: u128,
+    /// The last time reward states were updated.
+    pub last_update_time: u64,
+    /// Accumulated seconds where when farm distribute rewards, but the bin is empty. The reward will be accumulated for next reward time window.
+    pub cumulative_seconds_with_empty_liquidity_reward: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Bin {
+    /// Amount of token X in the bin. This already excluded protocol fees.
+    pub amount_x: u64,
+    /// Amount of token Y in the bin. This already excluded protocol fees.
+    pub amount_y: u64,
+    /// Bin price
+    pub price: u128,
+    /// Liquidities of the bin. This is the same as LP mint supply. q-number
+    pub liquidity_supply: u128,
+    /// reward_a_per_token_stored
+    pub reward_per_token_stored: [u128; 2],
+    /// Swap fee amount of token X per liquidity deposited.
+    pub fee_amount_x_per_token_stored: u128,
+    /// Swap fee amount of token Y per liquidity deposited.
+    pub fee_amount_y_per_token_stored: u128,
+    /// Total token X swap into the bin. Only used for tracking purpose.
+    pub amount_x_in: u128,
+    /// Total token Y swap into he bin. Only used for tracking purpose.
+    pub amount_y_in: u128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct UserRewardInfo {
+    pub reward_per_token_completes: [u128; 2],
+    pub reward_pendings: [u64; 2],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FeeInfo {
+    pub fee_x_per_token_complete: u128,
+    pub fee_y_per_token_complete: u128,
+    pub fee_x_pending: u64,
+    pub fee_y_pending: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Observation {
+    /// Cumulative active bin id.
+    pub cumulative_active_bin_id: i128,
+    /// Observation sample created timestamp.
+    pub created_at: i64,
+    /// Observation sample last updated timestamp.
+    pub last_updated_at: i64,
+}
+
+// -----------------------------------------------------------------------------
+// Account structs
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct LbPair {
+    pub parameters: StaticParameters,
+    pub v_parameters: VariableParameters,
+    pub bump_seed: [u8; 1],
+    /// Bin step signer seed
+    pub bin_step_seed: [u8; 2],
+    /// Type of the pair
+    pub pair_type: u8,
+    /// Active bin id
+    pub active_id: i32,
+    /// Bin step. Represent the price increment / decrement.
+    pub bin_step: u16,
+    /// Status of the pair. Check PairStatus enum.
+    pub status: u8,
+    /// Require base factor seed
+    pub require_base_factor_seed: u8,
+    /// Base factor seed
+    pub base_factor_seed: [u8; 2],
+    /// Activation type
+    pub activation_type: u8,
+    /// Allow pool creator to enable/disable pool with restricted validation. Only applicable for customizable permissionless pair type.
+    pub creator_pool_on_off_control: u8,
+    /// Token X mint
+    pub token_x_mint: Pubkey,
+    /// Token Y mint
+    pub token_y_mint: Pubkey,
+    /// LB token X vault
+    pub reserve_x: Pubkey,
+    /// LB token Y vault
+    pub reserve_y: Pubkey,
+    /// Uncollected protocol fee
+    pub protocol_fee: ProtocolFee,
+    /// _padding_1, previous Fee owner
+    pub padding_1: [u8; 32],
+    /// Farming reward information
+    pub reward_infos: [RewardInfo; 2],
+    /// Oracle pubkey
+    pub oracle: Pubkey,
+    /// Packed initialized bin array state
+    pub bin_array_bitmap: [u64; 16],
+    /// Last time the pool fee parameter was updated
+    pub last_updated_at: i64,
+    /// _padding_2, previous whitelisted_wallet
+    pub padding_2: [u8; 32],
+    /// Address allowed to swap when the current point is greater than or equal to the pre-activation point. The pre-activation point is calculated as `activation_point - pre_activation_duration`.
+    pub pre_activation_swap_address: Pubkey,
+    /// Base keypair. Only required for permission pair
+    pub base_key: Pubkey,
+    /// Time point to enable the pair. Only applicable for permission pair.
+    pub activation_point: u64,
+    /// Duration before activation activation_point. Used to calculate pre-activation time point for pre_activation_swap_address
+    pub pre_activation_duration: u64,
+    /// _padding 3 is reclaimed free space from swap_cap_deactivate_point and swap_cap_amount before
+    pub padding_3: [u8; 8],
+    /// _padding_4, previous lock_duration
+    pub padding_4: u64,
+    /// Pool creator
+    pub creator: Pubkey,
+    /// token_mint_x_program_flag
+    pub token_mint_x_program_flag: u8,
+    /// token_mint_y_program_flag
+    pub token_mint_y_program_flag: u8,
+    /// Reserved space for future use
+    pub reserved: [u8; 22],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct BinArray {
+    pub index: i64,
+    /// Version of binArray
+    pub version: u8,
+    pub padding: [u8; 7],
+    pub lb_pair: Pubkey,
+    pub bins: [Bin; MAX_BIN_PER_ARRAY],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct BinArrayBitmapExtension {
+    pub lb_pair: Pubkey,
+    /// Packed initialized bin array state for start_bin_index is positive
+    pub positive_bin_array_bitmap: [[u64; 8]; 12],
+    /// Packed initialized bin array state for start_bin_index is negative
+    pub negative_bin_array_bitmap: [[u64; 8]; 12],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PositionV2 {
+    /// The LB pair of this position
+    pub lb_pair: Pubkey,
+    /// Owner of the position. Client rely on this to to fetch their positions.
+    pub owner: Pubkey,
+    /// Liquidity shares of this position in bins (lower_bin_id <-> upper_bin_id). This is the same as LP concept.
+    pub liquidity_shares: [u128; 70],
+    /// Farming reward information
+    pub reward_infos: [UserRewardInfo; 70],
+    /// Swap fee to claim information
+    pub fee_infos: [FeeInfo; 70],
+    /// Lower bin ID
+    pub lower_bin_id: i32,
+    /// Upper bin ID
+    pub upper_bin_id: i32,
+    /// Last updated timestamp
+    pub last_updated_at: i64,
+    /// Total claimed token fee X
+    pub total_claimed_fee_x_amount: u64,
+    /// Total claimed token fee Y
+    pub total_claimed_fee_y_amount: u64,
+    /// Total claimed rewards
+    pub total_claimed_rewards: [u64; 2],
+    /// Operator of position
+    pub operator: Pubkey,
+    /// Time point which the locked liquidity can be withdraw
+    pub lock_release_point: u64,
+    /// _padding_0, previous subjected_to_bootstrap_liquidity_locking
+    pub padding_0: u8,
+    /// Address is able to claim fee in this position, only valid for bootstrap_liquidity_position
+    pub fee_owner: Pubkey,
+    /// Reserved space for future use
+    pub reserved: [u8; 87],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PresetParameter2 {
+    /// Bin step. Represent the price increment / decrement.
+    pub bin_step: u16,
+    /// Used for base fee calculation. base_fee_rate = base_factor * bin_step * 10 * 10^base_fee_power_factor
+    pub base_factor: u16,
+    /// Filter period determine high frequency trading time window.
+    pub filter_period: u16,
+    /// Decay period determine when the volatile fee start decay / decrease.
+    pub decay_period: u16,
+    /// Used to scale the variable fee component depending on the dynamic of the market
+    pub variable_fee_control: u32,
+    /// Maximum number of bin crossed can be accumulated. Used to cap volatile fee rate.
+    pub max_volatility_accumulator: u32,
+    /// Reduction factor controls the volatile fee rate decrement rate.
+    pub reduction_factor: u16,
+    /// Portion of swap fees retained by the protocol by controlling protocol_share parameter. protocol_swap_fee = protocol_share * total_swap_fee
+    pub protocol_share: u16,
+    /// index
+    pub index: u16,
+    /// Base fee power factor
+    pub base_fee_power_factor: u8,
+    /// Padding 0 for future use
+    pub padding_0: u8,
+    /// Padding 1 for future use
+    pub padding_1: [u64; 20],
+}
+
+/// Oracle header followed by its initialized (`active_size`) samples.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Oracle {
+    /// Index of latest observation
+    pub idx: u64,
+    /// Size of active sample. Active sample is initialized observation.
+    pub active_size: u64,
+    /// Number of observations
+    pub length: u64,
+    pub observations: Vec<Observation>,
+}
+
+impl LbPair {
+    /// Price of bin `bin_id`, in atoms of token Y per atom of token X.
+    pub fn price_of_bin(&self, bin_id: i32) -> f64 {
+        (1.0 + self.bin_step as f64 / BASIS_POINT_MAX).powi(bin_id)
+    }
+
+    /// Price of the active bin, in atoms of token Y per atom of token X.
+    pub fn active_price(&self) -> f64 {
+        self.price_of_bin(self.active_id)
+    }
+}
+
+impl Bin {
+    /// `price` is a Q64.64 number of token Y atoms per token X atom.
+    pub fn price_f64(&self) -> f64 {
+        self.price as f64 / 2f64.powi(64)
+    }
+}
+
+impl BinArray {
+    /// Index of the bin array holding `bin_id`.
+    pub fn index_of(bin_id: i32) -> i64 {
+        (bin_id as i64).div_euclid(MAX_BIN_PER_ARRAY as i64)
+    }
+
+    /// Bin id of `bins[offset]`.
+    pub fn bin_id(&self, offset: usize) -> i32 {
+        self.index.saturating_mul(MAX_BIN_PER_ARRAY as i64).saturating_add(offset as i64) as i32
+    }
+
+    /// Bins holding liquidity, with their bin id.
+    pub fn non_empty_bins(&self) -> impl Iterator<Item = (i32, &Bin)> + '_ {
+        self.bins
+            .iter()
+            .enumerate()
+            .filter(|(_, bin)| bin.amount_x != 0 || bin.amount_y != 0)
+            .map(move |(offset, bin)| (self.bin_id(offset), bin))
+    }
+}
+
+impl PositionV2 {
+    /// Liquidity shares of the position with their bin id, from
+    /// `lower_bin_id` to `upper_bin_id`. Bins beyond the first
+    /// [`MAX_BIN_PER_ARRAY`] live in the account extension and are not
+    /// included.
+    pub fn shares(&self) -> impl Iterator<Item = (i32, u128)> + '_ {
+        let width = (self.upper_bin_id as i64 - self.lower_bin_id as i64 + 1).clamp(0, MAX_BIN_PER_ARRAY as i64) as usize;
+        self.liquidity_shares[..width]
+            .iter()
+            .enumerate()
+            .map(move |(offset, share)| (self.lower_bin_id.saturating_add(offset as i32), *share))
+    }
+}
+
+impl BorshDeserialize for Oracle {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let idx = u64::deserialize_reader(reader)?;
+        let active_size = u64::deserialize_reader(reader)?;
+        let length = u64::deserialize_reader(reader)?;
+        let observations = (0..length.min(active_size))
+            .map(|_| Observation::deserialize_reader(reader))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            idx,
+            active_size,
+            length,
+            observations,
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Account enumeration
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MeteoraDlmmAccount {
+    LbPair(Box<LbPair>),
+    BinArray(Box<BinArray>),
+    BinArrayBitmapExtension(Box<BinArrayBitmapExtension>),
+    PositionV2(Box<PositionV2>),
+    Oracle(Oracle),
+    PresetParameter2(PresetParameter2),
+}
+
+impl<'a> TryFrom<&'a [u8]> for MeteoraDlmmAccount {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() < 8 {
+            return Err(ParseError::TooShort(data.len()));
+        }
+        let (disc, mut rest) = data.split_at(8);
+        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
+        let rest = &mut rest;
+        Ok(match discriminator {
+            LB_PAIR => Self::LbPair(Box::new(LbPair::deserialize(rest)?)),
+            BIN_ARRAY => Self::BinArray(Box::new(BinArray::deserialize(rest)?)),
+            BIN_ARRAY_BITMAP_EXTENSION => Self::BinArrayBitmapExtension(Box::new(BinArrayBitmapExtension::deserialize(rest)?)),
+            POSITION_V2 => Self::PositionV2(Box::new(PositionV2::deserialize(rest)?)),
+            ORACLE => Self::Oracle(Oracle::deserialize(rest)?),
+            PRESET_PARAMETER2 => Self::PresetParameter2(PresetParameter2::deserialize(rest)?),
+            other => return Err(ParseError::Unknown(other)),
+        })
+    }
+}
+
+/// Convenience wrapper that forwards to `TryFrom`.
+pub fn unpack(data: &[u8]) -> Result<MeteoraDlmmAccount, ParseError> {
+    MeteoraDlmmAccount::try_from(data)
+}
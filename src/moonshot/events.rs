@@ -53,24 +53,10 @@ pub enum MoonshotEvent {
     Unknown,
 }
 
-impl<'a> TryFrom<&'a [u8]> for MoonshotEvent {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-
-        let (disc, payload) = data.split_at(8);
-        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
-
-        Ok(match discriminator {
-            TRADE_EVENT => Self::TradeEvent(TradeEvent::try_from_slice(payload)?),
-            MIGRATION_EVENT => Self::MigrationEvent(MigrationEvent::try_from_slice(payload)?),
-            other => return Err(ParseError::Unknown(other)),
-        })
-    }
-}
+anchor_events!(MoonshotEvent {
+    TRADE_EVENT => TradeEvent(TradeEvent),
+    MIGRATION_EVENT => MigrationEvent(MigrationEvent),
+});
 
 /// Convenience wrapper that forwards to `TryFrom`.
 pub fn unpack_event(data: &[u8]) -> Result<MoonshotEvent, ParseError> {
@@ -0,0 +1,310 @@
+//! Stake Program instruction accounts.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+use substreams_solana::block_view::InstructionView;
+
+use crate::common::accounts::{to_pubkey, AccountsError};
+
+// -----------------------------------------------------------------------------
+// Simple instructions
+// -----------------------------------------------------------------------------
+accounts!(
+    InitializeAccounts,
+    get_initialize_accounts,
+    { stake_account, rent_sysvar }
+);
+
+accounts!(
+    DelegateStakeAccounts,
+    get_delegate_stake_accounts,
+    {
+        stake_account,
+        vote_account,
+        clock_sysvar,
+        stake_history_sysvar,
+        /// Unused, kept for backwards compatibility
+        stake_config,
+        stake_authority
+    }
+);
+
+accounts!(
+    SplitAccounts,
+    get_split_accounts,
+    {
+        stake_account,
+        /// Uninitialized stake account receiving the split lamports
+        split_stake_account,
+        stake_authority
+    }
+);
+
+accounts!(
+    DeactivateAccounts,
+    get_deactivate_accounts,
+    { stake_account, clock_sysvar, stake_authority }
+);
+
+accounts!(
+    SetLockupAccounts,
+    get_set_lockup_accounts,
+    {
+        stake_account,
+        /// Lockup custodian while the lockup is in force, withdraw authority otherwise
+        lockup_or_withdraw_authority
+    }
+);
+
+accounts!(
+    MergeAccounts,
+    get_merge_accounts,
+    {
+        destination_stake_account,
+        /// Source stake account, drained and closed by the merge
+        source_stake_account,
+        clock_sysvar,
+        stake_history_sysvar,
+        stake_authority
+    }
+);
+
+accounts!(
+    InitializeCheckedAccounts,
+    get_initialize_checked_accounts,
+    { stake_account, rent_sysvar, staker, withdrawer }
+);
+
+accounts!(
+    DeactivateDelinquentAccounts,
+    get_deactivate_delinquent_accounts,
+    {
+        stake_account,
+        /// Delinquent vote account the stake is delegated to
+        delinquent_vote_account,
+        /// Vote account proving the delinquency
+        reference_vote_account
+    }
+);
+
+accounts!(
+    RedelegateAccounts,
+    get_redelegate_accounts,
+    { stake_account, uninitialized_stake_account, vote_account, stake_config, stake_authority }
+);
+
+accounts!(
+    MoveStakeAccounts,
+    get_move_stake_accounts,
+    { source_stake_account, destination_stake_account, stake_authority }
+);
+
+accounts!(
+    MoveLamportsAccounts,
+    get_move_lamports_accounts,
+    { source_stake_account, destination_stake_account, stake_authority }
+);
+
+// -----------------------------------------------------------------------------
+// Instructions with optional accounts
+// -----------------------------------------------------------------------------
+/// Accounts for the `Authorize` instruction.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct AuthorizeAccounts {
+    pub stake_account: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub authority: Pubkey,
+    /// Lockup custodian, required when updating the withdrawer during lockup.
+    pub lockup_authority: Option<Pubkey>,
+}
+
+impl<'ix> TryFrom<&InstructionView<'ix>> for AuthorizeAccounts {
+    type Error = AccountsError;
+
+    fn try_from(ix: &InstructionView<'ix>) -> Result<Self, Self::Error> {
+        let accounts = ix.accounts();
+        let get_req = |index: usize, name: &'static str| -> Result<Pubkey, AccountsError> {
+            let a = accounts.get(index).ok_or(AccountsError::Missing { name, index })?;
+            to_pubkey(name, index, a.0)
+        };
+        let get_opt = |index: usize| -> Option<Pubkey> { accounts.get(index).and_then(|a| a.0.as_slice().try_into().ok()).map(Pubkey::new_from_array) };
+        Ok(AuthorizeAccounts {
+            stake_account: get_req(0, "stake_account")?,
+            clock_sysvar: get_req(1, "clock_sysvar")?,
+            authority: get_req(2, "authority")?,
+            lockup_authority: get_opt(3),
+        })
+    }
+}
+
+pub fn get_authorize_accounts(ix: &InstructionView) -> Result<AuthorizeAccounts, AccountsError> {
+    AuthorizeAccounts::try_from(ix)
+}
+
+/// Accounts for the `Withdraw` instruction.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct WithdrawAccounts {
+    pub stake_account: Pubkey,
+    pub recipient: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub stake_history_sysvar: Pubkey,
+    pub withdraw_authority: Pubkey,
+    /// Lockup custodian, required when withdrawing during lockup.
+    pub lockup_authority: Option<Pubkey>,
+}
+
+impl<'ix> TryFrom<&InstructionView<'ix>> for WithdrawAccounts {
+    type Error = AccountsError;
+
+    fn try_from(ix: &InstructionView<'ix>) -> Result<Self, Self::Error> {
+        let accounts = ix.accounts();
+        let get_req = |index: usize, name: &'static str| -> Result<Pubkey, AccountsError> {
+            let a = accounts.get(index).ok_or(AccountsError::Missing { name, index })?;
+            to_pubkey(name, index, a.0)
+        };
+        let get_opt = |index: usize| -> Option<Pubkey> { accounts.get(index).and_then(|a| a.0.as_slice().try_into().ok()).map(Pubkey::new_from_array) };
+        Ok(WithdrawAccounts {
+            stake_account: get_req(0, "stake_account")?,
+            recipient: get_req(1, "recipient")?,
+            clock_sysvar: get_req(2, "clock_sysvar")?,
+            stake_history_sysvar: get_req(3, "stake_history_sysvar")?,
+            withdraw_authority: get_req(4, "withdraw_authority")?,
+            lockup_authority: get_opt(5),
+        })
+    }
+}
+
+pub fn get_withdraw_accounts(ix: &InstructionView) -> Result<WithdrawAccounts, AccountsError> {
+    WithdrawAccounts::try_from(ix)
+}
+
+/// Accounts for the `AuthorizeWithSeed` instruction.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct AuthorizeWithSeedAccounts {
+    pub stake_account: Pubkey,
+    pub authority_base: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub lockup_authority: Option<Pubkey>,
+}
+
+impl<'ix> TryFrom<&InstructionView<'ix>> for AuthorizeWithSeedAccounts {
+    type Error = AccountsError;
+
+    fn try_from(ix: &InstructionView<'ix>) -> Result<Self, Self::Error> {
+        let accounts = ix.accounts();
+        let get_req = |index: usize, name: &'static str| -> Result<Pubkey, AccountsError> {
+            let a = accounts.get(index).ok_or(AccountsError::Missing { name, index })?;
+            to_pubkey(name, index, a.0)
+        };
+        let get_opt = |index: usize| -> Option<Pubkey> { accounts.get(index).and_then(|a| a.0.as_slice().try_into().ok()).map(Pubkey::new_from_array) };
+        Ok(AuthorizeWithSeedAccounts {
+            stake_account: get_req(0, "stake_account")?,
+            authority_base: get_req(1, "authority_base")?,
+            clock_sysvar: get_req(2, "clock_sysvar")?,
+            lockup_authority: get_opt(3),
+        })
+    }
+}
+
+pub fn get_authorize_with_seed_accounts(ix: &InstructionView) -> Result<AuthorizeWithSeedAccounts, AccountsError> {
+    AuthorizeWithSeedAccounts::try_from(ix)
+}
+
+/// Accounts for the `AuthorizeChecked` instruction.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct AuthorizeCheckedAccounts {
+    pub stake_account: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub lockup_authority: Option<Pubkey>,
+}
+
+impl<'ix> TryFrom<&InstructionView<'ix>> for AuthorizeCheckedAccounts {
+    type Error = AccountsError;
+
+    fn try_from(ix: &InstructionView<'ix>) -> Result<Self, Self::Error> {
+        let accounts = ix.accounts();
+        let get_req = |index: usize, name: &'static str| -> Result<Pubkey, AccountsError> {
+            let a = accounts.get(index).ok_or(AccountsError::Missing { name, index })?;
+            to_pubkey(name, index, a.0)
+        };
+        let get_opt = |index: usize| -> Option<Pubkey> { accounts.get(index).and_then(|a| a.0.as_slice().try_into().ok()).map(Pubkey::new_from_array) };
+        Ok(AuthorizeCheckedAccounts {
+            stake_account: get_req(0, "stake_account")?,
+            clock_sysvar: get_req(1, "clock_sysvar")?,
+            authority: get_req(2, "authority")?,
+            new_authority: get_req(3, "new_authority")?,
+            lockup_authority: get_opt(4),
+        })
+    }
+}
+
+pub fn get_authorize_checked_accounts(ix: &InstructionView) -> Result<AuthorizeCheckedAccounts, AccountsError> {
+    AuthorizeCheckedAccounts::try_from(ix)
+}
+
+/// Accounts for the `AuthorizeCheckedWithSeed` instruction.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct AuthorizeCheckedWithSeedAccounts {
+    pub stake_account: Pubkey,
+    pub authority_base: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub new_authority: Pubkey,
+    pub lockup_authority: Option<Pubkey>,
+}
+
+impl<'ix> TryFrom<&InstructionView<'ix>> for AuthorizeCheckedWithSeedAccounts {
+    type Error = AccountsError;
+
+    fn try_from(ix: &InstructionView<'ix>) -> Result<Self, Self::Error> {
+        let accounts = ix.accounts();
+        let get_req = |index: usize, name: &'static str| -> Result<Pubkey, AccountsError> {
+            let a = accounts.get(index).ok_or(AccountsError::Missing { name, index })?;
+            to_pubkey(name, index, a.0)
+        };
+        let get_opt = |index: usize| -> Option<Pubkey> { accounts.get(index).and_then(|a| a.0.as_slice().try_into().ok()).map(Pubkey::new_from_array) };
+        Ok(AuthorizeCheckedWithSeedAccounts {
+            stake_account: get_req(0, "stake_account")?,
+            authority_base: get_req(1, "authority_base")?,
+            clock_sysvar: get_req(2, "clock_sysvar")?,
+            new_authority: get_req(3, "new_authority")?,
+            lockup_authority: get_opt(4),
+        })
+    }
+}
+
+pub fn get_authorize_checked_with_seed_accounts(ix: &InstructionView) -> Result<AuthorizeCheckedWithSeedAccounts, AccountsError> {
+    AuthorizeCheckedWithSeedAccounts::try_from(ix)
+}
+
+/// Accounts for the `SetLockupChecked` instruction.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct SetLockupCheckedAccounts {
+    pub stake_account: Pubkey,
+    pub lockup_or_withdraw_authority: Pubkey,
+    /// New lockup custodian, when one is being set.
+    pub new_lockup_authority: Option<Pubkey>,
+}
+
+impl<'ix> TryFrom<&InstructionView<'ix>> for SetLockupCheckedAccounts {
+    type Error = AccountsError;
+
+    fn try_from(ix: &InstructionView<'ix>) -> Result<Self, Self::Error> {
+        let accounts = ix.accounts();
+        let get_req = |index: usize, name: &'static str| -> Result<Pubkey, AccountsError> {
+            let a = accounts.get(index).ok_or(AccountsError::Missing { name, index })?;
+            to_pubkey(name, index, a.0)
+        };
+        let get_opt = |index: usize| -> Option<Pubkey> { accounts.get(index).and_then(|a| a.0.as_slice().try_into().ok()).map(Pubkey::new_from_array) };
+        Ok(SetLockupCheckedAccounts {
+            stake_account: get_req(0, "stake_account")?,
+            lockup_or_withdraw_authority: get_req(1, "lockup_or_withdraw_authority")?,
+            new_lockup_authority: get_opt(2),
+        })
+    }
+}
+
+pub fn get_set_lockup_checked_accounts(ix: &InstructionView) -> Result<SetLockupCheckedAccounts, AccountsError> {
+    SetLockupCheckedAccounts::try_from(ix)
+}
@@ -1,8 +1,11 @@
 //! Stake Program instructions.
 //!
-//! Uses a sequential little-endian u32 discriminator (first 4 bytes).
+//! Uses a sequential little-endian u32 discriminator (first 4 bytes) followed
+//! by a bincode-encoded payload.
 
+use crate::common::bincode::{invalid, BincodeReader};
 use crate::common::ParseError;
+use solana_program::pubkey::Pubkey;
 
 // Discriminators (little-endian u32)
 pub const INITIALIZE: [u8; 4] = [0, 0, 0, 0];
@@ -18,29 +21,164 @@ pub const INITIALIZE_CHECKED: [u8; 4] = [9, 0, 0, 0];
 pub const AUTHORIZE_CHECKED: [u8; 4] = [10, 0, 0, 0];
 pub const AUTHORIZE_CHECKED_WITH_SEED: [u8; 4] = [11, 0, 0, 0];
 pub const SET_LOCKUP_CHECKED: [u8; 4] = [12, 0, 0, 0];
+pub const GET_MINIMUM_DELEGATION: [u8; 4] = [13, 0, 0, 0];
+pub const DEACTIVATE_DELINQUENT: [u8; 4] = [14, 0, 0, 0];
+pub const REDELEGATE: [u8; 4] = [15, 0, 0, 0];
+pub const MOVE_STAKE: [u8; 4] = [16, 0, 0, 0];
+pub const MOVE_LAMPORTS: [u8; 4] = [17, 0, 0, 0];
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum StakeInstruction {
-    Initialize(RawPayload),
-    Authorize(RawPayload),
-    DelegateStake(RawPayload),
-    Split(RawPayload),
-    Withdraw(RawPayload),
-    Deactivate(RawPayload),
-    SetLockup(RawPayload),
-    Merge(RawPayload),
-    AuthorizeWithSeed(RawPayload),
-    InitializeChecked(RawPayload),
-    AuthorizeChecked(RawPayload),
-    AuthorizeCheckedWithSeed(RawPayload),
-    SetLockupChecked(RawPayload),
+    Initialize(InitializeInstruction),
+    Authorize(AuthorizeInstruction),
+    DelegateStake,
+    Split(SplitInstruction),
+    Withdraw(WithdrawInstruction),
+    Deactivate,
+    SetLockup(SetLockupInstruction),
+    Merge,
+    AuthorizeWithSeed(AuthorizeWithSeedInstruction),
+    InitializeChecked,
+    AuthorizeChecked(AuthorizeCheckedInstruction),
+    AuthorizeCheckedWithSeed(AuthorizeCheckedWithSeedInstruction),
+    SetLockupChecked(SetLockupCheckedInstruction),
+    GetMinimumDelegation,
+    DeactivateDelinquent,
+    /// Deprecated, disabled on mainnet.
+    Redelegate,
+    MoveStake(MoveStakeInstruction),
+    MoveLamports(MoveLamportsInstruction),
+}
+
+// -----------------------------------------------------------------------------
+// Shared types
+// -----------------------------------------------------------------------------
+
+/// Which of the two stake authorities an instruction targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StakeAuthorize {
+    Staker,
+    Withdrawer,
+}
+
+impl StakeAuthorize {
+    pub(crate) fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        match r.u32()? {
+            0 => Ok(Self::Staker),
+            1 => Ok(Self::Withdrawer),
+            other => Err(invalid(format!("invalid StakeAuthorize {other}"))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Authorized {
+    pub staker: Pubkey,
+    pub withdrawer: Pubkey,
+}
+
+impl Authorized {
+    pub(crate) fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(Self {
+            staker: r.pubkey()?,
+            withdrawer: r.pubkey()?,
+        })
+    }
+}
+
+/// Withdrawal restrictions; in force while either the timestamp or the epoch
+/// is in the future, unless the custodian signs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lockup {
+    pub unix_timestamp: i64,
+    pub epoch: u64,
+    pub custodian: Pubkey,
+}
+
+impl Lockup {
+    pub(crate) fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(Self {
+            unix_timestamp: r.i64()?,
+            epoch: r.u64()?,
+            custodian: r.pubkey()?,
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Payload structs
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitializeInstruction {
+    pub authorized: Authorized,
+    pub lockup: Lockup,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizeInstruction {
+    pub new_authority: Pubkey,
+    pub stake_authorize: StakeAuthorize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitInstruction {
+    pub lamports: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawInstruction {
+    pub lamports: u64,
+}
+
+/// Only the fields set to `Some` are updated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetLockupInstruction {
+    pub unix_timestamp: Option<i64>,
+    pub epoch: Option<u64>,
+    pub custodian: Option<Pubkey>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizeWithSeedInstruction {
+    pub new_authorized_pubkey: Pubkey,
+    pub stake_authorize: StakeAuthorize,
+    pub authority_seed: String,
+    pub authority_owner: Pubkey,
+}
+
+/// The new authority is passed as a signing account rather than in the payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizeCheckedInstruction {
+    pub stake_authorize: StakeAuthorize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizeCheckedWithSeedInstruction {
+    pub stake_authorize: StakeAuthorize,
+    pub authority_seed: String,
+    pub authority_owner: Pubkey,
+}
+
+/// The new custodian, if any, is passed as a signing account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetLockupCheckedInstruction {
+    pub unix_timestamp: Option<i64>,
+    pub epoch: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveStakeInstruction {
+    pub lamports: u64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct RawPayload {
-    pub data: Vec<u8>,
+pub struct MoveLamportsInstruction {
+    pub lamports: u64,
 }
 
+// -----------------------------------------------------------------------------
+// Bincode deserialisation helper
+// -----------------------------------------------------------------------------
 impl<'a> TryFrom<&'a [u8]> for StakeInstruction {
     type Error = ParseError;
 
@@ -50,21 +188,50 @@ impl<'a> TryFrom<&'a [u8]> for StakeInstruction {
         }
         let (disc, payload) = data.split_at(4);
         let disc: [u8; 4] = disc.try_into().unwrap();
-        let raw = RawPayload { data: payload.to_vec() };
+        let mut r = BincodeReader::new(payload);
         Ok(match disc {
-            INITIALIZE => Self::Initialize(raw),
-            AUTHORIZE => Self::Authorize(raw),
-            DELEGATE_STAKE => Self::DelegateStake(raw),
-            SPLIT => Self::Split(raw),
-            WITHDRAW => Self::Withdraw(raw),
-            DEACTIVATE => Self::Deactivate(raw),
-            SET_LOCKUP => Self::SetLockup(raw),
-            MERGE => Self::Merge(raw),
-            AUTHORIZE_WITH_SEED => Self::AuthorizeWithSeed(raw),
-            INITIALIZE_CHECKED => Self::InitializeChecked(raw),
-            AUTHORIZE_CHECKED => Self::AuthorizeChecked(raw),
-            AUTHORIZE_CHECKED_WITH_SEED => Self::AuthorizeCheckedWithSeed(raw),
-            SET_LOCKUP_CHECKED => Self::SetLockupChecked(raw),
+            INITIALIZE => Self::Initialize(InitializeInstruction {
+                authorized: Authorized::read(&mut r)?,
+                lockup: Lockup::read(&mut r)?,
+            }),
+            AUTHORIZE => Self::Authorize(AuthorizeInstruction {
+                new_authority: r.pubkey()?,
+                stake_authorize: StakeAuthorize::read(&mut r)?,
+            }),
+            DELEGATE_STAKE => Self::DelegateStake,
+            SPLIT => Self::Split(SplitInstruction { lamports: r.u64()? }),
+            WITHDRAW => Self::Withdraw(WithdrawInstruction { lamports: r.u64()? }),
+            DEACTIVATE => Self::Deactivate,
+            SET_LOCKUP => Self::SetLockup(SetLockupInstruction {
+                unix_timestamp: r.option(|r| r.i64())?,
+                epoch: r.option(|r| r.u64())?,
+                custodian: r.option(|r| r.pubkey())?,
+            }),
+            MERGE => Self::Merge,
+            AUTHORIZE_WITH_SEED => Self::AuthorizeWithSeed(AuthorizeWithSeedInstruction {
+                new_authorized_pubkey: r.pubkey()?,
+                stake_authorize: StakeAuthorize::read(&mut r)?,
+                authority_seed: r.string()?,
+                authority_owner: r.pubkey()?,
+            }),
+            INITIALIZE_CHECKED => Self::InitializeChecked,
+            AUTHORIZE_CHECKED => Self::AuthorizeChecked(AuthorizeCheckedInstruction {
+                stake_authorize: StakeAuthorize::read(&mut r)?,
+            }),
+            AUTHORIZE_CHECKED_WITH_SEED => Self::AuthorizeCheckedWithSeed(AuthorizeCheckedWithSeedInstruction {
+                stake_authorize: StakeAuthorize::read(&mut r)?,
+                authority_seed: r.string()?,
+                authority_owner: r.pubkey()?,
+            }),
+            SET_LOCKUP_CHECKED => Self::SetLockupChecked(SetLockupCheckedInstruction {
+                unix_timestamp: r.option(|r| r.i64())?,
+                epoch: r.option(|r| r.u64())?,
+            }),
+            GET_MINIMUM_DELEGATION => Self::GetMinimumDelegation,
+            DEACTIVATE_DELINQUENT => Self::DeactivateDelinquent,
+            REDELEGATE => Self::Redelegate,
+            MOVE_STAKE => Self::MoveStake(MoveStakeInstruction { lamports: r.u64()? }),
+            MOVE_LAMPORTS => Self::MoveLamports(MoveLamportsInstruction { lamports: r.u64()? }),
             _ => return Err(ParseError::Unknown([disc[0], disc[1], disc[2], disc[3], 0, 0, 0, 0])),
         })
     }
@@ -1,5 +1,7 @@
 use substreams_solana::b58;
+pub mod accounts;
 pub mod instructions;
+pub mod state;
 
 /// Stake Program
 ///
@@ -0,0 +1,96 @@
+//! Stake account state (`StakeStateV2`).
+//!
+//! Stake accounts are 200 bytes of bincode: a little-endian `u32` variant tag
+//! followed by the variant payload, zero-padded to the account size.
+
+use crate::common::bincode::{invalid, BincodeReader};
+use crate::common::ParseError;
+use solana_program::pubkey::Pubkey;
+
+pub use super::instructions::{Authorized, Lockup};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StakeStateV2 {
+    Uninitialized,
+    /// Initialized but not delegated.
+    Initialized(Meta),
+    /// Delegated to a vote account.
+    Stake(Meta, Stake, StakeFlags),
+    RewardsPool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Meta {
+    pub rent_exempt_reserve: u64,
+    pub authorized: Authorized,
+    pub lockup: Lockup,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stake {
+    pub delegation: Delegation,
+    /// Vote credits observed at the last reward payout.
+    pub credits_observed: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Delegation {
+    pub voter_pubkey: Pubkey,
+    /// Activated stake amount, in lamports.
+    pub stake: u64,
+    pub activation_epoch: u64,
+    /// `u64::MAX` while the stake has not been deactivated.
+    pub deactivation_epoch: u64,
+    /// Deprecated, no longer read by the runtime.
+    pub warmup_cooldown_rate: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StakeFlags {
+    pub bits: u8,
+}
+
+impl Meta {
+    fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(Self {
+            rent_exempt_reserve: r.u64()?,
+            authorized: Authorized::read(r)?,
+            lockup: Lockup::read(r)?,
+        })
+    }
+}
+
+impl Stake {
+    fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(Self {
+            delegation: Delegation {
+                voter_pubkey: r.pubkey()?,
+                stake: r.u64()?,
+                activation_epoch: r.u64()?,
+                deactivation_epoch: r.u64()?,
+                warmup_cooldown_rate: f64::from_le_bytes(r.array()?),
+            },
+            credits_observed: r.u64()?,
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for StakeStateV2 {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut r = BincodeReader::new(data);
+        Ok(match r.u32()? {
+            0 => Self::Uninitialized,
+            1 => Self::Initialized(Meta::read(&mut r)?),
+            2 => Self::Stake(Meta::read(&mut r)?, Stake::read(&mut r)?, StakeFlags { bits: r.u8()? }),
+            3 => Self::RewardsPool,
+            other => return Err(invalid(format!("invalid StakeStateV2 tag {other}"))),
+        })
+    }
+}
+
+/// Decodes the data of an account owned by the Stake program.
+pub fn unpack(data: &[u8]) -> Result<StakeStateV2, ParseError> {
+    StakeStateV2::try_from(data)
+}
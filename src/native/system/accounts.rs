@@ -0,0 +1,132 @@
+//! System Program instruction accounts.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+use substreams_solana::block_view::InstructionView;
+
+use crate::common::accounts::{to_pubkey, AccountsError};
+
+// -----------------------------------------------------------------------------
+// Simple instructions
+// -----------------------------------------------------------------------------
+accounts!(
+    CreateAccountAccounts,
+    get_create_account_accounts,
+    {
+        /// Funding account (signer, writable)
+        from,
+        /// New account (signer, writable)
+        new_account
+    }
+);
+
+accounts!(AssignAccounts, get_assign_accounts, {
+    /// Assigned account (signer, writable)
+    account
+});
+
+accounts!(
+    TransferAccounts,
+    get_transfer_accounts,
+    {
+        /// Funding account (signer, writable)
+        from,
+        /// Recipient account (writable)
+        to
+    }
+);
+
+accounts!(
+    AdvanceNonceAccountAccounts,
+    get_advance_nonce_account_accounts,
+    { nonce_account, recent_blockhashes_sysvar, nonce_authority }
+);
+
+accounts!(
+    WithdrawNonceAccountAccounts,
+    get_withdraw_nonce_account_accounts,
+    { nonce_account, to, recent_blockhashes_sysvar, rent_sysvar, nonce_authority }
+);
+
+accounts!(
+    InitializeNonceAccountAccounts,
+    get_initialize_nonce_account_accounts,
+    { nonce_account, recent_blockhashes_sysvar, rent_sysvar }
+);
+
+accounts!(
+    AuthorizeNonceAccountAccounts,
+    get_authorize_nonce_account_accounts,
+    { nonce_account, nonce_authority }
+);
+
+accounts!(AllocateAccounts, get_allocate_accounts, {
+    /// Account to allocate (signer, writable)
+    new_account
+});
+
+accounts!(
+    AllocateWithSeedAccounts,
+    get_allocate_with_seed_accounts,
+    { account, base }
+);
+
+accounts!(
+    AssignWithSeedAccounts,
+    get_assign_with_seed_accounts,
+    { account, base }
+);
+
+accounts!(
+    TransferWithSeedAccounts,
+    get_transfer_with_seed_accounts,
+    {
+        /// Funding account, derived from `base` (writable)
+        from,
+        /// Base account (signer)
+        base,
+        /// Recipient account (writable)
+        to
+    }
+);
+
+accounts!(UpgradeNonceAccountAccounts, get_upgrade_nonce_account_accounts, { nonce_account });
+
+// -----------------------------------------------------------------------------
+// Instructions with optional accounts
+// -----------------------------------------------------------------------------
+const IDX_CAWS_FROM: usize = 0;
+const IDX_CAWS_TO: usize = 1;
+const IDX_CAWS_BASE: usize = 2;
+
+/// Accounts for the `CreateAccountWithSeed` instruction.
+///
+/// `base` is only passed when it differs from the funding account.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CreateAccountWithSeedAccounts {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub base: Option<Pubkey>,
+}
+
+impl<'ix> TryFrom<&InstructionView<'ix>> for CreateAccountWithSeedAccounts {
+    type Error = AccountsError;
+
+    fn try_from(ix: &InstructionView<'ix>) -> Result<Self, Self::Error> {
+        let accounts = ix.accounts();
+        let get_req = |index: usize, name: &'static str| -> Result<Pubkey, AccountsError> {
+            let a = accounts.get(index).ok_or(AccountsError::Missing { name, index })?;
+            to_pubkey(name, index, a.0)
+        };
+        let get_opt = |index: usize| -> Option<Pubkey> { accounts.get(index).and_then(|a| a.0.as_slice().try_into().ok()).map(Pubkey::new_from_array) };
+        Ok(CreateAccountWithSeedAccounts {
+            from: get_req(IDX_CAWS_FROM, "from")?,
+            to: get_req(IDX_CAWS_TO, "to")?,
+            base: get_opt(IDX_CAWS_BASE),
+        })
+    }
+}
+
+pub fn get_create_account_with_seed_accounts(ix: &InstructionView) -> Result<CreateAccountWithSeedAccounts, AccountsError> {
+    CreateAccountWithSeedAccounts::try_from(ix)
+}
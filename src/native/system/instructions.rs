@@ -1,8 +1,11 @@
 //! System Program instructions.
 //!
-//! Uses a sequential little-endian u32 discriminator (first 4 bytes).
+//! Uses a sequential little-endian u32 discriminator (first 4 bytes) followed
+//! by a bincode-encoded payload.
 
+use crate::common::bincode::BincodeReader;
 use crate::common::ParseError;
+use solana_program::pubkey::Pubkey;
 
 // Discriminators (little-endian u32)
 pub const CREATE_ACCOUNT: [u8; 4] = [0, 0, 0, 0];
@@ -17,28 +20,111 @@ pub const ALLOCATE: [u8; 4] = [8, 0, 0, 0];
 pub const ALLOCATE_WITH_SEED: [u8; 4] = [9, 0, 0, 0];
 pub const ASSIGN_WITH_SEED: [u8; 4] = [10, 0, 0, 0];
 pub const TRANSFER_WITH_SEED: [u8; 4] = [11, 0, 0, 0];
+pub const UPGRADE_NONCE_ACCOUNT: [u8; 4] = [12, 0, 0, 0];
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SystemInstruction {
-    CreateAccount(RawPayload),
-    Assign(RawPayload),
-    Transfer(RawPayload),
-    CreateAccountWithSeed(RawPayload),
-    AdvanceNonceAccount(RawPayload),
-    WithdrawNonceAccount(RawPayload),
-    InitializeNonceAccount(RawPayload),
-    AuthorizeNonceAccount(RawPayload),
-    Allocate(RawPayload),
-    AllocateWithSeed(RawPayload),
-    AssignWithSeed(RawPayload),
-    TransferWithSeed(RawPayload),
+    CreateAccount(CreateAccountInstruction),
+    Assign(AssignInstruction),
+    Transfer(TransferInstruction),
+    CreateAccountWithSeed(CreateAccountWithSeedInstruction),
+    AdvanceNonceAccount,
+    WithdrawNonceAccount(WithdrawNonceAccountInstruction),
+    InitializeNonceAccount(InitializeNonceAccountInstruction),
+    AuthorizeNonceAccount(AuthorizeNonceAccountInstruction),
+    Allocate(AllocateInstruction),
+    AllocateWithSeed(AllocateWithSeedInstruction),
+    AssignWithSeed(AssignWithSeedInstruction),
+    TransferWithSeed(TransferWithSeedInstruction),
+    UpgradeNonceAccount,
 }
 
+// -----------------------------------------------------------------------------
+// Payload structs
+// -----------------------------------------------------------------------------
+
+/// Create a new account funded with `lamports` and owned by `owner`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateAccountInstruction {
+    pub lamports: u64,
+    pub space: u64,
+    pub owner: Pubkey,
+}
+
+/// Assign the account to a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssignInstruction {
+    pub owner: Pubkey,
+}
+
+/// Transfer lamports between system accounts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferInstruction {
+    pub lamports: u64,
+}
+
+/// Create an account at an address derived from `base` and `seed`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateAccountWithSeedInstruction {
+    pub base: Pubkey,
+    pub seed: String,
+    pub lamports: u64,
+    pub space: u64,
+    pub owner: Pubkey,
+}
+
+/// Withdraw lamports from a nonce account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawNonceAccountInstruction {
+    pub lamports: u64,
+}
+
+/// Initialize a nonce account with its authority.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitializeNonceAccountInstruction {
+    pub nonce_authority: Pubkey,
+}
+
+/// Change the authority of a nonce account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizeNonceAccountInstruction {
+    pub new_nonce_authority: Pubkey,
+}
+
+/// Allocate `space` bytes for the account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllocateInstruction {
+    pub space: u64,
+}
+
+/// Allocate space for an account at an address derived from `base` and `seed`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllocateWithSeedInstruction {
+    pub base: Pubkey,
+    pub seed: String,
+    pub space: u64,
+    pub owner: Pubkey,
+}
+
+/// Assign an account at an address derived from `base` and `seed`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssignWithSeedInstruction {
+    pub base: Pubkey,
+    pub seed: String,
+    pub owner: Pubkey,
+}
+
+/// Transfer lamports from an account at an address derived from `base`.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct RawPayload {
-    pub data: Vec<u8>,
+pub struct TransferWithSeedInstruction {
+    pub lamports: u64,
+    pub from_seed: String,
+    pub from_owner: Pubkey,
 }
 
+// -----------------------------------------------------------------------------
+// Bincode deserialisation helper
+// -----------------------------------------------------------------------------
 impl<'a> TryFrom<&'a [u8]> for SystemInstruction {
     type Error = ParseError;
 
@@ -48,20 +134,46 @@ impl<'a> TryFrom<&'a [u8]> for SystemInstruction {
         }
         let (disc, payload) = data.split_at(4);
         let disc: [u8; 4] = disc.try_into().unwrap();
-        let raw = RawPayload { data: payload.to_vec() };
+        let mut r = BincodeReader::new(payload);
         Ok(match disc {
-            CREATE_ACCOUNT => Self::CreateAccount(raw),
-            ASSIGN => Self::Assign(raw),
-            TRANSFER => Self::Transfer(raw),
-            CREATE_ACCOUNT_WITH_SEED => Self::CreateAccountWithSeed(raw),
-            ADVANCE_NONCE_ACCOUNT => Self::AdvanceNonceAccount(raw),
-            WITHDRAW_NONCE_ACCOUNT => Self::WithdrawNonceAccount(raw),
-            INITIALIZE_NONCE_ACCOUNT => Self::InitializeNonceAccount(raw),
-            AUTHORIZE_NONCE_ACCOUNT => Self::AuthorizeNonceAccount(raw),
-            ALLOCATE => Self::Allocate(raw),
-            ALLOCATE_WITH_SEED => Self::AllocateWithSeed(raw),
-            ASSIGN_WITH_SEED => Self::AssignWithSeed(raw),
-            TRANSFER_WITH_SEED => Self::TransferWithSeed(raw),
+            CREATE_ACCOUNT => Self::CreateAccount(CreateAccountInstruction {
+                lamports: r.u64()?,
+                space: r.u64()?,
+                owner: r.pubkey()?,
+            }),
+            ASSIGN => Self::Assign(AssignInstruction { owner: r.pubkey()? }),
+            TRANSFER => Self::Transfer(TransferInstruction { lamports: r.u64()? }),
+            CREATE_ACCOUNT_WITH_SEED => Self::CreateAccountWithSeed(CreateAccountWithSeedInstruction {
+                base: r.pubkey()?,
+                seed: r.string()?,
+                lamports: r.u64()?,
+                space: r.u64()?,
+                owner: r.pubkey()?,
+            }),
+            ADVANCE_NONCE_ACCOUNT => Self::AdvanceNonceAccount,
+            WITHDRAW_NONCE_ACCOUNT => Self::WithdrawNonceAccount(WithdrawNonceAccountInstruction { lamports: r.u64()? }),
+            INITIALIZE_NONCE_ACCOUNT => Self::InitializeNonceAccount(InitializeNonceAccountInstruction { nonce_authority: r.pubkey()? }),
+            AUTHORIZE_NONCE_ACCOUNT => Self::AuthorizeNonceAccount(AuthorizeNonceAccountInstruction {
+                new_nonce_authority: r.pubkey()?,
+            }),
+            ALLOCATE => Self::Allocate(AllocateInstruction { space: r.u64()? }),
+            ALLOCATE_WITH_SEED => Self::AllocateWithSeed(AllocateWithSeedInstruction {
+                base: r.pubkey()?,
+                seed: r.string()?,
+                space: r.u64()?,
+                owner: r.pubkey()?,
+            }),
+            ASSIGN_WITH_SEED => Self::AssignWithSeed(AssignWithSeedInstruction {
+                base: r.pubkey()?,
+                seed: r.string()?,
+                owner: r.pubkey()?,
+            }),
+            TRANSFER_WITH_SEED => Self::TransferWithSeed(TransferWithSeedInstruction {
+                lamports: r.u64()?,
+                from_seed: r.string()?,
+                from_owner: r.pubkey()?,
+            }),
+            UPGRADE_NONCE_ACCOUNT => Self::UpgradeNonceAccount,
             _ => return Err(ParseError::Unknown([disc[0], disc[1], disc[2], disc[3], 0, 0, 0, 0])),
         })
     }
@@ -1,4 +1,5 @@
 use substreams_solana::b58;
+pub mod accounts;
 pub mod instructions;
 
 /// System Program
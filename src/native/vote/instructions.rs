@@ -1,8 +1,12 @@
 //! Vote Program instructions.
 //!
-//! Uses a sequential little-endian u32 discriminator (first 4 bytes).
+//! Uses a sequential little-endian u32 discriminator (first 4 bytes) followed
+//! by a bincode-encoded payload. The `Compact*` and `TowerSync*` variants encode
+//! their lockouts as slot offsets from the root slot.
 
+use crate::common::bincode::{invalid, BincodeReader};
 use crate::common::ParseError;
+use solana_program::pubkey::Pubkey;
 
 // Discriminators (little-endian u32)
 pub const INITIALIZE: [u8; 4] = [0, 0, 0, 0];
@@ -24,29 +28,222 @@ pub const TOWER_SYNC_SWITCH: [u8; 4] = [15, 0, 0, 0];
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum VoteInstruction {
-    Initialize(RawPayload),
-    Authorize(RawPayload),
-    Vote(RawPayload),
-    Withdraw(RawPayload),
-    UpdateValidatorIdentity(RawPayload),
-    UpdateCommission(RawPayload),
-    VoteSwitch(RawPayload),
-    AuthorizeChecked(RawPayload),
-    UpdateVoteState(RawPayload),
-    UpdateVoteStateSwitch(RawPayload),
-    AuthorizeWithSeed(RawPayload),
-    AuthorizeCheckedWithSeed(RawPayload),
-    CompactUpdateVoteState(RawPayload),
-    CompactUpdateVoteStateSwitch(RawPayload),
-    TowerSync(RawPayload),
-    TowerSyncSwitch(RawPayload),
+    Initialize(InitializeInstruction),
+    Authorize(AuthorizeInstruction),
+    Vote(Vote),
+    Withdraw(WithdrawInstruction),
+    /// The new identity is passed as a signing account.
+    UpdateValidatorIdentity,
+    UpdateCommission(UpdateCommissionInstruction),
+    VoteSwitch(VoteSwitchInstruction),
+    AuthorizeChecked(AuthorizeCheckedInstruction),
+    UpdateVoteState(VoteStateUpdate),
+    UpdateVoteStateSwitch(UpdateVoteStateSwitchInstruction),
+    AuthorizeWithSeed(AuthorizeWithSeedInstruction),
+    AuthorizeCheckedWithSeed(AuthorizeCheckedWithSeedInstruction),
+    CompactUpdateVoteState(VoteStateUpdate),
+    CompactUpdateVoteStateSwitch(UpdateVoteStateSwitchInstruction),
+    TowerSync(TowerSync),
+    TowerSyncSwitch(TowerSyncSwitchInstruction),
+}
+
+// -----------------------------------------------------------------------------
+// Shared types
+// -----------------------------------------------------------------------------
+
+/// Which of the two vote authorities an instruction targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoteAuthorize {
+    Voter,
+    Withdrawer,
+}
+
+impl VoteAuthorize {
+    fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        match r.u32()? {
+            0 => Ok(Self::Voter),
+            1 => Ok(Self::Withdrawer),
+            other => Err(invalid(format!("invalid VoteAuthorize {other}"))),
+        }
+    }
+}
+
+/// A voted slot and the number of votes stacked on top of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lockout {
+    pub slot: u64,
+    pub confirmation_count: u32,
+}
+
+impl Lockout {
+    pub(crate) fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(Self {
+            slot: r.u64()?,
+            confirmation_count: r.u32()?,
+        })
+    }
+}
+
+/// Legacy vote: a list of slots voted on, ending at the bank `hash`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vote {
+    pub slots: Vec<u64>,
+    pub hash: [u8; 32],
+    pub timestamp: Option<i64>,
+}
+
+impl Vote {
+    fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(Self {
+            slots: r.vec(|r| r.u64())?,
+            hash: r.array()?,
+            timestamp: r.option(|r| r.i64())?,
+        })
+    }
+}
+
+/// Full tower replacement, shared by the `UpdateVoteState*` and
+/// `CompactUpdateVoteState*` instructions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoteStateUpdate {
+    pub lockouts: Vec<Lockout>,
+    pub root: Option<u64>,
+    pub hash: [u8; 32],
+    pub timestamp: Option<i64>,
+}
+
+impl VoteStateUpdate {
+    fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(Self {
+            lockouts: r.vec(Lockout::read)?,
+            root: r.option(|r| r.u64())?,
+            hash: r.array()?,
+            timestamp: r.option(|r| r.i64())?,
+        })
+    }
+
+    fn read_compact(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        let (root, lockouts) = read_compact_lockouts(r)?;
+        Ok(Self {
+            lockouts,
+            root,
+            hash: r.array()?,
+            timestamp: r.option(|r| r.i64())?,
+        })
+    }
+}
+
+/// Like [`VoteStateUpdate`], plus the id of the voted block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TowerSync {
+    pub lockouts: Vec<Lockout>,
+    pub root: Option<u64>,
+    pub hash: [u8; 32],
+    pub timestamp: Option<i64>,
+    pub block_id: [u8; 32],
+}
+
+impl TowerSync {
+    fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        let (root, lockouts) = read_compact_lockouts(r)?;
+        Ok(Self {
+            lockouts,
+            root,
+            hash: r.array()?,
+            timestamp: r.option(|r| r.i64())?,
+            block_id: r.array()?,
+        })
+    }
+}
+
+/// Compact lockout encoding: the root slot (`u64::MAX` for none) followed by a
+/// `short_vec` of `(varint slot offset, u8 confirmation count)` pairs, each
+/// offset relative to the previous slot, starting from the root.
+fn read_compact_lockouts(r: &mut BincodeReader) -> Result<(Option<u64>, Vec<Lockout>), ParseError> {
+    let root = Some(r.u64()?).filter(|root| *root != u64::MAX);
+    let mut slot = root.unwrap_or_default();
+    let lockouts = r.short_vec(|r| {
+        let offset = r.varint_u64()?;
+        slot = slot.checked_add(offset).ok_or_else(|| invalid("lockout slot overflows u64".to_string()))?;
+        Ok(Lockout {
+            slot,
+            confirmation_count: u32::from(r.u8()?),
+        })
+    })?;
+    Ok((root, lockouts))
+}
+
+// -----------------------------------------------------------------------------
+// Payload structs
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitializeInstruction {
+    pub node_pubkey: Pubkey,
+    pub authorized_voter: Pubkey,
+    pub authorized_withdrawer: Pubkey,
+    pub commission: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizeInstruction {
+    pub new_authority: Pubkey,
+    pub vote_authorize: VoteAuthorize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawInstruction {
+    pub lamports: u64,
+}
+
+/// New commission, as a percentage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateCommissionInstruction {
+    pub commission: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoteSwitchInstruction {
+    pub vote: Vote,
+    /// Hash of the switching proof.
+    pub proof_hash: [u8; 32],
+}
+
+/// The new authority is passed as a signing account rather than in the payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizeCheckedInstruction {
+    pub vote_authorize: VoteAuthorize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateVoteStateSwitchInstruction {
+    pub vote_state_update: VoteStateUpdate,
+    pub proof_hash: [u8; 32],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizeWithSeedInstruction {
+    pub authorization_type: VoteAuthorize,
+    pub current_authority_derived_key_owner: Pubkey,
+    pub current_authority_derived_key_seed: String,
+    pub new_authority: Pubkey,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizeCheckedWithSeedInstruction {
+    pub authorization_type: VoteAuthorize,
+    pub current_authority_derived_key_owner: Pubkey,
+    pub current_authority_derived_key_seed: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct RawPayload {
-    pub data: Vec<u8>,
+pub struct TowerSyncSwitchInstruction {
+    pub tower_sync: TowerSync,
+    pub proof_hash: [u8; 32],
 }
 
+// -----------------------------------------------------------------------------
+// Bincode deserialisation helper
+// -----------------------------------------------------------------------------
 impl<'a> TryFrom<&'a [u8]> for VoteInstruction {
     type Error = ParseError;
 
@@ -56,24 +253,55 @@ impl<'a> TryFrom<&'a [u8]> for VoteInstruction {
         }
         let (disc, payload) = data.split_at(4);
         let disc: [u8; 4] = disc.try_into().unwrap();
-        let raw = RawPayload { data: payload.to_vec() };
+        let mut r = BincodeReader::new(payload);
         Ok(match disc {
-            INITIALIZE => Self::Initialize(raw),
-            AUTHORIZE => Self::Authorize(raw),
-            VOTE => Self::Vote(raw),
-            WITHDRAW => Self::Withdraw(raw),
-            UPDATE_VALIDATOR_IDENTITY => Self::UpdateValidatorIdentity(raw),
-            UPDATE_COMMISSION => Self::UpdateCommission(raw),
-            VOTE_SWITCH => Self::VoteSwitch(raw),
-            AUTHORIZE_CHECKED => Self::AuthorizeChecked(raw),
-            UPDATE_VOTE_STATE => Self::UpdateVoteState(raw),
-            UPDATE_VOTE_STATE_SWITCH => Self::UpdateVoteStateSwitch(raw),
-            AUTHORIZE_WITH_SEED => Self::AuthorizeWithSeed(raw),
-            AUTHORIZE_CHECKED_WITH_SEED => Self::AuthorizeCheckedWithSeed(raw),
-            COMPACT_UPDATE_VOTE_STATE => Self::CompactUpdateVoteState(raw),
-            COMPACT_UPDATE_VOTE_STATE_SWITCH => Self::CompactUpdateVoteStateSwitch(raw),
-            TOWER_SYNC => Self::TowerSync(raw),
-            TOWER_SYNC_SWITCH => Self::TowerSyncSwitch(raw),
+            INITIALIZE => Self::Initialize(InitializeInstruction {
+                node_pubkey: r.pubkey()?,
+                authorized_voter: r.pubkey()?,
+                authorized_withdrawer: r.pubkey()?,
+                commission: r.u8()?,
+            }),
+            AUTHORIZE => Self::Authorize(AuthorizeInstruction {
+                new_authority: r.pubkey()?,
+                vote_authorize: VoteAuthorize::read(&mut r)?,
+            }),
+            VOTE => Self::Vote(Vote::read(&mut r)?),
+            WITHDRAW => Self::Withdraw(WithdrawInstruction { lamports: r.u64()? }),
+            UPDATE_VALIDATOR_IDENTITY => Self::UpdateValidatorIdentity,
+            UPDATE_COMMISSION => Self::UpdateCommission(UpdateCommissionInstruction { commission: r.u8()? }),
+            VOTE_SWITCH => Self::VoteSwitch(VoteSwitchInstruction {
+                vote: Vote::read(&mut r)?,
+                proof_hash: r.array()?,
+            }),
+            AUTHORIZE_CHECKED => Self::AuthorizeChecked(AuthorizeCheckedInstruction {
+                vote_authorize: VoteAuthorize::read(&mut r)?,
+            }),
+            UPDATE_VOTE_STATE => Self::UpdateVoteState(VoteStateUpdate::read(&mut r)?),
+            UPDATE_VOTE_STATE_SWITCH => Self::UpdateVoteStateSwitch(UpdateVoteStateSwitchInstruction {
+                vote_state_update: VoteStateUpdate::read(&mut r)?,
+                proof_hash: r.array()?,
+            }),
+            AUTHORIZE_WITH_SEED => Self::AuthorizeWithSeed(AuthorizeWithSeedInstruction {
+                authorization_type: VoteAuthorize::read(&mut r)?,
+                current_authority_derived_key_owner: r.pubkey()?,
+                current_authority_derived_key_seed: r.string()?,
+                new_authority: r.pubkey()?,
+            }),
+            AUTHORIZE_CHECKED_WITH_SEED => Self::AuthorizeCheckedWithSeed(AuthorizeCheckedWithSeedInstruction {
+                authorization_type: VoteAuthorize::read(&mut r)?,
+                current_authority_derived_key_owner: r.pubkey()?,
+                current_authority_derived_key_seed: r.string()?,
+            }),
+            COMPACT_UPDATE_VOTE_STATE => Self::CompactUpdateVoteState(VoteStateUpdate::read_compact(&mut r)?),
+            COMPACT_UPDATE_VOTE_STATE_SWITCH => Self::CompactUpdateVoteStateSwitch(UpdateVoteStateSwitchInstruction {
+                vote_state_update: VoteStateUpdate::read_compact(&mut r)?,
+                proof_hash: r.array()?,
+            }),
+            TOWER_SYNC => Self::TowerSync(TowerSync::read(&mut r)?),
+            TOWER_SYNC_SWITCH => Self::TowerSyncSwitch(TowerSyncSwitchInstruction {
+                tower_sync: TowerSync::read(&mut r)?,
+                proof_hash: r.array()?,
+            }),
             _ => return Err(ParseError::Unknown([disc[0], disc[1], disc[2], disc[3], 0, 0, 0, 0])),
         })
     }
@@ -0,0 +1,275 @@
+//! Vote account state (`VoteStateVersions`).
+//!
+//! Vote accounts hold bincode: a little-endian `u32` version tag followed by the
+//! state for that version, zero-padded to the account size.
+
+use crate::common::bincode::{invalid, BincodeReader};
+use crate::common::ParseError;
+use solana_program::pubkey::Pubkey;
+
+pub use super::instructions::Lockout;
+
+/// Number of entries in the `prior_voters` ring buffer.
+pub const MAX_PRIOR_VOTERS: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VoteStateVersions {
+    V0_23_5(Box<VoteState0_23_5>),
+    V1_14_11(Box<VoteState1_14_11>),
+    Current(Box<VoteState>),
+}
+
+/// Current layout, with per-vote latency.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoteState {
+    pub node_pubkey: Pubkey,
+    pub authorized_withdrawer: Pubkey,
+    /// Percentage of rewards kept by the validator.
+    pub commission: u8,
+    pub votes: Vec<LandedVote>,
+    pub root_slot: Option<u64>,
+    pub authorized_voters: Vec<AuthorizedVoter>,
+    pub prior_voters: PriorVoters,
+    pub epoch_credits: Vec<EpochCredits>,
+    pub last_timestamp: BlockTimestamp,
+}
+
+/// Layout used from v1.14.11 until vote latency was added.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoteState1_14_11 {
+    pub node_pubkey: Pubkey,
+    pub authorized_withdrawer: Pubkey,
+    pub commission: u8,
+    pub votes: Vec<Lockout>,
+    pub root_slot: Option<u64>,
+    pub authorized_voters: Vec<AuthorizedVoter>,
+    pub prior_voters: PriorVoters,
+    pub epoch_credits: Vec<EpochCredits>,
+    pub last_timestamp: BlockTimestamp,
+}
+
+/// Original layout, with a single authorized voter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoteState0_23_5 {
+    pub node_pubkey: Pubkey,
+    pub authorized_voter: Pubkey,
+    pub authorized_voter_epoch: u64,
+    /// `(authorized_voter, epoch_start, epoch_end, slot)` ring buffer.
+    pub prior_voters: Vec<(Pubkey, u64, u64, u64)>,
+    pub prior_voters_idx: u64,
+    pub authorized_withdrawer: Pubkey,
+    pub commission: u8,
+    pub votes: Vec<Lockout>,
+    pub root_slot: Option<u64>,
+    pub epoch_credits: Vec<EpochCredits>,
+    pub last_timestamp: BlockTimestamp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LandedVote {
+    /// Slots between the voted slot and the slot the vote landed in.
+    pub latency: u8,
+    pub lockout: Lockout,
+}
+
+/// Voter authorized starting at `epoch`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuthorizedVoter {
+    pub epoch: u64,
+    pub voter: Pubkey,
+}
+
+/// Ring buffer of the previous authorized voters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriorVoters {
+    /// `(authorized_voter, epoch_start, epoch_end)` entries.
+    pub buf: Vec<(Pubkey, u64, u64)>,
+    pub idx: u64,
+    pub is_empty: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EpochCredits {
+    pub epoch: u64,
+    pub credits: u64,
+    pub prev_credits: u64,
+}
+
+/// Last timestamp submitted with a vote.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockTimestamp {
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+impl VoteStateVersions {
+    pub fn node_pubkey(&self) -> &Pubkey {
+        match self {
+            Self::V0_23_5(state) => &state.node_pubkey,
+            Self::V1_14_11(state) => &state.node_pubkey,
+            Self::Current(state) => &state.node_pubkey,
+        }
+    }
+
+    pub fn authorized_withdrawer(&self) -> &Pubkey {
+        match self {
+            Self::V0_23_5(state) => &state.authorized_withdrawer,
+            Self::V1_14_11(state) => &state.authorized_withdrawer,
+            Self::Current(state) => &state.authorized_withdrawer,
+        }
+    }
+
+    pub fn commission(&self) -> u8 {
+        match self {
+            Self::V0_23_5(state) => state.commission,
+            Self::V1_14_11(state) => state.commission,
+            Self::Current(state) => state.commission,
+        }
+    }
+
+    pub fn root_slot(&self) -> Option<u64> {
+        match self {
+            Self::V0_23_5(state) => state.root_slot,
+            Self::V1_14_11(state) => state.root_slot,
+            Self::Current(state) => state.root_slot,
+        }
+    }
+
+    pub fn epoch_credits(&self) -> &[EpochCredits] {
+        match self {
+            Self::V0_23_5(state) => &state.epoch_credits,
+            Self::V1_14_11(state) => &state.epoch_credits,
+            Self::Current(state) => &state.epoch_credits,
+        }
+    }
+
+    pub fn last_timestamp(&self) -> &BlockTimestamp {
+        match self {
+            Self::V0_23_5(state) => &state.last_timestamp,
+            Self::V1_14_11(state) => &state.last_timestamp,
+            Self::Current(state) => &state.last_timestamp,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Bincode deserialisation helpers
+// -----------------------------------------------------------------------------
+fn read_authorized_voters(r: &mut BincodeReader) -> Result<Vec<AuthorizedVoter>, ParseError> {
+    r.vec(|r| {
+        Ok(AuthorizedVoter {
+            epoch: r.u64()?,
+            voter: r.pubkey()?,
+        })
+    })
+}
+
+fn read_prior_voters(r: &mut BincodeReader) -> Result<PriorVoters, ParseError> {
+    let mut buf = Vec::with_capacity(MAX_PRIOR_VOTERS);
+    for _ in 0..MAX_PRIOR_VOTERS {
+        buf.push((r.pubkey()?, r.u64()?, r.u64()?));
+    }
+    Ok(PriorVoters {
+        buf,
+        idx: r.u64()?,
+        is_empty: r.bool()?,
+    })
+}
+
+fn read_epoch_credits(r: &mut BincodeReader) -> Result<Vec<EpochCredits>, ParseError> {
+    r.vec(|r| {
+        Ok(EpochCredits {
+            epoch: r.u64()?,
+            credits: r.u64()?,
+            prev_credits: r.u64()?,
+        })
+    })
+}
+
+fn read_block_timestamp(r: &mut BincodeReader) -> Result<BlockTimestamp, ParseError> {
+    Ok(BlockTimestamp {
+        slot: r.u64()?,
+        timestamp: r.i64()?,
+    })
+}
+
+impl VoteState {
+    fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(Self {
+            node_pubkey: r.pubkey()?,
+            authorized_withdrawer: r.pubkey()?,
+            commission: r.u8()?,
+            votes: r.vec(|r| {
+                Ok(LandedVote {
+                    latency: r.u8()?,
+                    lockout: Lockout::read(r)?,
+                })
+            })?,
+            root_slot: r.option(|r| r.u64())?,
+            authorized_voters: read_authorized_voters(r)?,
+            prior_voters: read_prior_voters(r)?,
+            epoch_credits: read_epoch_credits(r)?,
+            last_timestamp: read_block_timestamp(r)?,
+        })
+    }
+}
+
+impl VoteState1_14_11 {
+    fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(Self {
+            node_pubkey: r.pubkey()?,
+            authorized_withdrawer: r.pubkey()?,
+            commission: r.u8()?,
+            votes: r.vec(Lockout::read)?,
+            root_slot: r.option(|r| r.u64())?,
+            authorized_voters: read_authorized_voters(r)?,
+            prior_voters: read_prior_voters(r)?,
+            epoch_credits: read_epoch_credits(r)?,
+            last_timestamp: read_block_timestamp(r)?,
+        })
+    }
+}
+
+impl VoteState0_23_5 {
+    fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        let node_pubkey = r.pubkey()?;
+        let authorized_voter = r.pubkey()?;
+        let authorized_voter_epoch = r.u64()?;
+        let mut prior_voters = Vec::with_capacity(MAX_PRIOR_VOTERS);
+        for _ in 0..MAX_PRIOR_VOTERS {
+            prior_voters.push((r.pubkey()?, r.u64()?, r.u64()?, r.u64()?));
+        }
+        Ok(Self {
+            node_pubkey,
+            authorized_voter,
+            authorized_voter_epoch,
+            prior_voters,
+            prior_voters_idx: r.u64()?,
+            authorized_withdrawer: r.pubkey()?,
+            commission: r.u8()?,
+            votes: r.vec(Lockout::read)?,
+            root_slot: r.option(|r| r.u64())?,
+            epoch_credits: read_epoch_credits(r)?,
+            last_timestamp: read_block_timestamp(r)?,
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for VoteStateVersions {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut r = BincodeReader::new(data);
+        Ok(match r.u32()? {
+            0 => Self::V0_23_5(Box::new(VoteState0_23_5::read(&mut r)?)),
+            1 => Self::V1_14_11(Box::new(VoteState1_14_11::read(&mut r)?)),
+            2 => Self::Current(Box::new(VoteState::read(&mut r)?)),
+            other => return Err(invalid(format!("invalid VoteStateVersions tag {other}"))),
+        })
+    }
+}
+
+/// Decodes the data of an account owned by the Vote program.
+pub fn unpack(data: &[u8]) -> Result<VoteStateVersions, ParseError> {
+    VoteStateVersions::try_from(data)
+}
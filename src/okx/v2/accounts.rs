@@ -4,7 +4,8 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 use substreams_solana::block_view::InstructionView;
 
-use crate::common::accounts;
+use super::instructions::{Dex, SwapArgs, SwapV3Instruction};
+use crate::common::accounts::{to_pubkey, AccountsError};
 
 // -----------------------------------------------------------------------------
 // Swap V3 accounts (fixed prefix accounts before dynamic route accounts)
@@ -25,3 +26,122 @@ accounts!(
         destination_mint
     }
 );
+
+// -----------------------------------------------------------------------------
+// Route hops (dynamic accounts after the `swap_v3` fixed accounts)
+// -----------------------------------------------------------------------------
+/// Number of `swap_v3` accounts before the route accounts, optional ones
+/// included (they are passed as the program id when unused).
+pub const SWAP_V3_FIXED_ACCOUNTS: usize = 14;
+
+/// One DEX invocation of a route, with the accounts handed to its adapter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HopAccounts {
+    /// Index into `SwapArgs::amounts` / `SwapArgs::routes`.
+    pub branch: usize,
+    /// Position of the `Route` within its branch.
+    pub hop: usize,
+    pub dex: Dex,
+    /// Share of the hop input routed through this DEX, in percent.
+    pub weight: u8,
+    pub dex_program: Pubkey,
+    pub swap_authority: Pubkey,
+    pub swap_source_token: Pubkey,
+    pub swap_destination_token: Pubkey,
+    /// Every account of the adapter, `dex_program` first.
+    pub accounts: Vec<Pubkey>,
+}
+
+/// Maps each DEX of `args.routes` to its slice of `accounts`, the route
+/// accounts of the instruction.
+///
+/// Every adapter slice starts with the DEX program, the swap authority and
+/// the source and destination token accounts. The authority is the same for
+/// every hop, DEXes of one `Route` share the source and destination, and a
+/// `Route` reads from the destination of the previous one; a slice ends
+/// where the next matching prefix starts.
+///
+/// The authority of the first hop is assumed for every later hop: a hop
+/// signed by a different authority is not found and the split fails with
+/// [`AccountsError::Missing`]. A route without DEXes splits into no hops,
+/// whatever the accounts.
+pub fn split_route_accounts(args: &SwapArgs, accounts: &[Pubkey]) -> Result<Vec<HopAccounts>, AccountsError> {
+    if args.routes.iter().flatten().all(|route| route.dexes.is_empty()) {
+        return Ok(Vec::new());
+    }
+    let authority = *accounts.get(1).ok_or(AccountsError::Missing {
+        name: "swap_authority_pubkey",
+        index: 1,
+    })?;
+    let source = *accounts.get(2).ok_or(AccountsError::Missing {
+        name: "swap_source_token",
+        index: 2,
+    })?;
+
+    let mut starts: Vec<usize> = Vec::new();
+    let mut entries = Vec::new();
+    for (branch, routes) in args.routes.iter().enumerate() {
+        let mut hop_source = source;
+        for (hop, route) in routes.iter().enumerate() {
+            let mut hop_destination = None;
+            for (i, dex) in route.dexes.iter().enumerate() {
+                let start = match starts.last() {
+                    None => 0,
+                    Some(&previous) => (previous + 4..accounts.len().saturating_sub(3))
+                        .find(|&j| {
+                            accounts[j + 1] == authority
+                                && accounts[j + 2] == hop_source
+                                && hop_destination.map_or(true, |destination| accounts[j + 3] == destination)
+                        })
+                        .ok_or(AccountsError::Missing {
+                            name: "dex_program_id",
+                            index: previous + 4,
+                        })?,
+                };
+                if start + 4 > accounts.len() {
+                    return Err(AccountsError::Missing {
+                        name: "swap_destination_token",
+                        index: start + 3,
+                    });
+                }
+                hop_destination = Some(accounts[start + 3]);
+                starts.push(start);
+                entries.push((branch, hop, dex.clone(), route.weights.get(i).copied().unwrap_or_default()));
+            }
+            if let Some(destination) = hop_destination {
+                hop_source = destination;
+            }
+        }
+    }
+
+    Ok(entries
+        .into_iter()
+        .enumerate()
+        .map(|(k, (branch, hop, dex, weight))| {
+            let slice = &accounts[starts[k]..starts.get(k + 1).copied().unwrap_or(accounts.len())];
+            HopAccounts {
+                branch,
+                hop,
+                dex,
+                weight,
+                dex_program: slice[0],
+                swap_authority: slice[1],
+                swap_source_token: slice[2],
+                swap_destination_token: slice[3],
+                accounts: slice.to_vec(),
+            }
+        })
+        .collect())
+}
+
+/// Splits the route accounts of a `swap_v3` instruction decoded as `ix_data`.
+pub fn get_swap_v3_hop_accounts(ix: &InstructionView, ix_data: &SwapV3Instruction) -> Result<Vec<HopAccounts>, AccountsError> {
+    let accounts = ix
+        .accounts()
+        .iter()
+        .enumerate()
+        .skip(SWAP_V3_FIXED_ACCOUNTS)
+        .map(|(index, a)| to_pubkey("route_account", index, a.0))
+        .collect::<Result<Vec<_>, _>>()?;
+    split_route_accounts(&ix_data.args, &accounts)
+}
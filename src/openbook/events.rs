@@ -124,30 +124,16 @@ pub struct OpenOrdersPositionLogEvent {
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for OpenbookEvent {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-
-        let (disc, payload) = data.split_at(8);
-        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
-
-        Ok(match discriminator {
-            DEPOSIT_LOG => Self::DepositLog(DepositLogEvent::try_from_slice(payload)?),
-            FILL_LOG => Self::FillLog(FillLogEvent::try_from_slice(payload)?),
-            MARKET_META_DATA_LOG => Self::MarketMetaDataLog(MarketMetaDataLogEvent::try_from_slice(payload)?),
-            TOTAL_ORDER_FILL_EVENT => Self::TotalOrderFillEvent(TotalOrderFillEventData::try_from_slice(payload)?),
-            SET_DELEGATE_LOG => Self::SetDelegateLog(SetDelegateLogEvent::try_from_slice(payload)?),
-            SETTLE_FUNDS_LOG => Self::SettleFundsLog(SettleFundsLogEvent::try_from_slice(payload)?),
-            SWEEP_FEES_LOG => Self::SweepFeesLog(SweepFeesLogEvent::try_from_slice(payload)?),
-            OPEN_ORDERS_POSITION_LOG => Self::OpenOrdersPositionLog(OpenOrdersPositionLogEvent::try_from_slice(payload)?),
-            other => return Err(ParseError::Unknown(other)),
-        })
-    }
-}
+anchor_events!(OpenbookEvent {
+    DEPOSIT_LOG => DepositLog(DepositLogEvent),
+    FILL_LOG => FillLog(FillLogEvent),
+    MARKET_META_DATA_LOG => MarketMetaDataLog(MarketMetaDataLogEvent),
+    TOTAL_ORDER_FILL_EVENT => TotalOrderFillEvent(TotalOrderFillEventData),
+    SET_DELEGATE_LOG => SetDelegateLog(SetDelegateLogEvent),
+    SETTLE_FUNDS_LOG => SettleFundsLog(SettleFundsLogEvent),
+    SWEEP_FEES_LOG => SweepFeesLog(SweepFeesLogEvent),
+    OPEN_ORDERS_POSITION_LOG => OpenOrdersPositionLog(OpenOrdersPositionLogEvent),
+});
 
 /// Convenience wrapper that forwards to `TryFrom`.
 pub fn unpack(data: &[u8]) -> Result<OpenbookEvent, ParseError> {
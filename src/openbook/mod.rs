@@ -3,6 +3,7 @@ use substreams_solana::b58;
 pub mod accounts;
 pub mod events;
 pub mod instructions;
+pub mod state;
 
 /// Openbook V2 program
 ///
@@ -0,0 +1,691 @@
+//! Openbook V2 on-chain account state.
+//!
+//! All accounts are Anchor zero-copy: an 8-byte discriminator followed by the
+//! `#[repr(C)]` struct. Optional admin keys are stored as a zeroed pubkey when
+//! unset. The orderbook is two [`BookSide`] accounts, each holding a fixed and
+//! an oracle-pegged crit-bit tree in a shared node pool.
+
+use crate::common::bincode::{invalid, BincodeReader};
+use crate::common::ParseError;
+use solana_program::pubkey::Pubkey;
+
+// -----------------------------------------------------------------------------
+// Discriminators (Anchor: sha256("account:<AccountName>")[..8])
+// -----------------------------------------------------------------------------
+pub const MARKET: [u8; 8] = [219, 190, 213, 55, 0, 227, 198, 154];
+pub const BOOK_SIDE: [u8; 8] = [72, 44, 225, 141, 178, 130, 97, 57];
+pub const EVENT_HEAP: [u8; 8] = [119, 59, 61, 19, 165, 84, 57, 175];
+pub const OPEN_ORDERS_ACCOUNT: [u8; 8] = [255, 194, 78, 123, 16, 105, 208, 165];
+
+/// Size of a [`Market`], without the discriminator.
+pub const MARKET_LEN: usize = 840;
+/// Size of a [`BookSide`], without the discriminator.
+pub const BOOK_SIDE_LEN: usize = 90944;
+/// Size of an [`EventHeap`], without the discriminator.
+pub const EVENT_HEAP_LEN: usize = 91280;
+/// Size of an [`OpenOrdersAccount`], without the discriminator.
+pub const OPEN_ORDERS_ACCOUNT_LEN: usize = 1256;
+
+const MAX_ORDERTREE_NODES: usize = 1024;
+const MAX_NUM_EVENTS: usize = 600;
+const MAX_OPEN_ORDERS: usize = 24;
+const NODE_LEN: usize = 88;
+const EVENT_NODE_LEN: usize = 152;
+
+const INNER_NODE: u8 = 1;
+const LEAF_NODE: u8 = 2;
+
+const FILL_EVENT: u8 = 0;
+const OUT_EVENT: u8 = 1;
+
+// -----------------------------------------------------------------------------
+// Shared types
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Bid,
+    Ask,
+}
+
+impl TryFrom<u8> for Side {
+    type Error = ParseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Bid),
+            1 => Ok(Self::Ask),
+            other => Err(invalid(format!("invalid side {other}"))),
+        }
+    }
+}
+
+/// Checks the Anchor discriminator and returns the account body.
+fn body(data: &[u8], discriminator: [u8; 8], len: usize) -> Result<&[u8], ParseError> {
+    if data.len() < 8 {
+        return Err(ParseError::TooShort(data.len()));
+    }
+    if data[..8] != discriminator {
+        return Err(ParseError::Unknown(data[..8].try_into().unwrap()));
+    }
+    if data.len() < 8 + len {
+        return Err(ParseError::InvalidLength {
+            expected: 8 + len,
+            got: data.len(),
+        });
+    }
+    Ok(&data[8..8 + len])
+}
+
+fn optional_pubkey(r: &mut BincodeReader) -> Result<Option<Pubkey>, ParseError> {
+    let key = r.pubkey()?;
+    Ok((key != Pubkey::default()).then_some(key))
+}
+
+/// Decodes a zero-padded fixed-size name.
+fn name(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).trim_end_matches('\0').to_string()
+}
+
+// -----------------------------------------------------------------------------
+// Market
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq)]
+pub struct OracleConfig {
+    pub conf_filter: f64,
+    pub max_staleness_slots: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Market {
+    pub bump: u8,
+    pub base_decimals: u8,
+    pub quote_decimals: u8,
+    pub market_authority: Pubkey,
+    /// `0` when the market never expires.
+    pub time_expiry: i64,
+    pub collect_fee_admin: Pubkey,
+    pub open_orders_admin: Option<Pubkey>,
+    pub consume_events_admin: Option<Pubkey>,
+    pub close_market_admin: Option<Pubkey>,
+    pub name: String,
+    pub bids: Pubkey,
+    pub asks: Pubkey,
+    pub event_heap: Pubkey,
+    pub oracle_a: Option<Pubkey>,
+    pub oracle_b: Option<Pubkey>,
+    pub oracle_config: OracleConfig,
+    /// Quote native units per quote lot.
+    pub quote_lot_size: i64,
+    /// Base native units per base lot.
+    pub base_lot_size: i64,
+    pub seq_num: u64,
+    pub registration_time: i64,
+    /// In millionths; negative for a rebate.
+    pub maker_fee: i64,
+    /// In millionths.
+    pub taker_fee: i64,
+    pub fees_accrued: u128,
+    pub fees_to_referrers: u128,
+    pub referrer_rebates_accrued: u64,
+    pub fees_available: u64,
+    pub maker_volume: u128,
+    pub taker_volume_wo_oo: u128,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub market_base_vault: Pubkey,
+    pub base_deposit_total: u64,
+    pub market_quote_vault: Pubkey,
+    pub quote_deposit_total: u64,
+}
+
+/// Depth at one price (L2).
+#[derive(Debug, Clone, PartialEq)]
+pub struct L2Level {
+    pub price_lots: i64,
+    pub base_lots: i64,
+    /// Quote UI units per base UI unit.
+    pub price: f64,
+    /// Base UI units.
+    pub size: f64,
+}
+
+/// An order resting on the book (L3).
+#[derive(Debug, Clone, PartialEq)]
+pub struct L3Order {
+    pub order_id: u128,
+    pub owner: Pubkey,
+    pub owner_slot: u8,
+    pub client_order_id: u64,
+    pub price_lots: i64,
+    pub base_lots: i64,
+    /// Quote UI units per base UI unit.
+    pub price: f64,
+    /// Base UI units.
+    pub size: f64,
+}
+
+impl Market {
+    /// Converts a price in lots to quote UI units per base UI unit.
+    pub fn price_lots_to_ui(&self, price_lots: i64) -> f64 {
+        let decimals = self.base_decimals as i32 - self.quote_decimals as i32;
+        price_lots as f64 * self.quote_lot_size as f64 * 10f64.powi(decimals) / self.base_lot_size as f64
+    }
+
+    /// Converts base lots to base UI units.
+    pub fn base_lots_to_ui(&self, base_lots: i64) -> f64 {
+        base_lots as f64 * self.base_lot_size as f64 / 10f64.powi(self.base_decimals as i32)
+    }
+
+    /// Fixed-price orders of `book`, best first.
+    pub fn l3(&self, book: &BookSide) -> Vec<L3Order> {
+        book.fixed
+            .iter()
+            .map(|leaf| L3Order {
+                order_id: leaf.key,
+                owner: leaf.owner,
+                owner_slot: leaf.owner_slot,
+                client_order_id: leaf.client_order_id,
+                price_lots: leaf.price_lots(),
+                base_lots: leaf.quantity,
+                price: self.price_lots_to_ui(leaf.price_lots()),
+                size: self.base_lots_to_ui(leaf.quantity),
+            })
+            .collect()
+    }
+
+    /// Fixed-price orders of `book` aggregated by price, best first.
+    pub fn l2(&self, book: &BookSide) -> Vec<L2Level> {
+        let mut levels: Vec<L2Level> = Vec::new();
+        for leaf in &book.fixed {
+            match levels.last_mut() {
+                Some(level) if level.price_lots == leaf.price_lots() => {
+                    level.base_lots += leaf.quantity;
+                    level.size = self.base_lots_to_ui(level.base_lots);
+                }
+                _ => levels.push(L2Level {
+                    price_lots: leaf.price_lots(),
+                    base_lots: leaf.quantity,
+                    price: self.price_lots_to_ui(leaf.price_lots()),
+                    size: self.base_lots_to_ui(leaf.quantity),
+                }),
+            }
+        }
+        levels
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Market {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut r = BincodeReader::new(body(data, MARKET, MARKET_LEN)?);
+        let bump = r.u8()?;
+        let base_decimals = r.u8()?;
+        let quote_decimals = r.u8()?;
+        r.take(5)?;
+        let market_authority = r.pubkey()?;
+        let time_expiry = r.i64()?;
+        let collect_fee_admin = r.pubkey()?;
+        let open_orders_admin = optional_pubkey(&mut r)?;
+        let consume_events_admin = optional_pubkey(&mut r)?;
+        let close_market_admin = optional_pubkey(&mut r)?;
+        let name = name(r.take(16)?);
+        let bids = r.pubkey()?;
+        let asks = r.pubkey()?;
+        let event_heap = r.pubkey()?;
+        let oracle_a = optional_pubkey(&mut r)?;
+        let oracle_b = optional_pubkey(&mut r)?;
+        let oracle_config = OracleConfig {
+            conf_filter: f64::from_bits(r.u64()?),
+            max_staleness_slots: r.i64()?,
+        };
+        r.take(72)?;
+        Ok(Self {
+            bump,
+            base_decimals,
+            quote_decimals,
+            market_authority,
+            time_expiry,
+            collect_fee_admin,
+            open_orders_admin,
+            consume_events_admin,
+            close_market_admin,
+            name,
+            bids,
+            asks,
+            event_heap,
+            oracle_a,
+            oracle_b,
+            oracle_config,
+            quote_lot_size: r.i64()?,
+            base_lot_size: r.i64()?,
+            seq_num: r.u64()?,
+            registration_time: r.i64()?,
+            maker_fee: r.i64()?,
+            taker_fee: r.i64()?,
+            fees_accrued: r.u128()?,
+            fees_to_referrers: r.u128()?,
+            referrer_rebates_accrued: r.u64()?,
+            fees_available: r.u64()?,
+            maker_volume: r.u128()?,
+            taker_volume_wo_oo: r.u128()?,
+            base_mint: r.pubkey()?,
+            quote_mint: r.pubkey()?,
+            market_base_vault: r.pubkey()?,
+            base_deposit_total: r.u64()?,
+            market_quote_vault: r.pubkey()?,
+            quote_deposit_total: r.u64()?,
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// BookSide
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeafNode {
+    pub owner_slot: u8,
+    /// Seconds after `timestamp` the order stays valid; `0` for no expiry.
+    pub time_in_force: u16,
+    /// Price data in the high 64 bits, sequence number (inverted for bids)
+    /// in the low 64 bits. Also the order id.
+    pub key: u128,
+    pub owner: Pubkey,
+    /// Base lots.
+    pub quantity: i64,
+    pub timestamp: u64,
+    /// Worst price in lots for an oracle-pegged order; `-1` when unset.
+    pub peg_limit: i64,
+    pub client_order_id: u64,
+}
+
+impl LeafNode {
+    pub fn price_data(&self) -> u64 {
+        (self.key >> 64) as u64
+    }
+
+    /// Price in lots of a fixed order.
+    pub fn price_lots(&self) -> i64 {
+        self.price_data() as i64
+    }
+
+    /// Offset in lots from the oracle price of an oracle-pegged order.
+    pub fn peg_offset_lots(&self) -> i64 {
+        (self.price_data() ^ (1 << 63)) as i64
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BookSide {
+    pub side: Side,
+    /// Fixed-price orders, best first.
+    pub fixed: Vec<LeafNode>,
+    /// Oracle-pegged orders, best first.
+    pub oracle_pegged: Vec<LeafNode>,
+}
+
+fn read_leaf(node: &[u8]) -> Result<LeafNode, ParseError> {
+    let mut r = BincodeReader::new(node);
+    r.u8()?;
+    let owner_slot = r.u8()?;
+    let time_in_force = r.u16()?;
+    r.take(4)?;
+    Ok(LeafNode {
+        owner_slot,
+        time_in_force,
+        key: r.u128()?,
+        owner: r.pubkey()?,
+        quantity: r.i64()?,
+        timestamp: r.u64()?,
+        peg_limit: r.i64()?,
+        client_order_id: r.u64()?,
+    })
+}
+
+/// Collects the leaves under `root` in ascending key order.
+fn read_tree(nodes: &[u8], root: u32, leaf_count: u32) -> Result<Vec<LeafNode>, ParseError> {
+    let mut leaves = Vec::with_capacity(leaf_count as usize);
+    if leaf_count == 0 {
+        return Ok(leaves);
+    }
+    let mut stack = vec![root];
+    let mut visited = 0;
+    while let Some(handle) = stack.pop() {
+        visited += 1;
+        if handle as usize >= MAX_ORDERTREE_NODES || visited > MAX_ORDERTREE_NODES {
+            return Err(invalid(format!("invalid book node {handle}")));
+        }
+        let node = &nodes[handle as usize * NODE_LEN..][..NODE_LEN];
+        match node[0] {
+            INNER_NODE => {
+                let mut r = BincodeReader::new(&node[24..32]);
+                let left = r.u32()?;
+                let right = r.u32()?;
+                stack.push(right);
+                stack.push(left);
+            }
+            LEAF_NODE => leaves.push(read_leaf(node)?),
+            tag => return Err(invalid(format!("unexpected book node tag {tag}"))),
+        }
+    }
+    if leaves.len() != leaf_count as usize {
+        return Err(invalid(format!("book tree has {} leaves, expected {leaf_count}", leaves.len())));
+    }
+    Ok(leaves)
+}
+
+impl<'a> TryFrom<&'a [u8]> for BookSide {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut r = BincodeReader::new(body(data, BOOK_SIDE, BOOK_SIDE_LEN)?);
+        let roots = [(r.u32()?, r.u32()?), (r.u32()?, r.u32()?)];
+        r.take(32 + 256)?;
+        let side = Side::try_from(r.u8()?)?;
+        r.take(3 + 4 + 4 + 4 + 512)?;
+        let nodes = r.take(MAX_ORDERTREE_NODES * NODE_LEN)?;
+
+        let mut trees = roots.map(|(root, leaf_count)| read_tree(nodes, root, leaf_count));
+        if side == Side::Bid {
+            // Bids are keyed by inverted sequence number: the best bid is the
+            // highest key.
+            for tree in trees.iter_mut().flatten() {
+                tree.reverse();
+            }
+        }
+        let [fixed, oracle_pegged] = trees;
+        Ok(Self {
+            side,
+            fixed: fixed?,
+            oracle_pegged: oracle_pegged?,
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// EventHeap
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FillEvent {
+    pub taker_side: Side,
+    pub maker_out: bool,
+    pub maker_slot: u8,
+    pub timestamp: u64,
+    pub market_seq_num: u64,
+    pub maker: Pubkey,
+    pub maker_timestamp: u64,
+    pub taker: Pubkey,
+    pub taker_client_order_id: u64,
+    /// Price in lots.
+    pub price: i64,
+    pub peg_limit: i64,
+    /// Base lots.
+    pub quantity: i64,
+    pub maker_client_order_id: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutEvent {
+    pub side: Side,
+    pub owner_slot: u8,
+    pub timestamp: u64,
+    pub seq_num: u64,
+    pub owner: Pubkey,
+    /// Base lots.
+    pub quantity: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    Fill(FillEvent),
+    Out(OutEvent),
+}
+
+impl<'a> TryFrom<&'a [u8]> for Event {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut r = BincodeReader::new(data);
+        match r.u8()? {
+            FILL_EVENT => {
+                let taker_side = Side::try_from(r.u8()?)?;
+                let maker_out = r.bool()?;
+                let maker_slot = r.u8()?;
+                r.take(4)?;
+                Ok(Self::Fill(FillEvent {
+                    taker_side,
+                    maker_out,
+                    maker_slot,
+                    timestamp: r.u64()?,
+                    market_seq_num: r.u64()?,
+                    maker: r.pubkey()?,
+                    maker_timestamp: r.u64()?,
+                    taker: r.pubkey()?,
+                    taker_client_order_id: r.u64()?,
+                    price: r.i64()?,
+                    peg_limit: r.i64()?,
+                    quantity: r.i64()?,
+                    maker_client_order_id: r.u64()?,
+                }))
+            }
+            OUT_EVENT => {
+                let side = Side::try_from(r.u8()?)?;
+                let owner_slot = r.u8()?;
+                r.take(5)?;
+                Ok(Self::Out(OutEvent {
+                    side,
+                    owner_slot,
+                    timestamp: r.u64()?,
+                    seq_num: r.u64()?,
+                    owner: r.pubkey()?,
+                    quantity: r.i64()?,
+                }))
+            }
+            other => Err(invalid(format!("unknown event type {other}"))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventHeap {
+    /// Sequence number of the next event pushed.
+    pub seq_num: u64,
+    /// Pending events, oldest first.
+    pub events: Vec<Event>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for EventHeap {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut r = BincodeReader::new(body(data, EVENT_HEAP, EVENT_HEAP_LEN)?);
+        let _free_head = r.u16()?;
+        let used_head = r.u16()?;
+        let count = r.u16()? as usize;
+        r.take(2)?;
+        let seq_num = r.u64()?;
+        let nodes = r.take(MAX_NUM_EVENTS * EVENT_NODE_LEN)?;
+        if count > MAX_NUM_EVENTS {
+            return Err(invalid(format!("event heap count {count} exceeds capacity")));
+        }
+
+        let mut events = Vec::with_capacity(count);
+        let mut index = used_head as usize;
+        for _ in 0..count {
+            if index >= MAX_NUM_EVENTS {
+                return Err(invalid(format!("invalid event node {index}")));
+            }
+            let node = &nodes[index * EVENT_NODE_LEN..][..EVENT_NODE_LEN];
+            events.push(Event::try_from(&node[8..])?);
+            index = u16::from_le_bytes([node[0], node[1]]) as usize;
+        }
+        Ok(Self { seq_num, events })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// OpenOrdersAccount
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Position {
+    pub bids_base_lots: i64,
+    pub asks_base_lots: i64,
+    pub base_free_native: u64,
+    pub quote_free_native: u64,
+    pub locked_maker_fees: u64,
+    pub referrer_rebates_available: u64,
+    pub penalty_heap_count: u64,
+    pub maker_volume: u128,
+    pub taker_volume: u128,
+    pub bids_quote_lots: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SideAndOrderTree {
+    BidFixed,
+    AskFixed,
+    BidOraclePegged,
+    AskOraclePegged,
+}
+
+impl SideAndOrderTree {
+    pub fn side(&self) -> Side {
+        match self {
+            Self::BidFixed | Self::BidOraclePegged => Side::Bid,
+            Self::AskFixed | Self::AskOraclePegged => Side::Ask,
+        }
+    }
+}
+
+impl TryFrom<u8> for SideAndOrderTree {
+    type Error = ParseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::BidFixed),
+            1 => Ok(Self::AskFixed),
+            2 => Ok(Self::BidOraclePegged),
+            3 => Ok(Self::AskOraclePegged),
+            other => Err(invalid(format!("invalid side and order tree {other}"))),
+        }
+    }
+}
+
+/// An occupied open-order slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenOrder {
+    pub slot: u8,
+    /// Key of the order's [`LeafNode`].
+    pub id: u128,
+    pub client_id: u64,
+    /// Price in lots at which funds were locked.
+    pub locked_price: i64,
+    pub side_and_tree: SideAndOrderTree,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenOrdersAccount {
+    pub owner: Pubkey,
+    pub market: Pubkey,
+    pub name: String,
+    pub delegate: Option<Pubkey>,
+    pub account_num: u32,
+    pub bump: u8,
+    pub version: u8,
+    pub position: Position,
+    /// Occupied slots only.
+    pub open_orders: Vec<OpenOrder>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for OpenOrdersAccount {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut r = BincodeReader::new(body(data, OPEN_ORDERS_ACCOUNT, OPEN_ORDERS_ACCOUNT_LEN)?);
+        let owner = r.pubkey()?;
+        let market = r.pubkey()?;
+        let name = name(r.take(32)?);
+        let delegate = optional_pubkey(&mut r)?;
+        let account_num = r.u32()?;
+        let bump = r.u8()?;
+        let version = r.u8()?;
+        r.take(2)?;
+        let position = Position {
+            bids_base_lots: r.i64()?,
+            asks_base_lots: r.i64()?,
+            base_free_native: r.u64()?,
+            quote_free_native: r.u64()?,
+            locked_maker_fees: r.u64()?,
+            referrer_rebates_available: r.u64()?,
+            penalty_heap_count: r.u64()?,
+            maker_volume: r.u128()?,
+            taker_volume: r.u128()?,
+            bids_quote_lots: r.i64()?,
+        };
+        r.take(64)?;
+
+        let mut open_orders = Vec::new();
+        for slot in 0..MAX_OPEN_ORDERS as u8 {
+            let id = r.u128()?;
+            let client_id = r.u64()?;
+            let locked_price = r.i64()?;
+            let is_free = r.u8()?;
+            let side_and_tree = r.u8()?;
+            r.take(6)?;
+            if is_free == 0 {
+                open_orders.push(OpenOrder {
+                    slot,
+                    id,
+                    client_id,
+                    locked_price,
+                    side_and_tree: SideAndOrderTree::try_from(side_and_tree)?,
+                });
+            }
+        }
+        Ok(Self {
+            owner,
+            market,
+            name,
+            delegate,
+            account_num,
+            bump,
+            version,
+            position,
+            open_orders,
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Account enumeration
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpenbookAccount {
+    Market(Box<Market>),
+    BookSide(BookSide),
+    EventHeap(EventHeap),
+    OpenOrdersAccount(Box<OpenOrdersAccount>),
+}
+
+impl<'a> TryFrom<&'a [u8]> for OpenbookAccount {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() < 8 {
+            return Err(ParseError::TooShort(data.len()));
+        }
+        let discriminator: [u8; 8] = data[..8].try_into().unwrap();
+        Ok(match discriminator {
+            MARKET => Self::Market(Box::new(Market::try_from(data)?)),
+            BOOK_SIDE => Self::BookSide(BookSide::try_from(data)?),
+            EVENT_HEAP => Self::EventHeap(EventHeap::try_from(data)?),
+            OPEN_ORDERS_ACCOUNT => Self::OpenOrdersAccount(Box::new(OpenOrdersAccount::try_from(data)?)),
+            other => return Err(ParseError::Unknown(other)),
+        })
+    }
+}
+
+/// Convenience wrapper that forwards to `TryFrom`.
+pub fn unpack(data: &[u8]) -> Result<OpenbookAccount, ParseError> {
+    OpenbookAccount::try_from(data)
+}
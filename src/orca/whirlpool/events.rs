@@ -1,4 +1,7 @@
 //! Orca Whirlpool on-chain events.
+//!
+//! The program emits only these four events. Position, fee, reward, lock and
+//! position-bundle activity is visible through [`super::instructions`] alone.
 
 use crate::common::ParseError;
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -169,23 +172,13 @@ pub struct Traded {
     pub protocol_fee: u64,
 }
 
-impl<'a> TryFrom<&'a [u8]> for WhirlpoolEvent {
-    type Error = ParseError;
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-        let (disc, payload) = data.split_at(8);
-        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
-        Ok(match discriminator {
-            LIQUIDITY_DECREASED => Self::LiquidityDecreased(LiquidityDecreased::try_from_slice(payload)?),
-            LIQUIDITY_INCREASED => Self::LiquidityIncreased(LiquidityIncreased::try_from_slice(payload)?),
-            POOL_INITIALIZED => Self::PoolInitialized(PoolInitialized::try_from_slice(payload)?),
-            TRADED => Self::Traded(Traded::try_from_slice(payload)?),
-            _ => Self::Unknown,
-        })
-    }
-}
+anchor_events!(WhirlpoolEvent {
+    LIQUIDITY_DECREASED => LiquidityDecreased(LiquidityDecreased),
+    LIQUIDITY_INCREASED => LiquidityIncreased(LiquidityIncreased),
+    POOL_INITIALIZED => PoolInitialized(PoolInitialized),
+    TRADED => Traded(Traded),
+    _ => Unknown,
+});
 pub fn parse_event(data: &[u8]) -> Result<WhirlpoolEvent, ParseError> {
     WhirlpoolEvent::try_from(data)
 }
@@ -0,0 +1,228 @@
+//! Orca Whirlpool on-chain account state.
+//!
+//! Anchor accounts: an 8-byte discriminator followed by the Borsh struct.
+//! `TickArray` and `Oracle` are zero-copy but `#[repr(C, packed)]`, so their
+//! layout is the same. Trailing reserved space is ignored.
+
+use crate::common::ParseError;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+pub use super::instructions::{AdaptiveFeeConstants, AdaptiveFeeVariables, LockTypeLabel, PositionRewardInfo, Tick, WhirlpoolRewardInfo};
+
+/// Number of ticks in a [`TickArray`].
+pub const TICK_ARRAY_SIZE: usize = 88;
+
+// -----------------------------------------------------------------------------
+// Discriminators (Anchor: sha256("account:<AccountName>")[..8])
+// -----------------------------------------------------------------------------
+pub const WHIRLPOOL: [u8; 8] = [63, 149, 209, 12, 225, 128, 99, 9];
+pub const POSITION: [u8; 8] = [170, 188, 143, 228, 122, 64, 247, 208];
+pub const POSITION_BUNDLE: [u8; 8] = [129, 169, 175, 65, 185, 95, 32, 100];
+pub const TICK_ARRAY: [u8; 8] = [69, 97, 189, 190, 110, 7, 66, 187];
+pub const FEE_TIER: [u8; 8] = [56, 75, 159, 76, 142, 68, 190, 105];
+pub const ADAPTIVE_FEE_TIER: [u8; 8] = [147, 16, 144, 116, 47, 146, 149, 46];
+pub const ORACLE: [u8; 8] = [139, 194, 131, 179, 140, 179, 229, 244];
+pub const LOCK_CONFIG: [u8; 8] = [106, 47, 238, 159, 124, 12, 160, 192];
+pub const TOKEN_BADGE: [u8; 8] = [116, 219, 204, 229, 249, 116, 255, 150];
+pub const WHIRLPOOLS_CONFIG: [u8; 8] = [157, 20, 49, 224, 217, 87, 193, 254];
+pub const WHIRLPOOLS_CONFIG_EXTENSION: [u8; 8] = [2, 99, 215, 163, 240, 26, 153, 58];
+
+// -----------------------------------------------------------------------------
+// Account structs
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Whirlpool {
+    pub whirlpools_config: Pubkey,
+    pub whirlpool_bump: [u8; 1],
+    pub tick_spacing: u16,
+    pub fee_tier_index_seed: [u8; 2],
+    /// In hundredths of a basis point.
+    pub fee_rate: u16,
+    /// In basis points of `fee_rate`.
+    pub protocol_fee_rate: u16,
+    pub liquidity: u128,
+    /// Q64.64 square root of the price of token A in token B.
+    pub sqrt_price: u128,
+    pub tick_current_index: i32,
+    pub protocol_fee_owed_a: u64,
+    pub protocol_fee_owed_b: u64,
+    pub token_mint_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    /// Q64.64 fees of token A earned per unit of liquidity.
+    pub fee_growth_global_a: u128,
+    pub token_mint_b: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub fee_growth_global_b: u128,
+    pub reward_last_updated_timestamp: u64,
+    pub reward_infos: [WhirlpoolRewardInfo; 3],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Position {
+    pub whirlpool: Pubkey,
+    pub position_mint: Pubkey,
+    pub liquidity: u128,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub fee_growth_checkpoint_a: u128,
+    pub fee_owed_a: u64,
+    pub fee_growth_checkpoint_b: u128,
+    pub fee_owed_b: u64,
+    pub reward_infos: [PositionRewardInfo; 3],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PositionBundle {
+    pub position_bundle_mint: Pubkey,
+    pub position_bitmap: [u8; 32],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct TickArray {
+    pub start_tick_index: i32,
+    /// Every `tick_spacing` ticks from `start_tick_index`.
+    pub ticks: [Tick; TICK_ARRAY_SIZE],
+    pub whirlpool: Pubkey,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FeeTier {
+    pub whirlpools_config: Pubkey,
+    pub tick_spacing: u16,
+    pub default_fee_rate: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct AdaptiveFeeTier {
+    pub whirlpools_config: Pubkey,
+    pub fee_tier_index: u16,
+    pub tick_spacing: u16,
+    pub initialize_pool_authority: Pubkey,
+    pub delegated_fee_authority: Pubkey,
+    pub default_base_fee_rate: u16,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub adaptive_fee_control_factor: u32,
+    pub max_volatility_accumulator: u32,
+    pub tick_group_size: u16,
+    pub major_swap_threshold_ticks: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Oracle {
+    pub whirlpool: Pubkey,
+    pub trade_enable_timestamp: u64,
+    pub adaptive_fee_constants: AdaptiveFeeConstants,
+    pub adaptive_fee_variables: AdaptiveFeeVariables,
+    pub reserved: [u8; 128],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct LockConfig {
+    pub position: Pubkey,
+    pub position_owner: Pubkey,
+    pub whirlpool: Pubkey,
+    pub locked_timestamp: u64,
+    pub lock_type: LockTypeLabel,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct TokenBadge {
+    pub whirlpools_config: Pubkey,
+    pub token_mint: Pubkey,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct WhirlpoolsConfig {
+    pub fee_authority: Pubkey,
+    pub collect_protocol_fees_authority: Pubkey,
+    pub reward_emissions_super_authority: Pubkey,
+    pub default_protocol_fee_rate: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct WhirlpoolsConfigExtension {
+    pub whirlpools_config: Pubkey,
+    pub config_extension_authority: Pubkey,
+    pub token_badge_authority: Pubkey,
+}
+
+impl Whirlpool {
+    pub fn fee_tier_index(&self) -> u16 {
+        u16::from_le_bytes(self.fee_tier_index_seed)
+    }
+
+    /// Pools created from an adaptive fee tier have a fee tier index other
+    /// than their tick spacing, and an [`Oracle`].
+    pub fn is_initialized_with_adaptive_fee(&self) -> bool {
+        self.fee_tier_index() != self.tick_spacing
+    }
+}
+
+impl TickArray {
+    /// Tick index of `ticks[offset]` in a pool with `tick_spacing`.
+    pub fn tick_index(&self, offset: usize, tick_spacing: u16) -> i32 {
+        self.start_tick_index + offset as i32 * tick_spacing as i32
+    }
+
+    /// Initialized ticks with their tick index.
+    pub fn initialized_ticks(&self, tick_spacing: u16) -> impl Iterator<Item = (i32, &Tick)> + '_ {
+        self.ticks
+            .iter()
+            .enumerate()
+            .filter(|(_, tick)| tick.initialized)
+            .map(move |(offset, tick)| (self.tick_index(offset, tick_spacing), tick))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Account enumeration
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WhirlpoolAccount {
+    Whirlpool(Box<Whirlpool>),
+    Position(Position),
+    PositionBundle(PositionBundle),
+    TickArray(Box<TickArray>),
+    FeeTier(FeeTier),
+    AdaptiveFeeTier(AdaptiveFeeTier),
+    Oracle(Oracle),
+    LockConfig(LockConfig),
+    TokenBadge(TokenBadge),
+    WhirlpoolsConfig(WhirlpoolsConfig),
+    WhirlpoolsConfigExtension(WhirlpoolsConfigExtension),
+}
+
+impl<'a> TryFrom<&'a [u8]> for WhirlpoolAccount {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() < 8 {
+            return Err(ParseError::TooShort(data.len()));
+        }
+        let (disc, mut rest) = data.split_at(8);
+        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
+        let rest = &mut rest;
+        Ok(match discriminator {
+            WHIRLPOOL => Self::Whirlpool(Box::new(Whirlpool::deserialize(rest)?)),
+            POSITION => Self::Position(Position::deserialize(rest)?),
+            POSITION_BUNDLE => Self::PositionBundle(PositionBundle::deserialize(rest)?),
+            TICK_ARRAY => Self::TickArray(Box::new(TickArray::deserialize(rest)?)),
+            FEE_TIER => Self::FeeTier(FeeTier::deserialize(rest)?),
+            ADAPTIVE_FEE_TIER => Self::AdaptiveFeeTier(AdaptiveFeeTier::deserialize(rest)?),
+            ORACLE => Self::Oracle(Oracle::deserialize(rest)?),
+            LOCK_CONFIG => Self::LockConfig(LockConfig::deserialize(rest)?),
+            TOKEN_BADGE => Self::TokenBadge(TokenBadge::deserialize(rest)?),
+            WHIRLPOOLS_CONFIG => Self::WhirlpoolsConfig(WhirlpoolsConfig::deserialize(rest)?),
+            WHIRLPOOLS_CONFIG_EXTENSION => Self::WhirlpoolsConfigExtension(WhirlpoolsConfigExtension::deserialize(rest)?),
+            other => return Err(ParseError::Unknown(other)),
+        })
+    }
+}
+
+/// Convenience wrapper that forwards to `TryFrom`.
+pub fn unpack(data: &[u8]) -> Result<WhirlpoolAccount, ParseError> {
+    WhirlpoolAccount::try_from(data)
+}
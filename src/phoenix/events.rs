@@ -1,26 +1,64 @@
 //! Phonenix market events.
+//!
+//! Phoenix does not write logs: it records events by invoking its own `Log`
+//! instruction, whose data is the `LOG` discriminator, a `Header` event, then
+//! a Borsh `Vec` of the events of that batch. Each event is a Borsh
+//! `PhoenixMarketEvent`, i.e. a `u8` tag followed by its payload.
 
+use crate::common::bincode::invalid;
 use crate::common::ParseError;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
+use super::instructions::LOG;
+
 // -----------------------------------------------------------------------------
 // Discriminators
 // -----------------------------------------------------------------------------
+pub const HEADER_EVENT: u8 = 1;
 pub const FILL_EVENT: u8 = 2;
+pub const PLACE_EVENT: u8 = 3;
+pub const REDUCE_EVENT: u8 = 4;
+pub const EVICT_EVENT: u8 = 5;
+pub const FILL_SUMMARY_EVENT: u8 = 6;
+pub const FEE_EVENT: u8 = 7;
+pub const TIME_IN_FORCE_EVENT: u8 = 8;
+pub const EXPIRED_ORDER_EVENT: u8 = 9;
 
 // -----------------------------------------------------------------------------
 // Event enumeration
 // -----------------------------------------------------------------------------
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PhonenixEvent {
+    Header(AuditLogHeader),
     Fill(FillEvent),
+    Place(PlaceEvent),
+    Reduce(ReduceEvent),
+    Evict(EvictEvent),
+    FillSummary(FillSummaryEvent),
+    Fee(FeeEvent),
+    TimeInForce(TimeInForceEvent),
+    ExpiredOrder(ExpiredOrderEvent),
     Unknown,
 }
 
 // -----------------------------------------------------------------------------
 // Payload structs
 // -----------------------------------------------------------------------------
+/// Opens every event batch.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct AuditLogHeader {
+    /// Discriminator of the instruction that produced the events.
+    pub instruction: u8,
+    pub sequence_number: u64,
+    pub timestamp: i64,
+    pub slot: u64,
+    pub market: Pubkey,
+    pub signer: Pubkey,
+    /// Events recorded by the instruction, across all of its batches.
+    pub total_events: u16,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct FillEvent {
     pub index: u16,
@@ -31,9 +69,96 @@ pub struct FillEvent {
     pub base_lots_remaining: u64,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PlaceEvent {
+    pub index: u16,
+    pub order_sequence_number: u64,
+    pub client_order_id: u128,
+    pub price_in_ticks: u64,
+    pub base_lots_placed: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ReduceEvent {
+    pub index: u16,
+    pub order_sequence_number: u64,
+    pub price_in_ticks: u64,
+    pub base_lots_removed: u64,
+    pub base_lots_remaining: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct EvictEvent {
+    pub index: u16,
+    pub maker_id: Pubkey,
+    pub order_sequence_number: u64,
+    pub price_in_ticks: u64,
+    pub base_lots_evicted: u64,
+}
+
+/// Totals of the taker side of an order, after its `Fill` events.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FillSummaryEvent {
+    pub index: u16,
+    pub client_order_id: u128,
+    pub total_base_lots_filled: u64,
+    pub total_quote_lots_filled: u64,
+    pub total_fee_in_quote_lots: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FeeEvent {
+    pub index: u16,
+    pub fees_collected_in_quote_lots: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct TimeInForceEvent {
+    pub index: u16,
+    pub order_sequence_number: u64,
+    pub last_valid_slot: u64,
+    pub last_valid_unix_timestamp_in_seconds: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ExpiredOrderEvent {
+    pub index: u16,
+    pub maker_id: Pubkey,
+    pub order_sequence_number: u64,
+    pub price_in_ticks: u64,
+    pub base_lots_removed: u64,
+}
+
+/// Data of one `Log` instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoenixLog {
+    pub header: AuditLogHeader,
+    pub events: Vec<PhonenixEvent>,
+}
+
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
+impl PhonenixEvent {
+    /// Reads one tagged event off the front of `buf`.
+    fn read(buf: &mut &[u8]) -> Result<Self, ParseError> {
+        let (&discriminator, payload) = buf.split_first().ok_or(ParseError::TooShort(0))?;
+        *buf = payload;
+        Ok(match discriminator {
+            HEADER_EVENT => Self::Header(AuditLogHeader::deserialize(buf)?),
+            FILL_EVENT => Self::Fill(FillEvent::deserialize(buf)?),
+            PLACE_EVENT => Self::Place(PlaceEvent::deserialize(buf)?),
+            REDUCE_EVENT => Self::Reduce(ReduceEvent::deserialize(buf)?),
+            EVICT_EVENT => Self::Evict(EvictEvent::deserialize(buf)?),
+            FILL_SUMMARY_EVENT => Self::FillSummary(FillSummaryEvent::deserialize(buf)?),
+            FEE_EVENT => Self::Fee(FeeEvent::deserialize(buf)?),
+            TIME_IN_FORCE_EVENT => Self::TimeInForce(TimeInForceEvent::deserialize(buf)?),
+            EXPIRED_ORDER_EVENT => Self::ExpiredOrder(ExpiredOrderEvent::deserialize(buf)?),
+            other => return Err(ParseError::Unknown([other, 0, 0, 0, 0, 0, 0, 0])),
+        })
+    }
+}
+
 impl<'a> TryFrom<&'a [u8]> for PhonenixEvent {
     type Error = ParseError;
 
@@ -41,12 +166,35 @@ impl<'a> TryFrom<&'a [u8]> for PhonenixEvent {
         if data.is_empty() {
             return Err(ParseError::TooShort(data.len()));
         }
-        let (disc, payload) = data.split_at(1);
-        let discriminator = disc[0];
-        Ok(match discriminator {
-            FILL_EVENT => Self::Fill(FillEvent::try_from_slice(payload)?),
-            other => return Err(ParseError::Unknown([other, 0, 0, 0, 0, 0, 0, 0])),
-        })
+        let mut buf = data;
+        let event = Self::read(&mut buf)?;
+        if !buf.is_empty() {
+            return Err(ParseError::InvalidLength {
+                expected: data.len() - buf.len(),
+                got: data.len(),
+            });
+        }
+        Ok(event)
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PhoenixLog {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        match data.first() {
+            None => return Err(ParseError::TooShort(0)),
+            Some(&LOG) => {}
+            Some(&other) => return Err(ParseError::Unknown([other, 0, 0, 0, 0, 0, 0, 0])),
+        }
+        let mut buf = &data[1..];
+        let header = match PhonenixEvent::read(&mut buf)? {
+            PhonenixEvent::Header(header) => header,
+            _ => return Err(invalid("phoenix log does not start with a header".to_string())),
+        };
+        let len = u32::deserialize(&mut buf)?;
+        let events = (0..len).map(|_| PhonenixEvent::read(&mut buf)).collect::<Result<_, _>>()?;
+        Ok(Self { header, events })
     }
 }
 
@@ -54,3 +202,8 @@ impl<'a> TryFrom<&'a [u8]> for PhonenixEvent {
 pub fn unpack(data: &[u8]) -> Result<PhonenixEvent, ParseError> {
     PhonenixEvent::try_from(data)
 }
+
+/// Decodes the data of a Phoenix `Log` self-CPI.
+pub fn unpack_log(data: &[u8]) -> Result<PhoenixLog, ParseError> {
+    PhoenixLog::try_from(data)
+}
@@ -1,13 +1,219 @@
 //! Phonenix trading instructions.
+//!
+//! Phoenix is a Shank program: the discriminator is a single `u8` followed by
+//! the Borsh-encoded arguments.
 
 use crate::common::ParseError;
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
 
 // -----------------------------------------------------------------------------
 // Discriminators
 // -----------------------------------------------------------------------------
 pub const SWAP: u8 = 0;
 pub const SWAP_WITH_FREE_FUNDS: u8 = 1;
+pub const PLACE_LIMIT_ORDER: u8 = 2;
+pub const PLACE_LIMIT_ORDER_WITH_FREE_FUNDS: u8 = 3;
+pub const REDUCE_ORDER: u8 = 4;
+pub const REDUCE_ORDER_WITH_FREE_FUNDS: u8 = 5;
+pub const CANCEL_ALL_ORDERS: u8 = 6;
+pub const CANCEL_ALL_ORDERS_WITH_FREE_FUNDS: u8 = 7;
+pub const CANCEL_UP_TO: u8 = 8;
+pub const CANCEL_UP_TO_WITH_FREE_FUNDS: u8 = 9;
+pub const CANCEL_MULTIPLE_ORDERS_BY_ID: u8 = 10;
+pub const CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS: u8 = 11;
+pub const WITHDRAW_FUNDS: u8 = 12;
+pub const DEPOSIT_FUNDS: u8 = 13;
+pub const REQUEST_SEAT: u8 = 14;
+pub const LOG: u8 = 15;
+pub const PLACE_MULTIPLE_POST_ONLY_ORDERS: u8 = 16;
+pub const PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS: u8 = 17;
+pub const INITIALIZE_MARKET: u8 = 100;
+pub const CLAIM_AUTHORITY: u8 = 101;
+pub const NAME_SUCCESSOR: u8 = 102;
+pub const CHANGE_MARKET_STATUS: u8 = 103;
+pub const CHANGE_SEAT_STATUS: u8 = 104;
+pub const REQUEST_SEAT_AUTHORIZED: u8 = 105;
+pub const EVICT_SEAT: u8 = 106;
+pub const FORCE_CANCEL_ORDERS: u8 = 107;
+pub const COLLECT_FEES: u8 = 108;
+pub const CHANGE_FEE_RECIPIENT: u8 = 109;
+
+// -----------------------------------------------------------------------------
+// Custom types
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum Side {
+    Bid,
+    Ask,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum SelfTradeBehavior {
+    Abort,
+    CancelProvide,
+    DecrementTake,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum OrderPacket {
+    /// Rests on the book or fails; never takes liquidity.
+    PostOnly {
+        side: Side,
+        price_in_ticks: u64,
+        num_base_lots: u64,
+        client_order_id: u128,
+        /// Fail instead of amending the price when the order would cross.
+        reject_post_only: bool,
+        use_only_deposited_funds: bool,
+        last_valid_slot: Option<u64>,
+        last_valid_unix_timestamp_in_seconds: Option<u64>,
+        fail_silently_on_insufficient_funds: bool,
+    },
+    /// Takes what crosses, then rests the remainder.
+    Limit {
+        side: Side,
+        price_in_ticks: u64,
+        num_base_lots: u64,
+        self_trade_behavior: SelfTradeBehavior,
+        /// Maximum number of resting orders to match against.
+        match_limit: Option<u64>,
+        client_order_id: u128,
+        use_only_deposited_funds: bool,
+        last_valid_slot: Option<u64>,
+        last_valid_unix_timestamp_in_seconds: Option<u64>,
+        fail_silently_on_insufficient_funds: bool,
+    },
+    /// Takes what crosses and never rests; used by swaps.
+    ImmediateOrCancel {
+        side: Side,
+        /// `None` for a market order.
+        price_in_ticks: Option<u64>,
+        num_base_lots: u64,
+        num_quote_lots: u64,
+        min_base_lots_to_fill: u64,
+        min_quote_lots_to_fill: u64,
+        self_trade_behavior: SelfTradeBehavior,
+        match_limit: Option<u64>,
+        client_order_id: u128,
+        use_only_deposited_funds: bool,
+        last_valid_slot: Option<u64>,
+        last_valid_unix_timestamp_in_seconds: Option<u64>,
+    },
+}
+
+impl OrderPacket {
+    /// Decodes an order packet the way the program does: packets built before
+    /// `fail_silently_on_insufficient_funds` existed lack its trailing byte and
+    /// are retried with a `false` appended.
+    pub fn decode(data: &[u8]) -> Result<Self, ParseError> {
+        match Self::try_from_slice(data) {
+            Ok(packet) => Ok(packet),
+            Err(err) => Self::try_from_slice(&[data, &[0]].concat()).map_err(|_| err.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CancelOrderParams {
+    pub side: Side,
+    pub price_in_ticks: u64,
+    pub order_sequence_number: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ReduceOrderParams {
+    pub base_params: CancelOrderParams,
+    /// Base lots to remove from the order.
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CancelUpToParams {
+    pub side: Side,
+    /// Only cancel orders at or beyond this price.
+    pub tick_limit: Option<u64>,
+    pub num_orders_to_search: Option<u32>,
+    pub num_orders_to_cancel: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CancelMultipleOrdersByIdParams {
+    pub orders: Vec<CancelOrderParams>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct WithdrawParams {
+    /// `None` withdraws all free quote lots.
+    pub quote_lots_to_withdraw: Option<u64>,
+    /// `None` withdraws all free base lots.
+    pub base_lots_to_withdraw: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct DepositParams {
+    pub quote_lots_to_deposit: u64,
+    pub base_lots_to_deposit: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CondensedOrder {
+    pub price_in_ticks: u64,
+    pub size_in_base_lots: u64,
+    pub last_valid_slot: Option<u64>,
+    pub last_valid_unix_timestamp_in_seconds: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum FailedMultipleLimitOrderBehavior {
+    FailOnInsufficientFundsAndAmendOnCross,
+    FailOnInsufficientFundsAndFailOnCross,
+    SkipOnInsufficientFundsAndAmendOnCross,
+    SkipOnInsufficientFundsAndFailOnCross,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct MultipleOrderPacket {
+    pub bids: Vec<CondensedOrder>,
+    pub asks: Vec<CondensedOrder>,
+    pub client_order_id: Option<u128>,
+    pub failed_multiple_limit_order_behavior: FailedMultipleLimitOrderBehavior,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct MarketSizeParams {
+    pub bids_size: u64,
+    pub asks_size: u64,
+    pub num_seats: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct InitializeParams {
+    pub market_size_params: MarketSizeParams,
+    pub num_quote_lots_per_quote_unit: u64,
+    pub tick_size_in_quote_lots_per_base_unit: u64,
+    pub num_base_lots_per_base_unit: u64,
+    pub taker_fee_bps: u16,
+    pub fee_collector: Pubkey,
+    pub raw_base_units_per_base_unit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum MarketStatus {
+    Uninitialized,
+    Active,
+    PostOnly,
+    Paused,
+    Closed,
+    Tombstoned,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum SeatApprovalStatus {
+    NotApproved,
+    Approved,
+    Retired,
+}
 
 // -----------------------------------------------------------------------------
 // Instruction enumeration
@@ -16,6 +222,34 @@ pub const SWAP_WITH_FREE_FUNDS: u8 = 1;
 pub enum PhonenixInstruction {
     Swap(SwapInstruction),
     SwapWithFreeFunds(SwapWithFreeFundsInstruction),
+    PlaceLimitOrder(PlaceLimitOrderInstruction),
+    PlaceLimitOrderWithFreeFunds(PlaceLimitOrderWithFreeFundsInstruction),
+    ReduceOrder(ReduceOrderInstruction),
+    ReduceOrderWithFreeFunds(ReduceOrderWithFreeFundsInstruction),
+    CancelAllOrders,
+    CancelAllOrdersWithFreeFunds,
+    CancelUpTo(CancelUpToInstruction),
+    CancelUpToWithFreeFunds(CancelUpToWithFreeFundsInstruction),
+    CancelMultipleOrdersById(CancelMultipleOrdersByIdInstruction),
+    CancelMultipleOrdersByIdWithFreeFunds(CancelMultipleOrdersByIdWithFreeFundsInstruction),
+    WithdrawFunds(WithdrawFundsInstruction),
+    DepositFunds(DepositFundsInstruction),
+    RequestSeat,
+    /// Self-CPI carrying a batch of market events; decode its data with
+    /// [`crate::phoenix::events::unpack_log`].
+    Log,
+    PlaceMultiplePostOnlyOrders(PlaceMultiplePostOnlyOrdersInstruction),
+    PlaceMultiplePostOnlyOrdersWithFreeFunds(PlaceMultiplePostOnlyOrdersWithFreeFundsInstruction),
+    InitializeMarket(InitializeMarketInstruction),
+    ClaimAuthority,
+    NameSuccessor(NameSuccessorInstruction),
+    ChangeMarketStatus(ChangeMarketStatusInstruction),
+    ChangeSeatStatus(ChangeSeatStatusInstruction),
+    RequestSeatAuthorized,
+    EvictSeat,
+    ForceCancelOrders(ForceCancelOrdersInstruction),
+    CollectFees,
+    ChangeFeeRecipient,
     Unknown,
 }
 
@@ -24,14 +258,97 @@ pub enum PhonenixInstruction {
 // -----------------------------------------------------------------------------
 #[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct SwapInstruction {
-    /// Raw order packet payload
-    pub order_packet: Vec<u8>,
+    pub order_packet: OrderPacket,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct SwapWithFreeFundsInstruction {
-    /// Raw order packet payload
-    pub order_packet: Vec<u8>,
+    pub order_packet: OrderPacket,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PlaceLimitOrderInstruction {
+    pub order_packet: OrderPacket,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PlaceLimitOrderWithFreeFundsInstruction {
+    pub order_packet: OrderPacket,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ReduceOrderInstruction {
+    pub params: ReduceOrderParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ReduceOrderWithFreeFundsInstruction {
+    pub params: ReduceOrderParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CancelUpToInstruction {
+    pub params: CancelUpToParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CancelUpToWithFreeFundsInstruction {
+    pub params: CancelUpToParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CancelMultipleOrdersByIdInstruction {
+    pub params: CancelMultipleOrdersByIdParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CancelMultipleOrdersByIdWithFreeFundsInstruction {
+    pub params: CancelMultipleOrdersByIdParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct WithdrawFundsInstruction {
+    pub withdraw_funds_params: WithdrawParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct DepositFundsInstruction {
+    pub deposit_funds_params: DepositParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PlaceMultiplePostOnlyOrdersInstruction {
+    pub multiple_order_packet: MultipleOrderPacket,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PlaceMultiplePostOnlyOrdersWithFreeFundsInstruction {
+    pub multiple_order_packet: MultipleOrderPacket,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct InitializeMarketInstruction {
+    pub initialize_params: InitializeParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct NameSuccessorInstruction {
+    pub successor: Pubkey,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ChangeMarketStatusInstruction {
+    pub market_status: MarketStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ChangeSeatStatusInstruction {
+    pub approval_status: SeatApprovalStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ForceCancelOrdersInstruction {
+    pub params: CancelUpToParams,
 }
 
 // -----------------------------------------------------------------------------
@@ -48,11 +365,45 @@ impl<'a> TryFrom<&'a [u8]> for PhonenixInstruction {
         let discriminator = disc[0];
         Ok(match discriminator {
             SWAP => Self::Swap(SwapInstruction {
-                order_packet: payload.to_vec(),
+                order_packet: OrderPacket::decode(payload)?,
             }),
             SWAP_WITH_FREE_FUNDS => Self::SwapWithFreeFunds(SwapWithFreeFundsInstruction {
-                order_packet: payload.to_vec(),
+                order_packet: OrderPacket::decode(payload)?,
+            }),
+            PLACE_LIMIT_ORDER => Self::PlaceLimitOrder(PlaceLimitOrderInstruction {
+                order_packet: OrderPacket::decode(payload)?,
+            }),
+            PLACE_LIMIT_ORDER_WITH_FREE_FUNDS => Self::PlaceLimitOrderWithFreeFunds(PlaceLimitOrderWithFreeFundsInstruction {
+                order_packet: OrderPacket::decode(payload)?,
             }),
+            REDUCE_ORDER => Self::ReduceOrder(ReduceOrderInstruction::try_from_slice(payload)?),
+            REDUCE_ORDER_WITH_FREE_FUNDS => Self::ReduceOrderWithFreeFunds(ReduceOrderWithFreeFundsInstruction::try_from_slice(payload)?),
+            CANCEL_ALL_ORDERS => Self::CancelAllOrders,
+            CANCEL_ALL_ORDERS_WITH_FREE_FUNDS => Self::CancelAllOrdersWithFreeFunds,
+            CANCEL_UP_TO => Self::CancelUpTo(CancelUpToInstruction::try_from_slice(payload)?),
+            CANCEL_UP_TO_WITH_FREE_FUNDS => Self::CancelUpToWithFreeFunds(CancelUpToWithFreeFundsInstruction::try_from_slice(payload)?),
+            CANCEL_MULTIPLE_ORDERS_BY_ID => Self::CancelMultipleOrdersById(CancelMultipleOrdersByIdInstruction::try_from_slice(payload)?),
+            CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS => {
+                Self::CancelMultipleOrdersByIdWithFreeFunds(CancelMultipleOrdersByIdWithFreeFundsInstruction::try_from_slice(payload)?)
+            }
+            WITHDRAW_FUNDS => Self::WithdrawFunds(WithdrawFundsInstruction::try_from_slice(payload)?),
+            DEPOSIT_FUNDS => Self::DepositFunds(DepositFundsInstruction::try_from_slice(payload)?),
+            REQUEST_SEAT => Self::RequestSeat,
+            LOG => Self::Log,
+            PLACE_MULTIPLE_POST_ONLY_ORDERS => Self::PlaceMultiplePostOnlyOrders(PlaceMultiplePostOnlyOrdersInstruction::try_from_slice(payload)?),
+            PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS => {
+                Self::PlaceMultiplePostOnlyOrdersWithFreeFunds(PlaceMultiplePostOnlyOrdersWithFreeFundsInstruction::try_from_slice(payload)?)
+            }
+            INITIALIZE_MARKET => Self::InitializeMarket(InitializeMarketInstruction::try_from_slice(payload)?),
+            CLAIM_AUTHORITY => Self::ClaimAuthority,
+            NAME_SUCCESSOR => Self::NameSuccessor(NameSuccessorInstruction::try_from_slice(payload)?),
+            CHANGE_MARKET_STATUS => Self::ChangeMarketStatus(ChangeMarketStatusInstruction::try_from_slice(payload)?),
+            CHANGE_SEAT_STATUS => Self::ChangeSeatStatus(ChangeSeatStatusInstruction::try_from_slice(payload)?),
+            REQUEST_SEAT_AUTHORIZED => Self::RequestSeatAuthorized,
+            EVICT_SEAT => Self::EvictSeat,
+            FORCE_CANCEL_ORDERS => Self::ForceCancelOrders(ForceCancelOrdersInstruction::try_from_slice(payload)?),
+            COLLECT_FEES => Self::CollectFees,
+            CHANGE_FEE_RECIPIENT => Self::ChangeFeeRecipient,
             other => return Err(ParseError::Unknown([other, 0, 0, 0, 0, 0, 0, 0])),
         })
     }
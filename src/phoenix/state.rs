@@ -0,0 +1,289 @@
+//! Phoenix market account state.
+//!
+//! A market account is a 576-byte [`MarketHeader`] followed by the zero-copy
+//! `FIFOMarket`: a few counters, then three red-black trees (sokoban) for the
+//! bids, the asks and the trader seats. Tree sizes come from the header's
+//! [`MarketSizeParams`]. Each tree is a `u32` root and padding, a node
+//! allocator header, then `[u32; 4]` registers (left, right, parent, color)
+//! and the key/value of every node; node indices are 1-based, `0` is nil.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::common::bincode::{invalid, BincodeReader};
+use crate::common::ParseError;
+
+pub use super::instructions::{MarketSizeParams, MarketStatus, Side};
+
+/// Size of the [`MarketHeader`].
+pub const MARKET_HEADER_LEN: usize = 576;
+
+/// [`MarketHeader::discriminant`] of a market account: the first 8 bytes
+/// (little-endian) of `sha256(program_id || "phoenix::program::accounts::MarketHeader")`.
+pub const MARKET_HEADER_DISCRIMINANT: u64 = 5885176012900175465;
+
+const FIFO_MARKET_HEADER_LEN: usize = 304;
+const TREE_HEADER_LEN: usize = 32;
+const ORDER_NODE_LEN: usize = 64;
+const TRADER_NODE_LEN: usize = 144;
+const REGISTER_LEFT: usize = 0;
+const REGISTER_RIGHT: usize = 1;
+
+// -----------------------------------------------------------------------------
+// Header
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct TokenParams {
+    pub decimals: u32,
+    pub vault_bump: u32,
+    pub mint_key: Pubkey,
+    pub vault_key: Pubkey,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct MarketHeader {
+    pub discriminant: u64,
+    /// A [`MarketStatus`] discriminant.
+    pub status: u64,
+    pub market_size_params: MarketSizeParams,
+    pub base_params: TokenParams,
+    /// Base atoms per base lot.
+    pub base_lot_size: u64,
+    pub quote_params: TokenParams,
+    /// Quote atoms per quote lot.
+    pub quote_lot_size: u64,
+    pub tick_size_in_quote_atoms_per_base_unit: u64,
+    pub authority: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub market_sequence_number: u64,
+    pub successor: Pubkey,
+    /// Raw base units (`10^base_decimals` atoms) per base unit; `0` means 1.
+    pub raw_base_units_per_base_unit: u32,
+    pub padding1: u32,
+    pub padding2: [u64; 32],
+}
+
+// -----------------------------------------------------------------------------
+// Orderbook
+// -----------------------------------------------------------------------------
+/// An order resting on the book (L3).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestingOrder {
+    pub price_in_ticks: u64,
+    /// As stored in the order id and reported by events: bitwise-inverted
+    /// for bids.
+    pub order_sequence_number: u64,
+    /// 1-based index of the trader's seat.
+    pub trader_index: u64,
+    pub trader: Pubkey,
+    pub num_base_lots: u64,
+    /// `0` when the order does not expire by slot.
+    pub last_valid_slot: u64,
+    /// `0` when the order does not expire by time.
+    pub last_valid_unix_timestamp_in_seconds: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraderState {
+    pub trader: Pubkey,
+    pub quote_lots_locked: u64,
+    pub quote_lots_free: u64,
+    pub base_lots_locked: u64,
+    pub base_lots_free: u64,
+}
+
+/// Depth at one price (L2).
+#[derive(Debug, Clone, PartialEq)]
+pub struct L2Level {
+    pub price_in_ticks: u64,
+    pub num_base_lots: u64,
+    /// Quote units per raw base unit.
+    pub price: f64,
+    /// Raw base units.
+    pub size: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Market {
+    pub header: MarketHeader,
+    pub base_lots_per_base_unit: u64,
+    pub tick_size_in_quote_lots_per_base_unit: u64,
+    pub order_sequence_number: u64,
+    pub taker_fee_bps: u64,
+    pub collected_quote_lot_fees: u64,
+    pub unclaimed_quote_lot_fees: u64,
+    /// Resting bids, best first.
+    pub bids: Vec<RestingOrder>,
+    /// Resting asks, best first.
+    pub asks: Vec<RestingOrder>,
+    /// Seated traders, by seat index.
+    pub traders: Vec<TraderState>,
+}
+
+impl Market {
+    /// Resting orders on `side`, best first.
+    pub fn orders(&self, side: Side) -> &[RestingOrder] {
+        match side {
+            Side::Bid => &self.bids,
+            Side::Ask => &self.asks,
+        }
+    }
+
+    /// Orders on `side` aggregated by price, best first.
+    pub fn levels(&self, side: Side) -> Vec<L2Level> {
+        let mut levels: Vec<L2Level> = Vec::new();
+        for order in self.orders(side) {
+            match levels.last_mut() {
+                Some(level) if level.price_in_ticks == order.price_in_ticks => {
+                    level.num_base_lots += order.num_base_lots;
+                    level.size = self.base_lots_to_size(level.num_base_lots);
+                }
+                _ => levels.push(L2Level {
+                    price_in_ticks: order.price_in_ticks,
+                    num_base_lots: order.num_base_lots,
+                    price: self.ticks_to_price(order.price_in_ticks),
+                    size: self.base_lots_to_size(order.num_base_lots),
+                }),
+            }
+        }
+        levels
+    }
+
+    /// Converts a price in ticks to quote units per raw base unit.
+    pub fn ticks_to_price(&self, price_in_ticks: u64) -> f64 {
+        let quote_atoms_per_quote_unit = 10f64.powi(self.header.quote_params.decimals as i32);
+        let raw_base_units_per_base_unit = self.header.raw_base_units_per_base_unit.max(1) as f64;
+        price_in_ticks as f64 * self.header.tick_size_in_quote_atoms_per_base_unit as f64 / quote_atoms_per_quote_unit / raw_base_units_per_base_unit
+    }
+
+    /// Converts base lots to raw base units.
+    pub fn base_lots_to_size(&self, num_base_lots: u64) -> f64 {
+        let base_atoms_per_raw_base_unit = 10f64.powi(self.header.base_params.decimals as i32);
+        num_base_lots as f64 * self.header.base_lot_size as f64 / base_atoms_per_raw_base_unit
+    }
+}
+
+/// Yields the 0-based slot of every node reachable from the root of a tree.
+fn tree_nodes(tree: &[u8], node_len: usize, capacity: usize) -> Result<Vec<usize>, ParseError> {
+    let root = u32::from_le_bytes(tree[..4].try_into().unwrap()) as usize;
+    let nodes = &tree[TREE_HEADER_LEN..];
+    let mut slots = Vec::new();
+    let mut stack = vec![root];
+    while let Some(index) = stack.pop() {
+        if index == 0 {
+            continue;
+        }
+        if index > capacity || slots.len() >= capacity {
+            return Err(invalid(format!("invalid red-black tree node {index}")));
+        }
+        let node = &nodes[(index - 1) * node_len..];
+        let register = |i: usize| u32::from_le_bytes(node[i * 4..i * 4 + 4].try_into().unwrap()) as usize;
+        stack.push(register(REGISTER_RIGHT));
+        stack.push(register(REGISTER_LEFT));
+        slots.push(index - 1);
+    }
+    Ok(slots)
+}
+
+impl<'a> TryFrom<&'a [u8]> for Market {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() < MARKET_HEADER_LEN + FIFO_MARKET_HEADER_LEN {
+            return Err(ParseError::TooShort(data.len()));
+        }
+        let header = MarketHeader::try_from_slice(&data[..MARKET_HEADER_LEN])?;
+        if header.discriminant != MARKET_HEADER_DISCRIMINANT {
+            return Err(invalid(format!("invalid market discriminant {}", header.discriminant)));
+        }
+        let mut r = BincodeReader::new(&data[MARKET_HEADER_LEN..]);
+        r.take(256)?;
+        let base_lots_per_base_unit = r.u64()?;
+        let tick_size_in_quote_lots_per_base_unit = r.u64()?;
+        let order_sequence_number = r.u64()?;
+        let taker_fee_bps = r.u64()?;
+        let collected_quote_lot_fees = r.u64()?;
+        let unclaimed_quote_lot_fees = r.u64()?;
+
+        let sizes = &header.market_size_params;
+        let tree_len = |capacity: u64, node_len: usize| {
+            usize::try_from(capacity)
+                .ok()
+                .and_then(|capacity| capacity.checked_mul(node_len))
+                .and_then(|len| len.checked_add(TREE_HEADER_LEN))
+                .ok_or_else(|| invalid(format!("invalid tree capacity {capacity}")))
+        };
+        let bids_tree = r.take(tree_len(sizes.bids_size, ORDER_NODE_LEN)?)?;
+        let asks_tree = r.take(tree_len(sizes.asks_size, ORDER_NODE_LEN)?)?;
+        let traders_tree = r.take(tree_len(sizes.num_seats, TRADER_NODE_LEN)?)?;
+
+        // Seats are referenced by their 1-based slot in the traders tree.
+        let mut trader_slots = tree_nodes(traders_tree, TRADER_NODE_LEN, sizes.num_seats as usize)?;
+        trader_slots.sort_unstable();
+        let mut traders = Vec::with_capacity(trader_slots.len());
+        let mut seat_keys = vec![None; sizes.num_seats as usize];
+        for slot in trader_slots {
+            let start = TREE_HEADER_LEN + slot * TRADER_NODE_LEN + 16;
+            let mut node = BincodeReader::new(&traders_tree[start..start + TRADER_NODE_LEN - 16]);
+            let trader = node.pubkey()?;
+            seat_keys[slot] = Some(trader);
+            traders.push(TraderState {
+                trader,
+                quote_lots_locked: node.u64()?,
+                quote_lots_free: node.u64()?,
+                base_lots_locked: node.u64()?,
+                base_lots_free: node.u64()?,
+            });
+        }
+
+        let read_orders = |tree: &[u8], capacity: u64| -> Result<Vec<RestingOrder>, ParseError> {
+            tree_nodes(tree, ORDER_NODE_LEN, capacity as usize)?
+                .into_iter()
+                .map(|slot| {
+                    let start = TREE_HEADER_LEN + slot * ORDER_NODE_LEN + 16;
+                    let mut node = BincodeReader::new(&tree[start..start + ORDER_NODE_LEN - 16]);
+                    let price_in_ticks = node.u64()?;
+                    let order_sequence_number = node.u64()?;
+                    let trader_index = node.u64()?;
+                    let trader = (trader_index as usize)
+                        .checked_sub(1)
+                        .and_then(|seat| seat_keys.get(seat).copied().flatten())
+                        .ok_or_else(|| invalid(format!("order references unknown seat {trader_index}")))?;
+                    Ok(RestingOrder {
+                        price_in_ticks,
+                        order_sequence_number,
+                        trader_index,
+                        trader,
+                        num_base_lots: node.u64()?,
+                        last_valid_slot: node.u64()?,
+                        last_valid_unix_timestamp_in_seconds: node.u64()?,
+                    })
+                })
+                .collect()
+        };
+        // Price first, then time: bid sequence numbers are inverted, so the
+        // older order has the larger stored value.
+        let mut bids = read_orders(bids_tree, sizes.bids_size)?;
+        bids.sort_by(|a, b| (b.price_in_ticks, b.order_sequence_number).cmp(&(a.price_in_ticks, a.order_sequence_number)));
+        let mut asks = read_orders(asks_tree, sizes.asks_size)?;
+        asks.sort_by_key(|order| (order.price_in_ticks, order.order_sequence_number));
+
+        Ok(Self {
+            header,
+            base_lots_per_base_unit,
+            tick_size_in_quote_lots_per_base_unit,
+            order_sequence_number,
+            taker_fee_bps,
+            collected_quote_lot_fees,
+            unclaimed_quote_lot_fees,
+            bids,
+            asks,
+            traders,
+        })
+    }
+}
+
+/// Decodes the data of a Phoenix market account.
+pub fn unpack(data: &[u8]) -> Result<Market, ParseError> {
+    Market::try_from(data)
+}
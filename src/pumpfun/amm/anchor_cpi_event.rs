@@ -78,26 +78,10 @@ pub struct SellAnchorCpiEvent {
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for PumpFunAmmAnchorCpiEvent {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            // 8 bytes discriminator
-            return Err(ParseError::TooShort(data.len()));
-        }
-
-        let disc: [u8; 8] = data[0..8].try_into().expect("slice len 8");
-        let payload = &data[8..]; // skip the discriminator
-
-        Ok(match disc {
-            BUY_ANCHOR_CPI_EVENT => Self::Buy(BuyAnchorCpiEvent::try_from_slice(payload)?),
-            SELL_ANCHOR_CPI_EVENT => Self::Sell(SellAnchorCpiEvent::try_from_slice(payload)?),
-            // If the discriminator does not match any known event, return Unknown
-            other => return Err(ParseError::Unknown(other)),
-        })
-    }
-}
+anchor_events!(PumpFunAmmAnchorCpiEvent {
+    BUY_ANCHOR_CPI_EVENT => Buy(BuyAnchorCpiEvent),
+    SELL_ANCHOR_CPI_EVENT => Sell(SellAnchorCpiEvent),
+});
 
 /// Convenience wrapper that forwards to `TryFrom`.
 pub fn unpack(data: &[u8]) -> Result<PumpFunAmmAnchorCpiEvent, ParseError> {
@@ -7,7 +7,6 @@ use solana_program::pubkey::Pubkey;
 // -------------------------------------------------------------------------
 // Discriminators
 // -------------------------------------------------------------------------
-const PUMP_EVENT: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
 const BUY_EVENT: [u8; 8] = [103, 244, 82, 31, 44, 245, 119, 119];
 const CREATE_CONFIG_EVENT: [u8; 8] = [107, 52, 89, 129, 55, 226, 81, 22];
 const CREATE_POOL_EVENT: [u8; 8] = [177, 49, 12, 210, 160, 118, 167, 116]; // b1310cd2a076a774 (341)
@@ -281,41 +280,19 @@ pub struct WithdrawEvent {
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for PumpFunAmmEvent {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 16 {
-            // 8 bytes discriminator + 8 bytes Anchor discriminator
-            return Err(ParseError::TooShort(data.len()));
-        }
-
-        let disc: [u8; 8] = data[0..8].try_into().expect("slice len 8");
-        let anchor_disc: [u8; 8] = data[8..16].try_into().expect("slice len 8");
-        let payload = &data[16..]; // skip both discriminators
-
-        // 1️⃣ Pump.fun tag (bytes 0‥7)
-        if disc != PUMP_EVENT {
-            return Err(ParseError::PumpFunUnknown(anchor_disc));
-        }
-
-        Ok(match anchor_disc {
-            BUY_EVENT => Self::BuyEventV2(BuyEventV2::try_from_slice(payload)?),
-            SELL_EVENT => Self::SellEventV2(SellEventV2::try_from_slice(payload)?),
-            CREATE_CONFIG_EVENT => Self::CreateConfigEvent(CreateConfigEvent::try_from_slice(payload)?),
-            // TO-DO CreatePoolEventV1
-            CREATE_POOL_EVENT => Self::CreatePoolEventV2(CreatePoolEventV2::try_from_slice(payload)?),
-            DEPOSIT_EVENT => Self::DepositEvent(DepositEvent::try_from_slice(payload)?),
-            DISABLE_EVENT => Self::DisableEvent(DisableEvent::try_from_slice(payload)?),
-            EXTEND_ACCOUNT_EVENT => Self::ExtendAccountEvent(ExtendAccountEvent::try_from_slice(payload)?),
-            UPDATE_ADMIN_EVENT => Self::UpdateAdminEvent(UpdateAdminEvent::try_from_slice(payload)?),
-            UPDATE_FEE_CONFIG_EVENT => Self::UpdateFeeConfigEvent(UpdateFeeConfigEvent::try_from_slice(payload)?),
-            WITHDRAW_EVENT => Self::WithdrawEvent(WithdrawEvent::try_from_slice(payload)?),
-            // If the discriminator does not match any known event, return Unknown
-            _ => return Err(ParseError::AnchorUnknown(anchor_disc)),
-        })
-    }
-}
+anchor_events!(PumpFunAmmEvent {
+    BUY_EVENT => BuyEventV2(BuyEventV2),
+    SELL_EVENT => SellEventV2(SellEventV2),
+    CREATE_CONFIG_EVENT => CreateConfigEvent(CreateConfigEvent),
+    // TO-DO CreatePoolEventV1
+    CREATE_POOL_EVENT => CreatePoolEventV2(CreatePoolEventV2),
+    DEPOSIT_EVENT => DepositEvent(DepositEvent),
+    DISABLE_EVENT => DisableEvent(DisableEvent),
+    EXTEND_ACCOUNT_EVENT => ExtendAccountEvent(ExtendAccountEvent),
+    UPDATE_ADMIN_EVENT => UpdateAdminEvent(UpdateAdminEvent),
+    UPDATE_FEE_CONFIG_EVENT => UpdateFeeConfigEvent(UpdateFeeConfigEvent),
+    WITHDRAW_EVENT => WithdrawEvent(WithdrawEvent),
+});
 
 /// Convenience wrapper that forwards to `TryFrom`.
 pub fn unpack(data: &[u8]) -> Result<PumpFunAmmEvent, ParseError> {
@@ -1,5 +1,6 @@
 //! on-chain **events** and their Borsh-deserialisation helpers.
 
+use crate::common::anchor::split_event;
 use crate::common::ParseError;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
@@ -226,16 +227,8 @@ impl<'a> TryFrom<&'a [u8]> for PumpFunEvent {
     type Error = ParseError;
 
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 16 {
-            // 8 bytes discriminator + 8 bytes Anchor discriminator
-            return Err(ParseError::TooShort(data.len()));
-        }
-
-        let _disc: [u8; 8] = data[0..8].try_into().expect("slice len 8");
-        let anchor_disc: [u8; 8] = data[8..16].try_into().expect("slice len 8");
-        let payload = &data[16..]; // skip both discriminators
-
-        Ok(match anchor_disc {
+        let (disc, payload) = split_event(data)?;
+        Ok(match disc {
             CREATE => Self::Create(CreateEvent::try_from_slice(payload)?),
             COMPLETE => Self::Complete(CompleteEvent::try_from_slice(payload)?),
             SET_PARAMS => Self::SetParams(SetParamsEvent::try_from_slice(payload)?),
@@ -306,32 +306,29 @@ pub struct WithdrawEvent {
     pub user_pool_token_account: Pubkey,
 }
 
+anchor_events!(PumpSwapEvent {
+    ADMIN_SET_COIN_CREATOR_EVENT => AdminSetCoinCreator(AdminSetCoinCreatorEvent),
+    ADMIN_UPDATE_TOKEN_INCENTIVES_EVENT => AdminUpdateTokenIncentives(AdminUpdateTokenIncentivesEvent),
+    BUY_EVENT => Buy(BuyEvent),
+    CLAIM_TOKEN_INCENTIVES_EVENT => ClaimTokenIncentives(ClaimTokenIncentivesEvent),
+    CLOSE_USER_VOLUME_ACCUMULATOR_EVENT => CloseUserVolumeAccumulator(CloseUserVolumeAccumulatorEvent),
+    COLLECT_COIN_CREATOR_FEE_EVENT => CollectCoinCreatorFee(CollectCoinCreatorFeeEvent),
+    CREATE_CONFIG_EVENT => CreateConfig(CreateConfigEvent),
+    CREATE_POOL_EVENT => CreatePool(CreatePoolEvent),
+    DEPOSIT_EVENT => Deposit(DepositEvent),
+    DISABLE_EVENT => Disable(DisableEvent),
+    EXTEND_ACCOUNT_EVENT => ExtendAccount(ExtendAccountEvent),
+    INIT_USER_VOLUME_ACCUMULATOR_EVENT => InitUserVolumeAccumulator(InitUserVolumeAccumulatorEvent),
+    SELL_EVENT => Sell(SellEvent),
+    SET_BONDING_CURVE_COIN_CREATOR_EVENT => SetBondingCurveCoinCreator(SetBondingCurveCoinCreatorEvent),
+    SET_METAPLEX_COIN_CREATOR_EVENT => SetMetaplexCoinCreator(SetMetaplexCoinCreatorEvent),
+    SYNC_USER_VOLUME_ACCUMULATOR_EVENT => SyncUserVolumeAccumulator(SyncUserVolumeAccumulatorEvent),
+    UPDATE_ADMIN_EVENT => UpdateAdmin(UpdateAdminEvent),
+    UPDATE_FEE_CONFIG_EVENT => UpdateFeeConfig(UpdateFeeConfigEvent),
+    WITHDRAW_EVENT => Withdraw(WithdrawEvent),
+});
+
+/// Convenience wrapper that forwards to `TryFrom`.
 pub fn unpack_event(data: &[u8]) -> Result<PumpSwapEvent, ParseError> {
-    if data.len() < 8 {
-        return Err(ParseError::TooShort(data.len()));
-    }
-    let (disc, rest) = data.split_at(8);
-    let disc: [u8; 8] = disc.try_into().unwrap();
-    Ok(match disc {
-        ADMIN_SET_COIN_CREATOR_EVENT => PumpSwapEvent::AdminSetCoinCreator(AdminSetCoinCreatorEvent::try_from_slice(rest)?),
-        ADMIN_UPDATE_TOKEN_INCENTIVES_EVENT => PumpSwapEvent::AdminUpdateTokenIncentives(AdminUpdateTokenIncentivesEvent::try_from_slice(rest)?),
-        BUY_EVENT => PumpSwapEvent::Buy(BuyEvent::try_from_slice(rest)?),
-        CLAIM_TOKEN_INCENTIVES_EVENT => PumpSwapEvent::ClaimTokenIncentives(ClaimTokenIncentivesEvent::try_from_slice(rest)?),
-        CLOSE_USER_VOLUME_ACCUMULATOR_EVENT => PumpSwapEvent::CloseUserVolumeAccumulator(CloseUserVolumeAccumulatorEvent::try_from_slice(rest)?),
-        COLLECT_COIN_CREATOR_FEE_EVENT => PumpSwapEvent::CollectCoinCreatorFee(CollectCoinCreatorFeeEvent::try_from_slice(rest)?),
-        CREATE_CONFIG_EVENT => PumpSwapEvent::CreateConfig(CreateConfigEvent::try_from_slice(rest)?),
-        CREATE_POOL_EVENT => PumpSwapEvent::CreatePool(CreatePoolEvent::try_from_slice(rest)?),
-        DEPOSIT_EVENT => PumpSwapEvent::Deposit(DepositEvent::try_from_slice(rest)?),
-        DISABLE_EVENT => PumpSwapEvent::Disable(DisableEvent::try_from_slice(rest)?),
-        EXTEND_ACCOUNT_EVENT => PumpSwapEvent::ExtendAccount(ExtendAccountEvent::try_from_slice(rest)?),
-        INIT_USER_VOLUME_ACCUMULATOR_EVENT => PumpSwapEvent::InitUserVolumeAccumulator(InitUserVolumeAccumulatorEvent::try_from_slice(rest)?),
-        SELL_EVENT => PumpSwapEvent::Sell(SellEvent::try_from_slice(rest)?),
-        SET_BONDING_CURVE_COIN_CREATOR_EVENT => PumpSwapEvent::SetBondingCurveCoinCreator(SetBondingCurveCoinCreatorEvent::try_from_slice(rest)?),
-        SET_METAPLEX_COIN_CREATOR_EVENT => PumpSwapEvent::SetMetaplexCoinCreator(SetMetaplexCoinCreatorEvent::try_from_slice(rest)?),
-        SYNC_USER_VOLUME_ACCUMULATOR_EVENT => PumpSwapEvent::SyncUserVolumeAccumulator(SyncUserVolumeAccumulatorEvent::try_from_slice(rest)?),
-        UPDATE_ADMIN_EVENT => PumpSwapEvent::UpdateAdmin(UpdateAdminEvent::try_from_slice(rest)?),
-        UPDATE_FEE_CONFIG_EVENT => PumpSwapEvent::UpdateFeeConfig(UpdateFeeConfigEvent::try_from_slice(rest)?),
-        WITHDRAW_EVENT => PumpSwapEvent::Withdraw(WithdrawEvent::try_from_slice(rest)?),
-        _ => return Err(ParseError::Unknown(disc)),
-    })
+    PumpSwapEvent::try_from(data)
 }
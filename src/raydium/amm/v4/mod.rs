@@ -2,6 +2,7 @@ use substreams_solana::b58;
 pub mod accounts;
 pub mod instructions;
 pub mod logs;
+pub mod state;
 
 /// Raydium Liquidity Pool V4
 ///
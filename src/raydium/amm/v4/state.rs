@@ -0,0 +1,194 @@
+//! Raydium AMM v4 on-chain account state.
+// https://github.com/raydium-io/raydium-amm/blob/master/program/src/state.rs
+//
+// Not an Anchor program: accounts are plain `#[repr(C)]` structs without a
+// discriminator, so they are told apart by their size. Every field is a
+// `u64`, `u128` or `Pubkey`, so the layout has no implicit padding and
+// decodes sequentially.
+
+use crate::common::bincode::invalid;
+use crate::common::ParseError;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Size of an [`AmmInfo`] account.
+pub const AMM_INFO_LEN: usize = 752;
+/// Size of a [`TargetOrders`] account.
+pub const TARGET_ORDERS_LEN: usize = 2208;
+/// Number of orders per side in [`TargetOrders`].
+pub const MAX_ORDER_LIMIT: usize = 50;
+
+// -----------------------------------------------------------------------------
+// Custom types
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u64)]
+pub enum AmmStatus {
+    Uninitialized = 0,
+    Initialized = 1,
+    Disabled = 2,
+    WithdrawOnly = 3,
+    /// Deposit and withdraw only.
+    LiquidityOnly = 4,
+    /// Orderbook and withdraw only.
+    OrderBookOnly = 5,
+    /// Swap and withdraw only.
+    SwapOnly = 6,
+    /// Initialized, waiting for `pool_open_time`.
+    WaitingTrade = 7,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Fees {
+    pub min_separate_numerator: u64,
+    pub min_separate_denominator: u64,
+    pub trade_fee_numerator: u64,
+    pub trade_fee_denominator: u64,
+    pub pnl_numerator: u64,
+    pub pnl_denominator: u64,
+    pub swap_fee_numerator: u64,
+    pub swap_fee_denominator: u64,
+}
+
+/// Pending PnL and cumulative swap totals.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct StateData {
+    pub need_take_pnl_coin: u64,
+    pub need_take_pnl_pc: u64,
+    pub total_pnl_pc: u64,
+    pub total_pnl_coin: u64,
+    /// Unix timestamp from which swaps are allowed.
+    pub pool_open_time: u64,
+    pub padding: [u64; 2],
+    pub orderbook_to_init_time: u64,
+    pub swap_coin_in_amount: u128,
+    pub swap_pc_out_amount: u128,
+    pub swap_acc_pc_fee: u64,
+    pub swap_pc_in_amount: u128,
+    pub swap_coin_out_amount: u128,
+    pub swap_acc_coin_fee: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct TargetOrder {
+    pub price: u64,
+    pub vol: u64,
+}
+
+// -----------------------------------------------------------------------------
+// Account structs
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct AmmInfo {
+    /// An [`AmmStatus`] discriminant.
+    pub status: u64,
+    pub nonce: u64,
+    pub order_num: u64,
+    pub depth: u64,
+    pub coin_decimals: u64,
+    pub pc_decimals: u64,
+    pub state: u64,
+    pub reset_flag: u64,
+    pub min_size: u64,
+    pub vol_max_cut_ratio: u64,
+    pub amount_wave: u64,
+    pub coin_lot_size: u64,
+    pub pc_lot_size: u64,
+    pub min_price_multiplier: u64,
+    pub max_price_multiplier: u64,
+    pub sys_decimal_value: u64,
+    pub fees: Fees,
+    pub state_data: StateData,
+    pub coin_vault: Pubkey,
+    pub pc_vault: Pubkey,
+    pub coin_vault_mint: Pubkey,
+    pub pc_vault_mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub open_orders: Pubkey,
+    pub market: Pubkey,
+    pub market_program: Pubkey,
+    pub target_orders: Pubkey,
+    pub padding1: [u64; 8],
+    /// Owner allowed to withdraw PnL and update parameters.
+    pub amm_owner: Pubkey,
+    pub lp_amount: u64,
+    pub client_order_id: u64,
+    pub recent_epoch: u64,
+    pub padding2: u64,
+}
+
+impl AmmInfo {
+    /// Decoded `status`, `None` for values unknown to this crate.
+    pub fn status(&self) -> Option<AmmStatus> {
+        Some(match self.status {
+            0 => AmmStatus::Uninitialized,
+            1 => AmmStatus::Initialized,
+            2 => AmmStatus::Disabled,
+            3 => AmmStatus::WithdrawOnly,
+            4 => AmmStatus::LiquidityOnly,
+            5 => AmmStatus::OrderBookOnly,
+            6 => AmmStatus::SwapOnly,
+            7 => AmmStatus::WaitingTrade,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct TargetOrders {
+    pub owner: [u64; 4],
+    pub buy_orders: [TargetOrder; MAX_ORDER_LIMIT],
+    pub padding1: [u64; 8],
+    pub target_x: u128,
+    pub target_y: u128,
+    pub plan_x_buy: u128,
+    pub plan_y_buy: u128,
+    pub plan_x_sell: u128,
+    pub plan_y_sell: u128,
+    pub placed_x: u128,
+    pub placed_y: u128,
+    pub calc_pnl_x: u128,
+    pub calc_pnl_y: u128,
+    pub sell_orders: [TargetOrder; MAX_ORDER_LIMIT],
+    pub padding2: [u64; 6],
+    pub replace_buy_client_id: [u64; 10],
+    pub replace_sell_client_id: [u64; 10],
+    pub last_order_numerator: u64,
+    pub last_order_denominator: u64,
+    pub plan_orders_cur: u64,
+    pub place_orders_cur: u64,
+    pub valid_buy_order_num: u64,
+    pub valid_sell_order_num: u64,
+    pub padding3: [u64; 10],
+    pub free_slot_bits: u128,
+}
+
+// -----------------------------------------------------------------------------
+// Account enumeration
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RaydiumAmmAccount {
+    AmmInfo(Box<AmmInfo>),
+    TargetOrders(Box<TargetOrders>),
+}
+
+impl<'a> TryFrom<&'a [u8]> for RaydiumAmmAccount {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(match data.len() {
+            AMM_INFO_LEN => Self::AmmInfo(Box::new(AmmInfo::try_from_slice(data)?)),
+            TARGET_ORDERS_LEN => Self::TargetOrders(Box::new(TargetOrders::try_from_slice(data)?)),
+            got => {
+                return Err(invalid(format!(
+                    "unrecognised account size {got}: expected {AMM_INFO_LEN} (AmmInfo) or {TARGET_ORDERS_LEN} (TargetOrders) bytes"
+                )))
+            }
+        })
+    }
+}
+
+/// Convenience wrapper that forwards to `TryFrom`.
+pub fn unpack(data: &[u8]) -> Result<RaydiumAmmAccount, ParseError> {
+    RaydiumAmmAccount::try_from(data)
+}
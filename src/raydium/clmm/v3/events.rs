@@ -249,31 +249,19 @@ pub struct UpdateRewardInfosEvent {
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for RaydiumClmmEvent {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-        let disc: [u8; 8] = data[0..8].try_into().expect("slice len 8");
-        let payload = &data[8..];
-        Ok(match disc {
-            COLLECT_PERSONAL_FEE_EVENT => Self::CollectPersonalFeeEvent(CollectPersonalFeeEvent::try_from_slice(payload)?),
-            COLLECT_PROTOCOL_FEE_EVENT => Self::CollectProtocolFeeEvent(CollectProtocolFeeEvent::try_from_slice(payload)?),
-            CONFIG_CHANGE_EVENT => Self::ConfigChangeEvent(ConfigChangeEvent::try_from_slice(payload)?),
-            CREATE_PERSONAL_POSITION_EVENT => Self::CreatePersonalPositionEvent(CreatePersonalPositionEvent::try_from_slice(payload)?),
-            DECREASE_LIQUIDITY_EVENT => Self::DecreaseLiquidityEvent(DecreaseLiquidityEvent::try_from_slice(payload)?),
-            INCREASE_LIQUIDITY_EVENT => Self::IncreaseLiquidityEvent(IncreaseLiquidityEvent::try_from_slice(payload)?),
-            LIQUIDITY_CALCULATE_EVENT => Self::LiquidityCalculateEvent(LiquidityCalculateEvent::try_from_slice(payload)?),
-            LIQUIDITY_CHANGE_EVENT => Self::LiquidityChangeEvent(LiquidityChangeEvent::try_from_slice(payload)?),
-            POOL_CREATED_EVENT => Self::PoolCreatedEvent(PoolCreatedEvent::try_from_slice(payload)?),
-            SWAP_EVENT => Self::SwapEvent(SwapEvent::try_from_slice(payload)?),
-            UPDATE_REWARD_INFOS_EVENT => Self::UpdateRewardInfosEvent(UpdateRewardInfosEvent::try_from_slice(payload)?),
-            other => return Err(ParseError::Unknown(other)),
-        })
-    }
-}
+anchor_events!(RaydiumClmmEvent {
+    COLLECT_PERSONAL_FEE_EVENT => CollectPersonalFeeEvent(CollectPersonalFeeEvent),
+    COLLECT_PROTOCOL_FEE_EVENT => CollectProtocolFeeEvent(CollectProtocolFeeEvent),
+    CONFIG_CHANGE_EVENT => ConfigChangeEvent(ConfigChangeEvent),
+    CREATE_PERSONAL_POSITION_EVENT => CreatePersonalPositionEvent(CreatePersonalPositionEvent),
+    DECREASE_LIQUIDITY_EVENT => DecreaseLiquidityEvent(DecreaseLiquidityEvent),
+    INCREASE_LIQUIDITY_EVENT => IncreaseLiquidityEvent(IncreaseLiquidityEvent),
+    LIQUIDITY_CALCULATE_EVENT => LiquidityCalculateEvent(LiquidityCalculateEvent),
+    LIQUIDITY_CHANGE_EVENT => LiquidityChangeEvent(LiquidityChangeEvent),
+    POOL_CREATED_EVENT => PoolCreatedEvent(PoolCreatedEvent),
+    SWAP_EVENT => SwapEvent(SwapEvent),
+    UPDATE_REWARD_INFOS_EVENT => UpdateRewardInfosEvent(UpdateRewardInfosEvent),
+});
 
 /// Convenience wrapper that forwards to `TryFrom`.
 pub fn unpack(data: &[u8]) -> Result<RaydiumClmmEvent, ParseError> {
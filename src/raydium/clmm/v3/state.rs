@@ -0,0 +1,332 @@
+//! Raydium CLMM v3 on-chain account state.
+//!
+//! Anchor accounts: an 8-byte discriminator followed by the struct.
+//! `PoolState`, `ObservationState`, `TickArrayState` and
+//! `TickArrayBitmapExtension` are zero-copy but `#[repr(C, packed)]`, so they
+//! decode with the same sequential layout as the Borsh accounts.
+
+use crate::common::ParseError;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Number of ticks in a [`TickArrayState`].
+pub const TICK_ARRAY_SIZE: usize = 60;
+/// Number of samples in an [`ObservationState`].
+pub const OBSERVATION_NUM: usize = 100;
+
+// -----------------------------------------------------------------------------
+// Discriminators (Anchor: sha256("account:<AccountName>")[..8])
+// -----------------------------------------------------------------------------
+pub const POOL_STATE: [u8; 8] = [247, 237, 227, 245, 215, 195, 222, 70];
+pub const AMM_CONFIG: [u8; 8] = [218, 244, 33, 104, 203, 203, 43, 111];
+pub const OBSERVATION_STATE: [u8; 8] = [122, 174, 197, 53, 129, 9, 165, 132];
+pub const TICK_ARRAY_STATE: [u8; 8] = [192, 155, 85, 205, 49, 249, 129, 42];
+pub const TICK_ARRAY_BITMAP_EXTENSION: [u8; 8] = [60, 150, 36, 219, 97, 128, 139, 153];
+pub const PERSONAL_POSITION_STATE: [u8; 8] = [70, 111, 150, 126, 230, 15, 25, 117];
+pub const PROTOCOL_POSITION_STATE: [u8; 8] = [100, 226, 145, 99, 146, 218, 160, 106];
+
+// -----------------------------------------------------------------------------
+// Custom types
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct RewardInfo {
+    /// Reward state
+    pub reward_state: u8,
+    /// Reward open time
+    pub open_time: u64,
+    /// Reward end time
+    pub end_time: u64,
+    /// Reward last update time
+    pub last_update_time: u64,
+    /// Q64.64 number indicates how many tokens per second are earned per unit of liquidity.
+    pub emissions_per_second_x64: u128,
+    /// The total amount of reward emissioned
+    pub reward_total_emissioned: u64,
+    /// The total amount of claimed reward
+    pub reward_claimed: u64,
+    /// Reward token mint.
+    pub token_mint: Pubkey,
+    /// Reward vault token account.
+    pub token_vault: Pubkey,
+    /// The owner that has permission to set reward param
+    pub authority: Pubkey,
+    /// Q64.64 number that tracks the total tokens earned per unit of liquidity since the reward
+    /// emissions were turned on.
+    pub reward_growth_global_x64: u128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct TickState {
+    pub tick: i32,
+    /// Amount of net liquidity added (subtracted) when tick is crossed from left to right (right to left)
+    pub liquidity_net: i128,
+    /// The total position liquidity that references this tick
+    pub liquidity_gross: u128,
+    /// Fee growth per unit of liquidity on the _other_ side of this tick (relative to the current tick)
+    /// only has relative meaning, not absolute — the value depends on when the tick is initialized
+    pub fee_growth_outside_0_x64: u128,
+    pub fee_growth_outside_1_x64: u128,
+    pub reward_growths_outside_x64: [u128; 3],
+    pub padding: [u32; 13],
+}
+
+/// The element of observations in ObservationState
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Observation {
+    /// The block timestamp of the observation
+    pub block_timestamp: u32,
+    /// the cumulative of tick during the duration time
+    pub tick_cumulative: i64,
+    /// padding for feature update
+    pub padding: [u64; 4],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PositionRewardInfo {
+    pub growth_inside_last_x64: u128,
+    pub reward_amount_owed: u64,
+}
+
+// -----------------------------------------------------------------------------
+// Account structs
+// -----------------------------------------------------------------------------
+/// The pool state
+///
+/// PDA of `[POOL_SEED, config, token_mint_0, token_mint_1]`
+///
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PoolState {
+    /// Bump to identify PDA
+    pub bump: [u8; 1],
+    pub amm_config: Pubkey,
+    pub owner: Pubkey,
+    /// Token pair of the pool, where token_mint_0 address < token_mint_1 address
+    pub token_mint_0: Pubkey,
+    pub token_mint_1: Pubkey,
+    /// Token pair vault
+    pub token_vault_0: Pubkey,
+    pub token_vault_1: Pubkey,
+    /// observation account key
+    pub observation_key: Pubkey,
+    /// mint0 and mint1 decimals
+    pub mint_decimals_0: u8,
+    pub mint_decimals_1: u8,
+    /// The minimum number of ticks between initialized ticks
+    pub tick_spacing: u16,
+    /// The currently in range liquidity available to the pool.
+    pub liquidity: u128,
+    /// The current price of the pool as a sqrt(token_1/token_0) Q64.64 value
+    pub sqrt_price_x64: u128,
+    /// The current tick of the pool, i.e. according to the last tick transition that was run.
+    pub tick_current: i32,
+    pub padding3: u16,
+    pub padding4: u16,
+    /// The fee growth as a Q64.64 number, i.e. fees of token_0 and token_1 collected per
+    /// unit of liquidity for the entire life of the pool.
+    pub fee_growth_global_0_x64: u128,
+    pub fee_growth_global_1_x64: u128,
+    /// The amounts of token_0 and token_1 that are owed to the protocol.
+    pub protocol_fees_token_0: u64,
+    pub protocol_fees_token_1: u64,
+    /// The amounts in and out of swap token_0 and token_1
+    pub swap_in_amount_token_0: u128,
+    pub swap_out_amount_token_1: u128,
+    pub swap_in_amount_token_1: u128,
+    pub swap_out_amount_token_0: u128,
+    /// Bitwise representation of the state of the pool
+    /// bit0, 1: disable open position and increase liquidity, 0: normal
+    /// bit1, 1: disable decrease liquidity, 0: normal
+    /// bit2, 1: disable collect fee, 0: normal
+    /// bit3, 1: disable collect reward, 0: normal
+    /// bit4, 1: disable swap, 0: normal
+    pub status: u8,
+    /// Leave blank for future use
+    pub padding: [u8; 7],
+    pub reward_infos: [RewardInfo; 3],
+    /// Packed initialized tick array state
+    pub tick_array_bitmap: [u64; 16],
+    /// except protocol_fee and fund_fee
+    pub total_fees_token_0: u64,
+    /// except protocol_fee and fund_fee
+    pub total_fees_claimed_token_0: u64,
+    pub total_fees_token_1: u64,
+    pub total_fees_claimed_token_1: u64,
+    pub fund_fees_token_0: u64,
+    pub fund_fees_token_1: u64,
+    pub open_time: u64,
+    pub recent_epoch: u64,
+    pub padding1: [u64; 24],
+    pub padding2: [u64; 32],
+}
+
+/// Holds the current owner of the factory
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct AmmConfig {
+    /// Bump to identify PDA
+    pub bump: u8,
+    pub index: u16,
+    /// Address of the protocol owner
+    pub owner: Pubkey,
+    /// The protocol fee
+    pub protocol_fee_rate: u32,
+    /// The trade fee, denominated in hundredths of a bip (10^-6)
+    pub trade_fee_rate: u32,
+    /// The tick spacing
+    pub tick_spacing: u16,
+    /// The fund fee, denominated in hundredths of a bip (10^-6)
+    pub fund_fee_rate: u32,
+    pub padding_u32: u32,
+    pub fund_owner: Pubkey,
+    pub padding: [u64; 3],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ObservationState {
+    /// Whether the ObservationState is initialized
+    pub initialized: bool,
+    /// recent update epoch
+    pub recent_epoch: u64,
+    /// the most-recently updated index of the observations array
+    pub observation_index: u16,
+    /// belongs to which pool
+    pub pool_id: Pubkey,
+    /// observation array
+    pub observations: [Observation; OBSERVATION_NUM],
+    /// padding for feature update
+    pub padding: [u64; 4],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct TickArrayState {
+    pub pool_id: Pubkey,
+    pub start_tick_index: i32,
+    pub ticks: [TickState; TICK_ARRAY_SIZE],
+    pub initialized_tick_count: u8,
+    pub recent_epoch: u64,
+    pub padding: [u8; 107],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct TickArrayBitmapExtension {
+    pub pool_id: Pubkey,
+    /// Packed initialized tick array state for start_tick_index is positive
+    pub positive_tick_array_bitmap: [[u64; 8]; 14],
+    /// Packed initialized tick array state for start_tick_index is negitive
+    pub negative_tick_array_bitmap: [[u64; 8]; 14],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PersonalPositionState {
+    /// Bump to identify PDA
+    pub bump: [u8; 1],
+    /// Mint address of the tokenized position
+    pub nft_mint: Pubkey,
+    /// The ID of the pool with which this token is connected
+    pub pool_id: Pubkey,
+    /// The lower bound tick of the position
+    pub tick_lower_index: i32,
+    /// The upper bound tick of the position
+    pub tick_upper_index: i32,
+    /// The amount of liquidity owned by this position
+    pub liquidity: u128,
+    /// The token_0 fee growth of the aggregate position as of the last action on the individual position
+    pub fee_growth_inside_0_last_x64: u128,
+    /// The token_1 fee growth of the aggregate position as of the last action on the individual position
+    pub fee_growth_inside_1_last_x64: u128,
+    /// The fees owed to the position owner in token_0, as of the last computation
+    pub token_fees_owed_0: u64,
+    /// The fees owed to the position owner in token_1, as of the last computation
+    pub token_fees_owed_1: u64,
+    pub reward_infos: [PositionRewardInfo; 3],
+    pub recent_epoch: u64,
+    pub padding: [u64; 7],
+}
+
+/// Info stored for each user's position
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ProtocolPositionState {
+    /// Bump to identify PDA
+    pub bump: u8,
+    /// The ID of the pool with which this token is connected
+    pub pool_id: Pubkey,
+    /// The lower bound tick of the position
+    pub tick_lower_index: i32,
+    /// The upper bound tick of the position
+    pub tick_upper_index: i32,
+    /// The amount of liquidity owned by this position
+    pub liquidity: u128,
+    /// The token_0 fee growth per unit of liquidity as of the last update to liquidity or fees owed
+    pub fee_growth_inside_0_last_x64: u128,
+    /// The token_1 fee growth per unit of liquidity as of the last update to liquidity or fees owed
+    pub fee_growth_inside_1_last_x64: u128,
+    /// The fees owed to the position owner in token_0
+    pub token_fees_owed_0: u64,
+    /// The fees owed to the position owner in token_1
+    pub token_fees_owed_1: u64,
+    /// The reward growth per unit of liquidity as of the last update to liquidity
+    pub reward_growth_inside: [u128; 3],
+    pub recent_epoch: u64,
+    pub padding: [u64; 7],
+}
+
+impl PoolState {
+    /// Price of token 0 in token 1, adjusted for mint decimals.
+    pub fn price(&self) -> f64 {
+        let sqrt_price = self.sqrt_price_x64 as f64 / 2f64.powi(64);
+        sqrt_price * sqrt_price * 10f64.powi(self.mint_decimals_0 as i32 - self.mint_decimals_1 as i32)
+    }
+}
+
+impl TickArrayState {
+    /// Tick index of `ticks[offset]` in a pool with `tick_spacing`.
+    pub fn tick_index(&self, offset: usize, tick_spacing: u16) -> i32 {
+        self.start_tick_index + offset as i32 * tick_spacing as i32
+    }
+
+    /// Ticks referenced by at least one position.
+    pub fn initialized_ticks(&self) -> impl Iterator<Item = &TickState> + '_ {
+        self.ticks.iter().filter(|tick| tick.liquidity_gross != 0)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Account enumeration
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RaydiumClmmAccount {
+    PoolState(Box<PoolState>),
+    AmmConfig(AmmConfig),
+    ObservationState(Box<ObservationState>),
+    TickArrayState(Box<TickArrayState>),
+    TickArrayBitmapExtension(Box<TickArrayBitmapExtension>),
+    PersonalPositionState(PersonalPositionState),
+    ProtocolPositionState(ProtocolPositionState),
+}
+
+impl<'a> TryFrom<&'a [u8]> for RaydiumClmmAccount {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() < 8 {
+            return Err(ParseError::TooShort(data.len()));
+        }
+        let (disc, mut rest) = data.split_at(8);
+        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
+        let rest = &mut rest;
+        Ok(match discriminator {
+            POOL_STATE => Self::PoolState(Box::new(PoolState::deserialize(rest)?)),
+            AMM_CONFIG => Self::AmmConfig(AmmConfig::deserialize(rest)?),
+            OBSERVATION_STATE => Self::ObservationState(Box::new(ObservationState::deserialize(rest)?)),
+            TICK_ARRAY_STATE => Self::TickArrayState(Box::new(TickArrayState::deserialize(rest)?)),
+            TICK_ARRAY_BITMAP_EXTENSION => Self::TickArrayBitmapExtension(Box::new(TickArrayBitmapExtension::deserialize(rest)?)),
+            PERSONAL_POSITION_STATE => Self::PersonalPositionState(PersonalPositionState::deserialize(rest)?),
+            PROTOCOL_POSITION_STATE => Self::ProtocolPositionState(ProtocolPositionState::deserialize(rest)?),
+            other => return Err(ParseError::Unknown(other)),
+        })
+    }
+}
+
+/// Convenience wrapper that forwards to `TryFrom`.
+pub fn unpack(data: &[u8]) -> Result<RaydiumClmmAccount, ParseError> {
+    RaydiumClmmAccount::try_from(data)
+}
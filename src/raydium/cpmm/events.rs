@@ -1,5 +1,6 @@
 //! Raydium CPMM events.
 
+use crate::common::anchor::split_event;
 use crate::common::ParseError;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
@@ -91,13 +92,7 @@ impl<'a> TryFrom<&'a [u8]> for RaydiumCpmmEvent {
     type Error = ParseError;
 
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-
-        let (disc, payload) = data.split_at(8);
-        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
-
+        let (discriminator, payload) = split_event(data)?;
         Ok(match discriminator {
             LP_CHANGE_EVENT => Self::LpChangeEvent(LpChangeEvent::try_from_slice(payload)?),
             SWAP_EVENT => match payload.len() {
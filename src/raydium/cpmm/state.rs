@@ -0,0 +1,201 @@
+//! Raydium CPMM on-chain account state.
+//!
+//! Anchor accounts: an 8-byte discriminator followed by the struct.
+//! `PoolState` and `ObservationState` are zero-copy but `#[repr(C, packed)]`,
+//! so they decode with the same sequential layout as the Borsh accounts.
+//!
+//! IDL v2 added the creator-fee fields to `PoolState` and `AmmConfig` by
+//! carving them out of the zeroed padding of the v1 layout, so account sizes
+//! did not change. The v2 structs below therefore decode both generations;
+//! for v1 accounts the creator-fee fields read as zero / `false`.
+
+use crate::common::ParseError;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Number of samples in an [`ObservationState`].
+pub const OBSERVATION_NUM: usize = 100;
+
+// -----------------------------------------------------------------------------
+// Discriminators (Anchor: sha256("account:<AccountName>")[..8])
+// -----------------------------------------------------------------------------
+pub const POOL_STATE: [u8; 8] = [247, 237, 227, 245, 215, 195, 222, 70];
+pub const AMM_CONFIG: [u8; 8] = [218, 244, 33, 104, 203, 203, 43, 111];
+pub const OBSERVATION_STATE: [u8; 8] = [122, 174, 197, 53, 129, 9, 165, 132];
+pub const PERMISSION: [u8; 8] = [224, 83, 28, 79, 10, 253, 161, 28];
+
+// -----------------------------------------------------------------------------
+// Custom types
+// -----------------------------------------------------------------------------
+/// The element of observations in ObservationState
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Observation {
+    /// The block timestamp of the observation
+    pub block_timestamp: u64,
+    /// the cumulative of token0 price during the duration time, Q32.32, the remaining 64 bit for overflow
+    pub cumulative_token_0_price_x32: u128,
+    /// the cumulative of token1 price during the duration time, Q32.32, the remaining 64 bit for overflow
+    pub cumulative_token_1_price_x32: u128,
+}
+
+// -----------------------------------------------------------------------------
+// Account structs
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PoolState {
+    /// Which config the pool belongs
+    pub amm_config: Pubkey,
+    /// pool creator
+    pub pool_creator: Pubkey,
+    /// Token A
+    pub token_0_vault: Pubkey,
+    /// Token B
+    pub token_1_vault: Pubkey,
+    /// Pool tokens are issued when A or B tokens are deposited.
+    /// Pool tokens can be withdrawn back to the original A or B token.
+    pub lp_mint: Pubkey,
+    /// Mint information for token A
+    pub token_0_mint: Pubkey,
+    /// Mint information for token B
+    pub token_1_mint: Pubkey,
+    /// token_0 program
+    pub token_0_program: Pubkey,
+    /// token_1 program
+    pub token_1_program: Pubkey,
+    /// observation account to store oracle data
+    pub observation_key: Pubkey,
+    pub auth_bump: u8,
+    /// Bitwise representation of the state of the pool
+    /// bit0, 1: disable deposit(value is 1), 0: normal
+    /// bit1, 1: disable withdraw(value is 2), 0: normal
+    /// bit2, 1: disable swap(value is 4), 0: normal
+    pub status: u8,
+    pub lp_mint_decimals: u8,
+    /// mint0 and mint1 decimals
+    pub mint_0_decimals: u8,
+    pub mint_1_decimals: u8,
+    /// True circulating supply without burns and lock ups
+    pub lp_supply: u64,
+    /// The amounts of token_0 and token_1 that are owed to the liquidity provider.
+    pub protocol_fees_token_0: u64,
+    pub protocol_fees_token_1: u64,
+    pub fund_fees_token_0: u64,
+    pub fund_fees_token_1: u64,
+    /// The timestamp allowed for swap in the pool.
+    pub open_time: u64,
+    /// recent epoch
+    pub recent_epoch: u64,
+    /// Creator fee collect mode
+    /// 0: both token_0 and token_1 can be used as trade fees. It depends on what the input token is when swapping
+    /// 1: only token_0 as trade fee
+    /// 2: only token_1 as trade fee
+    pub creator_fee_on: u8,
+    pub enable_creator_fee: bool,
+    pub padding1: [u8; 6],
+    pub creator_fees_token_0: u64,
+    pub creator_fees_token_1: u64,
+    /// padding for future updates
+    pub padding: [u64; 28],
+}
+
+/// Holds the current owner of the factory
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct AmmConfig {
+    /// Bump to identify PDA
+    pub bump: u8,
+    /// Status to control if new pool can be create
+    pub disable_create_pool: bool,
+    /// Config index
+    pub index: u16,
+    /// The trade fee, denominated in hundredths of a bip (10^-6)
+    pub trade_fee_rate: u64,
+    /// The protocol fee
+    pub protocol_fee_rate: u64,
+    /// The fund fee, denominated in hundredths of a bip (10^-6)
+    pub fund_fee_rate: u64,
+    /// Fee for create a new pool
+    pub create_pool_fee: u64,
+    /// Address of the protocol fee owner
+    pub protocol_owner: Pubkey,
+    /// Address of the fund fee owner
+    pub fund_owner: Pubkey,
+    /// The pool creator fee, denominated in hundredths of a bip (10^-6)
+    pub creator_fee_rate: u64,
+    /// padding
+    pub padding: [u64; 15],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ObservationState {
+    /// Whether the ObservationState is initialized
+    pub initialized: bool,
+    /// the most-recently updated index of the observations array
+    pub observation_index: u16,
+    pub pool_id: Pubkey,
+    /// observation array
+    pub observations: [Observation; OBSERVATION_NUM],
+    /// padding for feature update
+    pub padding: [u64; 4],
+}
+
+/// Holds the current owner of the factory
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Permission {
+    /// authority
+    pub authority: Pubkey,
+    /// padding
+    pub padding: [u64; 30],
+}
+
+impl PoolState {
+    /// Vault balances net of the protocol, fund and creator fees still held
+    /// in the vaults, i.e. the reserves the swap curve trades against.
+    pub fn vault_amount_without_fee(&self, vault_0: u64, vault_1: u64) -> (u64, u64) {
+        (
+            vault_0
+                .saturating_sub(self.protocol_fees_token_0)
+                .saturating_sub(self.fund_fees_token_0)
+                .saturating_sub(self.creator_fees_token_0),
+            vault_1
+                .saturating_sub(self.protocol_fees_token_1)
+                .saturating_sub(self.fund_fees_token_1)
+                .saturating_sub(self.creator_fees_token_1),
+        )
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Account enumeration
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RaydiumCpmmAccount {
+    PoolState(Box<PoolState>),
+    AmmConfig(AmmConfig),
+    ObservationState(Box<ObservationState>),
+    Permission(Permission),
+}
+
+impl<'a> TryFrom<&'a [u8]> for RaydiumCpmmAccount {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() < 8 {
+            return Err(ParseError::TooShort(data.len()));
+        }
+        let (disc, mut rest) = data.split_at(8);
+        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
+        let rest = &mut rest;
+        Ok(match discriminator {
+            POOL_STATE => Self::PoolState(Box::new(PoolState::deserialize(rest)?)),
+            AMM_CONFIG => Self::AmmConfig(AmmConfig::deserialize(rest)?),
+            OBSERVATION_STATE => Self::ObservationState(Box::new(ObservationState::deserialize(rest)?)),
+            PERMISSION => Self::Permission(Permission::deserialize(rest)?),
+            other => return Err(ParseError::Unknown(other)),
+        })
+    }
+}
+
+/// Convenience wrapper that forwards to `TryFrom`.
+pub fn unpack(data: &[u8]) -> Result<RaydiumCpmmAccount, ParseError> {
+    RaydiumCpmmAccount::try_from(data)
+}
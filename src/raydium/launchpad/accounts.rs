@@ -163,3 +163,571 @@ accounts!(
         program
     }
 );
+
+// -----------------------------------------------------------------------------
+// Claim creator fee accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    ClaimCreatorFeeAccounts,
+    get_claim_creator_fee_accounts,
+    {
+        /// The pool creator
+        creator,
+        /// PDA that acts as the authority for fee vaults
+        fee_vault_authority,
+        /// The creator fee vault
+        creator_fee_vault,
+        /// Token account receiving the claimed fees
+        recipient_token_account,
+        /// The mint for the quote token
+        quote_mint,
+        /// SPL Token program for the quote token
+        token_program,
+        /// Required for account creation
+        system_program,
+        /// Required for associated token program
+        associated_token_program
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Claim platform fee accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    ClaimPlatformFeeAccounts,
+    get_claim_platform_fee_accounts,
+    {
+        /// Only the wallet stored in platform_config can collect platform fees
+        platform_fee_wallet,
+        /// PDA that acts as the authority for pool vault and mint operations
+        authority,
+        /// Account that stores the pool's state and parameters
+        pool_state,
+        /// The platform config account
+        platform_config,
+        /// The pool's vault for quote tokens
+        quote_vault,
+        /// The address that receives the collected quote token fees
+        recipient_token_account,
+        /// The mint of the quote token vault
+        quote_mint,
+        /// SPL program for input token transfers
+        token_program,
+        /// Required for account creation
+        system_program,
+        /// Required for associated token program
+        associated_token_program
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Claim platform fee from vault accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    ClaimPlatformFeeFromVaultAccounts,
+    get_claim_platform_fee_from_vault_accounts,
+    {
+        /// Only the wallet stored in platform_config can collect platform fees
+        platform_fee_wallet,
+        /// PDA that acts as the authority for fee vaults
+        fee_vault_authority,
+        /// The platform config account
+        platform_config,
+        /// The platform fee vault
+        platform_fee_vault,
+        /// The address that receives the collected quote token fees
+        recipient_token_account,
+        /// The mint of the quote token vault
+        quote_mint,
+        /// SPL program for input token transfers
+        token_program,
+        /// Required for account creation
+        system_program,
+        /// Required for associated token program
+        associated_token_program
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Claim vested token accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    ClaimVestedTokenAccounts,
+    get_claim_vested_token_accounts,
+    {
+        /// The beneficiary of the vesting account
+        beneficiary,
+        /// PDA that acts as the authority for pool vault and mint operations
+        authority,
+        /// Account that stores the pool's state and parameters
+        pool_state,
+        /// The vesting record account
+        vesting_record,
+        /// The pool's vault for base tokens
+        base_vault,
+        /// The beneficiary's token account for base tokens
+        user_base_token,
+        /// The mint for the base token (token being sold)
+        base_token_mint,
+        /// SPL Token program for the base token
+        base_token_program,
+        /// Required for account creation
+        system_program,
+        /// Required for associated token program
+        associated_token_program
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Collect fee accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    CollectFeeAccounts,
+    get_collect_fee_accounts,
+    {
+        /// Only protocol_fee_owner saved in global_config can collect protocol fee now
+        owner,
+        /// PDA that acts as the authority for pool vault operations
+        authority,
+        /// Pool state stores accumulated protocol fee amount
+        pool_state,
+        /// Global config account stores owner
+        global_config,
+        /// The address that holds pool tokens for quote token
+        quote_vault,
+        /// The mint of the quote token vault
+        quote_mint,
+        /// The address that receives the collected quote token fees
+        recipient_token_account,
+        /// SPL program for input token transfers
+        token_program
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Collect migrate fee accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    CollectMigrateFeeAccounts,
+    get_collect_migrate_fee_accounts,
+    {
+        /// Only migrate_fee_owner saved in global_config can collect migrate fee now
+        owner,
+        /// PDA that acts as the authority for pool vault operations
+        authority,
+        /// Pool state stores accumulated protocol fee amount
+        pool_state,
+        /// Global config account stores owner
+        global_config,
+        /// The address that holds pool tokens for quote token
+        quote_vault,
+        /// The mint of the quote token vault
+        quote_mint,
+        /// The address that receives the collected quote token fees
+        recipient_token_account,
+        /// SPL program for input token transfers
+        token_program
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Create config accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    CreateConfigAccounts,
+    get_create_config_accounts,
+    {
+        /// The protocol owner/admin account
+        owner,
+        /// Global configuration account that stores protocol-wide settings
+        global_config,
+        /// The mint address of the quote token (token used for buying)
+        quote_token_mint,
+        /// Account that will receive protocol fees
+        protocol_fee_owner,
+        /// Account that will receive migrate fees
+        migrate_fee_owner,
+        /// The control wallet address for migrating to amm
+        migrate_to_amm_wallet,
+        /// The control wallet address for migrating to cpswap
+        migrate_to_cpswap_wallet,
+        /// Required for account creation
+        system_program
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Create platform config accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    CreatePlatformConfigAccounts,
+    get_create_platform_config_accounts,
+    {
+        /// The account paying for the initialization costs
+        platform_admin,
+        /// Wallet that receives platform fees
+        platform_fee_wallet,
+        /// Wallet that receives the platform share of the migration liquidity NFT
+        platform_nft_wallet,
+        /// The platform config account
+        platform_config,
+        /// CPMM config account
+        cpswap_config,
+        /// Required for account creation
+        system_program,
+        /// Authority of the transfer fee extension on Token-2022 base mints
+        transfer_fee_extension_authority
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Create vesting account accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    CreateVestingAccountAccounts,
+    get_create_vesting_account_accounts,
+    {
+        /// The account paying for the initialization costs
+        creator,
+        /// The beneficiary is used to receive the allocated linear release of tokens
+        beneficiary,
+        /// The pool state account
+        pool_state,
+        /// The vesting record account
+        vesting_record,
+        /// Required for account creation
+        system_program
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Initialize accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    InitializeAccounts,
+    get_initialize_accounts,
+    {
+        /// The account paying for the initialization costs
+        payer,
+        /// The token creator
+        creator,
+        /// Global configuration account containing protocol-wide settings
+        global_config,
+        /// Platform configuration account containing platform info
+        platform_config,
+        /// PDA that acts as the authority for pool vault and mint operations
+        authority,
+        /// Account that stores the pool's state and parameters
+        pool_state,
+        /// The mint for the base token (token being sold)
+        base_mint,
+        /// The mint for the quote token (token used to buy)
+        quote_mint,
+        /// Token account that holds the pool's base tokens
+        base_vault,
+        /// Token account that holds the pool's quote tokens
+        quote_vault,
+        /// Account to store the base token's metadata
+        metadata_account,
+        /// SPL Token program for the base token
+        base_token_program,
+        /// SPL Token program for the quote token
+        quote_token_program,
+        /// Metaplex Token Metadata program
+        metadata_program,
+        /// Required for account creation
+        system_program,
+        /// Required for rent exempt calculations
+        rent_program,
+        /// Program-derived address authorising event emissions
+        event_authority,
+        /// The raydium launchpad program id
+        program
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Initialize v2 accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    InitializeV2Accounts,
+    get_initialize_v2_accounts,
+    {
+        /// The account paying for the initialization costs
+        payer,
+        /// The token creator
+        creator,
+        /// Global configuration account containing protocol-wide settings
+        global_config,
+        /// Platform configuration account containing platform info
+        platform_config,
+        /// PDA that acts as the authority for pool vault and mint operations
+        authority,
+        /// Account that stores the pool's state and parameters
+        pool_state,
+        /// The mint for the base token (token being sold)
+        base_mint,
+        /// The mint for the quote token (token used to buy)
+        quote_mint,
+        /// Token account that holds the pool's base tokens
+        base_vault,
+        /// Token account that holds the pool's quote tokens
+        quote_vault,
+        /// Account to store the base token's metadata
+        metadata_account,
+        /// SPL Token program for the base token
+        base_token_program,
+        /// SPL Token program for the quote token
+        quote_token_program,
+        /// Metaplex Token Metadata program
+        metadata_program,
+        /// Required for account creation
+        system_program,
+        /// Required for rent exempt calculations
+        rent_program,
+        /// Program-derived address authorising event emissions
+        event_authority,
+        /// The raydium launchpad program id
+        program
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Initialize with token 2022 accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    InitializeWithToken2022Accounts,
+    get_initialize_with_token_2022_accounts,
+    {
+        /// The account paying for the initialization costs
+        payer,
+        /// The token creator
+        creator,
+        /// Global configuration account containing protocol-wide settings
+        global_config,
+        /// Platform configuration account containing platform info
+        platform_config,
+        /// PDA that acts as the authority for pool vault and mint operations
+        authority,
+        /// Account that stores the pool's state and parameters
+        pool_state,
+        /// The mint for the base token (token being sold)
+        base_mint,
+        /// The mint for the quote token (token used to buy)
+        quote_mint,
+        /// Token account that holds the pool's base tokens
+        base_vault,
+        /// Token account that holds the pool's quote tokens
+        quote_vault,
+        /// SPL Token program for the base token
+        base_token_program,
+        /// SPL Token program for the quote token
+        quote_token_program,
+        /// Required for account creation
+        system_program,
+        /// Program-derived address authorising event emissions
+        event_authority,
+        /// The raydium launchpad program id
+        program
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Migrate to amm accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    MigrateToAmmAccounts,
+    get_migrate_to_amm_accounts,
+    {
+        /// Only migrate_to_amm_wallet can migrate to an AMM v4 pool
+        payer,
+        /// The mint for the base token (token being sold)
+        base_mint,
+        /// The mint for the quote token (token used to buy)
+        quote_mint,
+        /// OpenBook program
+        openbook_program,
+        /// Account created and assigned to openbook_program but not yet initialized
+        market,
+        /// Account created and assigned to openbook_program but not yet initialized
+        request_queue,
+        /// Account created and assigned to openbook_program but not yet initialized
+        event_queue,
+        /// Account created and assigned to openbook_program but not yet initialized
+        bids,
+        /// Account created and assigned to openbook_program but not yet initialized
+        asks,
+        /// OpenBook market vault signer
+        market_vault_signer,
+        /// Token account that holds the market's base tokens
+        market_base_vault,
+        /// Token account that holds the market's quote tokens
+        market_quote_vault,
+        /// Raydium AMM v4 program
+        amm_program,
+        /// AMM v4 pool created by the migration
+        amm_pool,
+        /// AMM v4 pool authority
+        amm_authority,
+        /// AMM v4 OpenBook open orders account
+        amm_open_orders,
+        /// AMM v4 LP mint
+        amm_lp_mint,
+        /// AMM v4 pool vault for base tokens
+        amm_base_vault,
+        /// AMM v4 pool vault for quote tokens
+        amm_quote_vault,
+        /// AMM v4 target orders account
+        amm_target_orders,
+        /// AMM v4 config account
+        amm_config,
+        /// Receives the AMM v4 pool creation fee
+        amm_create_fee_destination,
+        /// PDA that acts as the authority for pool vault operations
+        authority,
+        /// Account that stores the pool's state and parameters
+        pool_state,
+        /// Global config account stores owner
+        global_config,
+        /// The pool's vault for base tokens
+        base_vault,
+        /// The pool's vault for quote tokens
+        quote_vault,
+        /// The pool's LP token account
+        pool_lp_token,
+        /// SPL Token program for the base token
+        spl_token_program,
+        /// Program to create an ATA for receiving fee NFT
+        associated_token_program,
+        /// Required for account creation
+        system_program,
+        /// Required for rent exempt calculations
+        rent_program
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Migrate to cpswap accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    MigrateToCpswapAccounts,
+    get_migrate_to_cpswap_accounts,
+    {
+        /// Only migrate_to_cpswap_wallet can migrate to a CPMM pool
+        payer,
+        /// The mint for the base token (token being sold)
+        base_mint,
+        /// The mint for the quote token (token used to buy)
+        quote_mint,
+        /// Platform configuration account containing platform-wide settings
+        platform_config,
+        /// Raydium CPMM program
+        cpswap_program,
+        /// CPMM pool created by the migration
+        cpswap_pool,
+        /// CPMM pool authority
+        cpswap_authority,
+        /// CPMM LP mint
+        cpswap_lp_mint,
+        /// CPMM pool vault for base tokens
+        cpswap_base_vault,
+        /// CPMM pool vault for quote tokens
+        cpswap_quote_vault,
+        /// CPMM config account
+        cpswap_config,
+        /// Receives the CPMM pool creation fee
+        cpswap_create_pool_fee,
+        /// CPMM observation account
+        cpswap_observation,
+        /// Raydium liquidity lock program
+        lock_program,
+        /// Liquidity lock program authority
+        lock_authority,
+        /// Vault holding the locked LP tokens
+        lock_lp_vault,
+        /// PDA that acts as the authority for pool vault operations
+        authority,
+        /// Account that stores the pool's state and parameters
+        pool_state,
+        /// Global config account stores owner
+        global_config,
+        /// The pool's vault for base tokens
+        base_vault,
+        /// The pool's vault for quote tokens
+        quote_vault,
+        /// The pool's LP token account
+        pool_lp_token,
+        /// SPL Token program for the base token
+        base_token_program,
+        /// SPL Token program for the quote token
+        quote_token_program,
+        /// Program to create an ATA for receiving fee NFT
+        associated_token_program,
+        /// Required for account creation
+        system_program,
+        /// Required for rent exempt calculations
+        rent_program,
+        /// Program to create NFT metadata account
+        metadata_program
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Remove platform curve param accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    RemovePlatformCurveParamAccounts,
+    get_remove_platform_curve_param_accounts,
+    {
+        /// The account paying for the initialization costs
+        platform_admin,
+        /// Platform config account to be changed
+        platform_config
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Update config accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    UpdateConfigAccounts,
+    get_update_config_accounts,
+    {
+        /// The global config owner or admin
+        owner,
+        /// Global config account to be changed
+        global_config
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Update platform config accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    UpdatePlatformConfigAccounts,
+    get_update_platform_config_accounts,
+    {
+        /// The account paying for the initialization costs
+        platform_admin,
+        /// Platform config account to be changed
+        platform_config
+    }
+);
+
+// -----------------------------------------------------------------------------
+// Update platform curve param accounts
+// -----------------------------------------------------------------------------
+accounts!(
+    UpdatePlatformCurveParamAccounts,
+    get_update_platform_curve_param_accounts,
+    {
+        /// The account paying for the initialization costs
+        platform_admin,
+        /// Platform config account to be changed
+        platform_config,
+        /// Global configuration account containing protocol-wide settings
+        global_config,
+        /// System program for lamport transfers
+        system_program
+    }
+);
@@ -1,5 +1,6 @@
 //! Raydium Launchpad Anchor CPI events.
 
+use crate::common::anchor::split_event;
 use crate::common::ParseError;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
@@ -13,7 +14,6 @@ pub const POOL_CREATE_EVENT: [u8; 8] = [35, 19, 27, 213, 21, 36, 194, 123];
 pub const TRADE_EVENT: [u8; 8] = [189, 219, 127, 211, 78, 230, 97, 238];
 pub const TRADE_EVENT_LEN_V1: usize = 139;
 pub const TRADE_EVENT_LEN_V2: usize = 130;
-const ANCHOR_DISC: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
 
 // -----------------------------------------------------------------------------
 // Event enumeration
@@ -175,16 +175,7 @@ impl<'a> TryFrom<&'a [u8]> for RaydiumLaunchpadAnchorCpiEvent {
     type Error = ParseError;
 
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-        let (disc, payload) = if data.len() >= 16 && data[0..8] == ANCHOR_DISC {
-            let disc: [u8; 8] = data[8..16].try_into().expect("slice len 8");
-            (disc, &data[16..])
-        } else {
-            let disc: [u8; 8] = data[0..8].try_into().expect("slice len 8");
-            (disc, &data[8..])
-        };
+        let (disc, payload) = split_event(data)?;
         Ok(match disc {
             CLAIM_VESTED_EVENT => Self::ClaimVestedEvent(ClaimVestedEvent::try_from_slice(payload)?),
             CREATE_VESTING_EVENT => Self::CreateVestingEvent(CreateVestingEvent::try_from_slice(payload)?),
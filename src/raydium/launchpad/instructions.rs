@@ -1,15 +1,36 @@
-//! Raydium Launchpad trading instructions.
+//! Raydium Launchpad instructions.
 
 use crate::common::ParseError;
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+pub use super::anchor_cpi_event::{AmmCreatorFeeOn, ConstantCurve, CurveParams, FixedCurve, LinearCurve, MintParams, VestingParams};
 
 // -----------------------------------------------------------------------------
 // Discriminators
 // -----------------------------------------------------------------------------
 pub const BUY_EXACT_IN: [u8; 8] = [250, 234, 13, 123, 213, 156, 19, 236];
 pub const BUY_EXACT_OUT: [u8; 8] = [24, 211, 116, 40, 105, 3, 153, 56];
+pub const CLAIM_CREATOR_FEE: [u8; 8] = [26, 97, 138, 203, 132, 171, 141, 252];
+pub const CLAIM_PLATFORM_FEE: [u8; 8] = [156, 39, 208, 135, 76, 237, 61, 72];
+pub const CLAIM_PLATFORM_FEE_FROM_VAULT: [u8; 8] = [117, 241, 198, 168, 248, 218, 80, 29];
+pub const CLAIM_VESTED_TOKEN: [u8; 8] = [49, 33, 104, 30, 189, 157, 79, 35];
+pub const COLLECT_FEE: [u8; 8] = [60, 173, 247, 103, 4, 93, 130, 48];
+pub const COLLECT_MIGRATE_FEE: [u8; 8] = [255, 186, 150, 223, 235, 118, 201, 186];
+pub const CREATE_CONFIG: [u8; 8] = [201, 207, 243, 114, 75, 111, 47, 189];
+pub const CREATE_PLATFORM_CONFIG: [u8; 8] = [176, 90, 196, 175, 253, 113, 220, 20];
+pub const CREATE_VESTING_ACCOUNT: [u8; 8] = [129, 178, 2, 13, 217, 172, 230, 218];
+pub const INITIALIZE: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+pub const INITIALIZE_V2: [u8; 8] = [67, 153, 175, 39, 218, 16, 38, 32];
+pub const INITIALIZE_WITH_TOKEN_2022: [u8; 8] = [37, 190, 126, 222, 44, 154, 171, 17];
+pub const MIGRATE_TO_AMM: [u8; 8] = [207, 82, 192, 145, 254, 207, 145, 223];
+pub const MIGRATE_TO_CPSWAP: [u8; 8] = [136, 92, 200, 103, 28, 218, 144, 140];
+pub const REMOVE_PLATFORM_CURVE_PARAM: [u8; 8] = [27, 30, 62, 169, 93, 224, 24, 145];
 pub const SELL_EXACT_IN: [u8; 8] = [149, 39, 222, 155, 211, 124, 152, 26];
 pub const SELL_EXACT_OUT: [u8; 8] = [95, 200, 71, 34, 8, 9, 11, 166];
+pub const UPDATE_CONFIG: [u8; 8] = [29, 158, 252, 191, 10, 83, 219, 99];
+pub const UPDATE_PLATFORM_CONFIG: [u8; 8] = [195, 60, 76, 129, 146, 45, 67, 143];
+pub const UPDATE_PLATFORM_CURVE_PARAM: [u8; 8] = [138, 144, 138, 250, 220, 128, 4, 57];
 
 // -----------------------------------------------------------------------------
 // Instruction enumeration
@@ -18,8 +39,26 @@ pub const SELL_EXACT_OUT: [u8; 8] = [95, 200, 71, 34, 8, 9, 11, 166];
 pub enum RaydiumLaunchpadInstruction {
     BuyExactIn(BuyExactInInstruction),
     BuyExactOut(BuyExactOutInstruction),
+    ClaimCreatorFee,
+    ClaimPlatformFee,
+    ClaimPlatformFeeFromVault,
+    ClaimVestedToken,
+    CollectFee,
+    CollectMigrateFee,
+    CreateConfig(CreateConfigInstruction),
+    CreatePlatformConfig(CreatePlatformConfigInstruction),
+    CreateVestingAccount(CreateVestingAccountInstruction),
+    Initialize(InitializeInstruction),
+    InitializeV2(InitializeV2Instruction),
+    InitializeWithToken2022(InitializeWithToken2022Instruction),
+    MigrateToAmm(MigrateToAmmInstruction),
+    MigrateToCpswap,
+    RemovePlatformCurveParam(RemovePlatformCurveParamInstruction),
     SellExactIn(SellExactInInstruction),
     SellExactOut(SellExactOutInstruction),
+    UpdateConfig(UpdateConfigInstruction),
+    UpdatePlatformConfig(UpdatePlatformConfigInstruction),
+    UpdatePlatformCurveParam(UpdatePlatformCurveParamInstruction),
     Unknown,
 }
 
@@ -70,6 +109,168 @@ pub struct SellExactOutInstruction {
     pub share_fee_rate: u64,
 }
 
+/// Creates a new configuration.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CreateConfigInstruction {
+    /// The type of bonding curve (0: ConstantProduct)
+    pub curve_type: u8,
+    /// The index of config, there may be multiple config with the same curve type
+    pub index: u16,
+    pub migrate_fee: u64,
+    /// Trade fee rate, must be less than RATE_DENOMINATOR_VALUE
+    pub trade_fee_rate: u64,
+}
+
+/// Create platform config account.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CreatePlatformConfigInstruction {
+    pub platform_params: PlatformParams,
+}
+
+/// Create vesting account.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CreateVestingAccountInstruction {
+    /// The share amount of base token to be vested
+    pub share_amount: u64,
+}
+
+/// Initializes a new trading pool.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct InitializeInstruction {
+    pub base_mint_param: MintParams,
+    pub curve_param: CurveParams,
+    pub vesting_param: VestingParams,
+}
+
+/// Initializes a new trading pool, choosing which tokens the creator fee is taken in after migration.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct InitializeV2Instruction {
+    pub base_mint_param: MintParams,
+    pub curve_param: CurveParams,
+    pub vesting_param: VestingParams,
+    pub amm_fee_on: AmmCreatorFeeOn,
+}
+
+/// Initializes a new trading pool whose base token is a Token-2022 mint.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct InitializeWithToken2022Instruction {
+    pub base_mint_param: MintParams,
+    pub curve_param: CurveParams,
+    pub vesting_param: VestingParams,
+    pub amm_fee_on: AmmCreatorFeeOn,
+    pub transfer_fee_extension_param: Option<TransferFeeExtensionParams>,
+}
+
+/// Migrates a pool whose fundraising has ended to a Raydium AMM v4 pool.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct MigrateToAmmInstruction {
+    pub base_lot_size: u64,
+    pub quote_lot_size: u64,
+    pub market_vault_signer_nonce: u8,
+}
+
+/// Remove platform launch param.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct RemovePlatformCurveParamInstruction {
+    /// The index of the curve param to remove
+    pub index: u8,
+}
+
+/// Updates configuration parameters.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct UpdateConfigInstruction {
+    /// Parameter to update: 0 for `trade_fee_rate`, 1 for the fee owner
+    pub param: u8,
+    pub value: u64,
+}
+
+/// Update platform config.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct UpdatePlatformConfigInstruction {
+    pub param: PlatformConfigParam,
+}
+
+/// Update platform launch param.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct UpdatePlatformCurveParamInstruction {
+    pub index: u8,
+    pub bonding_curve_param: BondingCurveParam,
+}
+
+// -----------------------------------------------------------------------------
+// Additional types
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct TransferFeeExtensionParams {
+    /// Denominator is 10000, currently this value cannot exceed 5%, which is 500
+    pub transfer_fee_basis_points: u16,
+    /// Maximum fee on each transfer, must exceed `supply * transfer_fee_basis_points / 10000`
+    pub maximum_fee: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PlatformParams {
+    pub migrate_nft_info: MigrateNftInfo,
+    pub fee_rate: u64,
+    pub name: String,
+    pub web: String,
+    pub img: String,
+    pub creator_fee_rate: u64,
+}
+
+/// Split of the migrated CPMM liquidity; the three scales sum to `RATE_DENOMINATOR_VALUE`.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct MigrateNftInfo {
+    /// Share converted into an NFT for the platform
+    pub platform_scale: u64,
+    /// Share converted into an NFT for the token creator
+    pub creator_scale: u64,
+    /// Share burned outright
+    pub burn_scale: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum PlatformConfigParam {
+    FeeWallet(Pubkey),
+    NftWallet(Pubkey),
+    MigrateNftInfo(MigrateNftInfo),
+    FeeRate(u64),
+    Name(String),
+    Web(String),
+    Img(String),
+    CpSwapConfig,
+    AllInfo(PlatformConfigInfo),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PlatformConfigInfo {
+    pub fee_wallet: Pubkey,
+    pub nft_wallet: Pubkey,
+    pub migrate_nft_info: MigrateNftInfo,
+    pub fee_rate: u64,
+    pub name: String,
+    pub web: String,
+    pub img: String,
+    pub transfer_fee_extension_auth: Pubkey,
+    pub creator_fee_rate: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct BondingCurveParam {
+    /// 0: AMM v4, 1: CPMM
+    pub migrate_type: u8,
+    /// 0: fee on the quote token, 1: fee on both tokens
+    pub migrate_cpmm_fee_on: u8,
+    pub supply: u64,
+    pub total_base_sell: u64,
+    pub total_quote_fund_raising: u64,
+    pub total_locked_amount: u64,
+    /// Seconds to wait after fundraising ends before unlocking starts
+    pub cliff_period: u64,
+    /// Unlocking period in seconds
+    pub unlock_period: u64,
+}
+
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
@@ -87,8 +288,26 @@ impl<'a> TryFrom<&'a [u8]> for RaydiumLaunchpadInstruction {
         Ok(match discriminator {
             BUY_EXACT_IN => Self::BuyExactIn(BuyExactInInstruction::try_from_slice(payload)?),
             BUY_EXACT_OUT => Self::BuyExactOut(BuyExactOutInstruction::try_from_slice(payload)?),
+            CLAIM_CREATOR_FEE => Self::ClaimCreatorFee,
+            CLAIM_PLATFORM_FEE => Self::ClaimPlatformFee,
+            CLAIM_PLATFORM_FEE_FROM_VAULT => Self::ClaimPlatformFeeFromVault,
+            CLAIM_VESTED_TOKEN => Self::ClaimVestedToken,
+            COLLECT_FEE => Self::CollectFee,
+            COLLECT_MIGRATE_FEE => Self::CollectMigrateFee,
+            CREATE_CONFIG => Self::CreateConfig(CreateConfigInstruction::try_from_slice(payload)?),
+            CREATE_PLATFORM_CONFIG => Self::CreatePlatformConfig(CreatePlatformConfigInstruction::try_from_slice(payload)?),
+            CREATE_VESTING_ACCOUNT => Self::CreateVestingAccount(CreateVestingAccountInstruction::try_from_slice(payload)?),
+            INITIALIZE => Self::Initialize(InitializeInstruction::try_from_slice(payload)?),
+            INITIALIZE_V2 => Self::InitializeV2(InitializeV2Instruction::try_from_slice(payload)?),
+            INITIALIZE_WITH_TOKEN_2022 => Self::InitializeWithToken2022(InitializeWithToken2022Instruction::try_from_slice(payload)?),
+            MIGRATE_TO_AMM => Self::MigrateToAmm(MigrateToAmmInstruction::try_from_slice(payload)?),
+            MIGRATE_TO_CPSWAP => Self::MigrateToCpswap,
+            REMOVE_PLATFORM_CURVE_PARAM => Self::RemovePlatformCurveParam(RemovePlatformCurveParamInstruction::try_from_slice(payload)?),
             SELL_EXACT_IN => Self::SellExactIn(SellExactInInstruction::try_from_slice(payload)?),
             SELL_EXACT_OUT => Self::SellExactOut(SellExactOutInstruction::try_from_slice(payload)?),
+            UPDATE_CONFIG => Self::UpdateConfig(UpdateConfigInstruction::try_from_slice(payload)?),
+            UPDATE_PLATFORM_CONFIG => Self::UpdatePlatformConfig(UpdatePlatformConfigInstruction::try_from_slice(payload)?),
+            UPDATE_PLATFORM_CURVE_PARAM => Self::UpdatePlatformCurveParam(UpdatePlatformCurveParamInstruction::try_from_slice(payload)?),
             other => return Err(ParseError::Unknown(other)),
         })
     }
@@ -0,0 +1,45 @@
+//! Program-ID keyed event decoding across every protocol that emits events.
+//!
+//! `data` is forwarded untouched to the protocol's own event/log decoder, so
+//! it must be in the form that decoder expects (self-CPI instruction data,
+//! `Program data:` log bytes or `ray_log` payload, depending on the program).
+
+use crate::common::ParseError;
+use crate::*;
+
+registry!(DecodedEvent, decode_event, {
+    Boop(boop::events::BoopEvent) = boop::PROGRAM_ID => boop::events::unpack_event,
+    ByrealClmm(byreal::clmm::events::ByrealClmmEvent) = byreal::clmm::PROGRAM_ID => byreal::clmm::events::unpack,
+    Darklake(darklake::events::DarklakeEvent) = darklake::PROGRAM_ID => darklake::events::unpack_event,
+    DflowV4(dflow::v4::events::DflowV4Event) = dflow::v4::PROGRAM_ID => dflow::v4::events::unpack,
+    DriftV2(drift::v2::events::DriftEvent) = drift::v2::PROGRAM_ID => drift::v2::events::unpack,
+    Dumpfun(dumpfun::events::DumpfunEvent) = dumpfun::PROGRAM_ID => dumpfun::events::unpack_event,
+    Heaven(heaven::logs::HeavenLog) = heaven::PROGRAM_ID => heaven::logs::unpack,
+    JupiterDca(jupiter::dca::events::JupiterDcaEvent) = jupiter::dca::PROGRAM_ID => jupiter::dca::events::unpack,
+    JupiterLimitOrder(jupiter::limit_order::events::JupiterLimitOrderEvent) = jupiter::limit_order::PROGRAM_ID => jupiter::limit_order::events::unpack,
+    JupiterV4(jupiter::v4::events::JupiterV4Event) = jupiter::v4::PROGRAM_ID => jupiter::v4::events::unpack,
+    JupiterV6(jupiter::v6::events::JupiterV6Event) = jupiter::v6::PROGRAM_ID => jupiter::v6::events::unpack,
+    Marinade(marinade::events::MarinadeEvent) = marinade::PROGRAM_ID => marinade::events::unpack,
+    MeteoraAmm(meteora::amm::events::AmmEvent) = meteora::amm::PROGRAM_ID => meteora::amm::events::parse_event,
+    MeteoraDamm(meteora::daam::anchor_cpi_event::MeteoraDammAnchorCpiEvent) = meteora::daam::PROGRAM_ID => meteora::daam::anchor_cpi_event::unpack,
+    MeteoraDlmm(meteora::dlmm::anchor_cpi_event::MeteoraDlmmAnchorCpiEvent) = meteora::dlmm::PROGRAM_ID => meteora::dlmm::anchor_cpi_event::unpack,
+    Moonshot(moonshot::events::MoonshotEvent) = moonshot::PROGRAM_ID => moonshot::events::unpack_event,
+    Openbook(openbook::events::OpenbookEvent) = openbook::PROGRAM_ID => openbook::events::unpack,
+    OrcaWhirlpool(orca::whirlpool::events::WhirlpoolEvent) = orca::whirlpool::PROGRAM_ID => orca::whirlpool::events::parse_event,
+    PancakeSwap(pancakeswap::events::PancakeSwapEvent) = pancakeswap::PROGRAM_ID => pancakeswap::events::unpack,
+    /// Data of the `Log` self-CPI.
+    Phoenix(phoenix::events::PhoenixLog) = phoenix::PROGRAM_ID => phoenix::events::unpack_log,
+    PumpFunBondingCurve(pumpfun::bonding_curve::events::PumpFunEvent) = pumpfun::bonding_curve::PROGRAM_ID => pumpfun::bonding_curve::events::unpack,
+    /// Also covers `pumpfun::amm`, which targets the same program.
+    PumpSwap(pumpswap::events::PumpSwapEvent) = pumpswap::PROGRAM_ID => pumpswap::events::unpack_event,
+    /// `ray_log` payload (base64-decoded).
+    RaydiumAmmV4(raydium::amm::v4::logs::RaydiumV4Log) = raydium::amm::v4::PROGRAM_ID => raydium::amm::v4::logs::unpack,
+    RaydiumClmmV3(raydium::clmm::v3::events::RaydiumClmmEvent) = raydium::clmm::v3::PROGRAM_ID => raydium::clmm::v3::events::unpack,
+    RaydiumCpmm(raydium::cpmm::events::RaydiumCpmmEvent) = raydium::cpmm::PROGRAM_ID => raydium::cpmm::events::unpack,
+    RaydiumLaunchpad(raydium::launchpad::anchor_cpi_event::RaydiumLaunchpadAnchorCpiEvent) = raydium::launchpad::PROGRAM_ID => raydium::launchpad::anchor_cpi_event::unpack,
+    RaydiumStable(raydium::stable::events::RaydiumStableEvent) = raydium::stable::PROGRAM_ID => raydium::stable::events::unpack,
+    SolfiV1(solfi::v1::events::SolfiEvent) = solfi::v1::PROGRAM_ID => solfi::v1::events::unpack,
+    SolfiV2(solfi::v2::events::SolfiEvent) = solfi::v2::PROGRAM_ID => solfi::v2::events::unpack,
+    Stabble(stabble::events::StabbleEvent) = stabble::PROGRAM_ID => stabble::events::unpack,
+    Tensor(tensor::events::TensorEvent) = tensor::PROGRAM_ID => tensor::events::unpack,
+});
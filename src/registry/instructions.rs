@@ -0,0 +1,65 @@
+//! Program-ID keyed instruction decoding across every supported protocol.
+
+use crate::common::ParseError;
+use crate::*;
+
+registry!(DecodedInstruction, decode_instruction, {
+    Aldrin(aldrin::instructions::AldrinInstruction) = aldrin::PROGRAM_ID => aldrin::instructions::unpack,
+    BonkSwap(bonkswap::instructions::BonkSwapInstruction) = bonkswap::PROGRAM_ID => bonkswap::instructions::unpack,
+    Boop(boop::instructions::BoopInstruction) = boop::PROGRAM_ID => boop::instructions::unpack,
+    ByrealClmm(byreal::clmm::instructions::ByrealClmmInstruction) = byreal::clmm::PROGRAM_ID => byreal::clmm::instructions::unpack,
+    Darklake(darklake::instructions::DarklakeInstruction) = darklake::PROGRAM_ID => darklake::instructions::unpack,
+    DflowV4(dflow::v4::instructions::DflowV4Instruction) = dflow::v4::PROGRAM_ID => dflow::v4::instructions::unpack,
+    DriftV2(drift::v2::instructions::DriftInstruction) = drift::v2::PROGRAM_ID => drift::v2::instructions::unpack,
+    Dumpfun(dumpfun::instructions::DumpfunInstruction) = dumpfun::PROGRAM_ID => dumpfun::instructions::unpack,
+    GoonFi(goonfi::instructions::GoonFiInstruction) = goonfi::PROGRAM_ID => goonfi::instructions::unpack,
+    Heaven(heaven::instructions::HeavenInstruction) = heaven::PROGRAM_ID => heaven::instructions::unpack,
+    HumidiFi(humidifi::instructions::HumidiFiInstruction) = humidifi::PROGRAM_ID => humidifi::instructions::unpack,
+    JupiterDca(jupiter::dca::instructions::JupiterDcaInstruction) = jupiter::dca::PROGRAM_ID => jupiter::dca::instructions::unpack,
+    JupiterLimitOrder(jupiter::limit_order::instructions::JupiterLimitOrderInstruction) = jupiter::limit_order::PROGRAM_ID => jupiter::limit_order::instructions::unpack,
+    JupiterV4(jupiter::v4::instructions::JupiterV4Instruction) = jupiter::v4::PROGRAM_ID => jupiter::v4::instructions::unpack,
+    JupiterV6(jupiter::v6::instructions::JupiterV6Instruction) = jupiter::v6::PROGRAM_ID => jupiter::v6::instructions::unpack,
+    Lifinity(lifinity::instructions::LifinityInstruction) = lifinity::PROGRAM_ID => lifinity::instructions::unpack,
+    MagicEdenM2(magiceden::m2::instructions::MagicEdenInstruction) = magiceden::m2::PROGRAM_ID => magiceden::m2::instructions::unpack,
+    MagicEdenM3(magiceden::m3::instructions::MagicEdenInstruction) = magiceden::m3::PROGRAM_ID => magiceden::m3::instructions::unpack,
+    Marinade(marinade::instructions::MarinadeInstruction) = marinade::PROGRAM_ID => marinade::instructions::unpack,
+    MetaplexBubblegum(metaplex::bubblegum::instructions::BubblegumInstruction) = metaplex::bubblegum::PROGRAM_ID => metaplex::bubblegum::instructions::unpack,
+    MetaplexTokenMetadata(metaplex::token_metadata::instructions::TokenMetadataInstruction) = metaplex::token_metadata::PROGRAM_ID => metaplex::token_metadata::instructions::unpack,
+    MeteoraAmm(meteora::amm::instructions::AmmInstruction) = meteora::amm::PROGRAM_ID => meteora::amm::instructions::unpack,
+    MeteoraDamm(meteora::daam::instructions::MeteoraDammInstruction) = meteora::daam::PROGRAM_ID => meteora::daam::instructions::unpack,
+    MeteoraDlmm(meteora::dlmm::instructions::MeteoraDlmmInstruction) = meteora::dlmm::PROGRAM_ID => meteora::dlmm::instructions::unpack,
+    Moonshot(moonshot::instructions::MoonshotInstruction) = moonshot::PROGRAM_ID => moonshot::instructions::unpack,
+    NativeStake(native::stake::instructions::StakeInstruction) = native::stake::PROGRAM_ID => native::stake::instructions::unpack,
+    NativeSystem(native::system::instructions::SystemInstruction) = native::system::PROGRAM_ID => native::system::instructions::unpack,
+    NativeVote(native::vote::instructions::VoteInstruction) = native::vote::PROGRAM_ID => native::vote::instructions::unpack,
+    ObricV2(obric::v2::instructions::ObricV2Instruction) = obric::v2::PROGRAM_ID => obric::v2::instructions::unpack,
+    ObricV3(obric::v3::instructions::ObricV3Instruction) = obric::v3::PROGRAM_ID => obric::v3::instructions::unpack,
+    OkxV2(okx::v2::instructions::OkxV2Instruction) = okx::v2::PROGRAM_ID => okx::v2::instructions::unpack,
+    Openbook(openbook::instructions::OpenbookInstruction) = openbook::PROGRAM_ID => openbook::instructions::unpack,
+    OrcaWhirlpool(orca::whirlpool::instructions::WhirlpoolInstruction) = orca::whirlpool::PROGRAM_ID => orca::whirlpool::instructions::unpack,
+    PancakeSwap(pancakeswap::instructions::PancakeSwapInstruction) = pancakeswap::PROGRAM_ID => pancakeswap::instructions::unpack,
+    /// Penguin is an SPL Token Swap fork and shares its instruction set.
+    Penguin(spl::token_swap::instructions::TokenSwapInstruction) = penguin::PROGRAM_ID => penguin::instructions::unpack,
+    Phoenix(phoenix::instructions::PhonenixInstruction) = phoenix::PROGRAM_ID => phoenix::instructions::unpack,
+    Plasma(plasma::instructions::PlasmaInstruction) = plasma::PROGRAM_ID => plasma::instructions::unpack,
+    PumpFunBondingCurve(pumpfun::bonding_curve::instructions::PumpFunInstruction) = pumpfun::bonding_curve::PROGRAM_ID => pumpfun::bonding_curve::instructions::unpack,
+    /// Also covers `pumpfun::amm`, which targets the same program.
+    PumpSwap(pumpswap::instructions::PumpSwapInstruction) = pumpswap::PROGRAM_ID => pumpswap::instructions::unpack,
+    RaydiumAmmV4(raydium::amm::v4::instructions::RaydiumV4Instruction) = raydium::amm::v4::PROGRAM_ID => raydium::amm::v4::instructions::unpack,
+    RaydiumClmmV3(raydium::clmm::v3::instructions::RaydiumClmmInstruction) = raydium::clmm::v3::PROGRAM_ID => raydium::clmm::v3::instructions::unpack,
+    RaydiumCpmm(raydium::cpmm::instructions::RaydiumCpmmInstruction) = raydium::cpmm::PROGRAM_ID => raydium::cpmm::instructions::unpack,
+    RaydiumLaunchpad(raydium::launchpad::instructions::RaydiumLaunchpadInstruction) = raydium::launchpad::PROGRAM_ID => raydium::launchpad::instructions::unpack,
+    RaydiumStable(raydium::stable::instructions::RaydiumStableInstruction) = raydium::stable::PROGRAM_ID => raydium::stable::instructions::unpack,
+    Sanctum(sanctum::instructions::SanctumInstruction) = sanctum::PROGRAM_ID => sanctum::instructions::unpack,
+    Saros(saros::instructions::SarosInstruction) = saros::PROGRAM_ID => saros::instructions::unpack,
+    Serum(serum::instructions::SerumInstruction) = serum::PROGRAM_ID => serum::instructions::unpack,
+    SolfiV1(solfi::v1::instructions::SolfiInstruction) = solfi::v1::PROGRAM_ID => solfi::v1::instructions::unpack,
+    SolfiV2(solfi::v2::instructions::SolfiInstruction) = solfi::v2::PROGRAM_ID => solfi::v2::instructions::unpack,
+    SplToken(spl::token::instructions::TokenInstruction) = spl::token::PROGRAM_ID => spl::token::instructions::unpack,
+    SplToken2022(spl::token_2022::instructions::Token2022Instruction) = spl::token_2022::PROGRAM_ID => spl::token_2022::instructions::unpack,
+    SplTokenLending(spl::token_lending::instructions::TokenLendingInstruction) = spl::token_lending::PROGRAM_ID => spl::token_lending::instructions::unpack,
+    SplTokenSwap(spl::token_swap::instructions::TokenSwapInstruction) = spl::token_swap::PROGRAM_ID => spl::token_swap::instructions::unpack,
+    Stabble(stabble::instructions::StabbleInstruction) = stabble::PROGRAM_ID => stabble::instructions::unpack,
+    Tensor(tensor::instructions::TensorInstruction) = tensor::PROGRAM_ID => tensor::instructions::unpack,
+    TesseraV(tesserav::instructions::TesseraVInstruction) = tesserav::PROGRAM_ID => tesserav::instructions::unpack,
+});
@@ -0,0 +1,59 @@
+//! Cross-protocol decoders keyed by program ID.
+//!
+//! Every protocol module exposes its own `PROGRAM_ID` and `unpack`; this
+//! module wraps them behind a single entry point so callers don't have to
+//! maintain their own `match program_id { .. }` over every supported program.
+//!
+//! ```ignore
+//! use substreams_solana_idls::{decode_instruction, DecodedInstruction};
+//!
+//! match decode_instruction(ix.program_id().0, ix.data()) {
+//!     Ok(DecodedInstruction::JupiterV6(ix)) => { /* … */ }
+//!     Ok(_) => {}
+//!     Err(_) => {}
+//! }
+//! ```
+
+use crate::common::ParseError;
+
+/// Converts a raw program ID slice into the fixed-size form used by every
+/// `PROGRAM_ID` constant.
+#[inline]
+fn to_program_id(program_id: &[u8]) -> Result<[u8; 32], ParseError> {
+    program_id.try_into().map_err(|_| ParseError::InvalidLength {
+        expected: 32,
+        got: program_id.len(),
+    })
+}
+
+// -----------------------------------------------------------------------------
+// Helper macro generating a tagged enum + program-ID dispatcher
+// -----------------------------------------------------------------------------
+macro_rules! registry {
+    ($name:ident, $decode:ident, { $( $(#[$doc:meta])* $variant:ident($ty:ty) = $program:path => $unpack:path ),+ $(,)? }) => {
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum $name {
+            $( $(#[$doc])* $variant($ty), )+
+        }
+
+        /// Decodes `data` with the decoder registered for `program_id`.
+        ///
+        /// Returns [`ParseError::UnknownProgram`] when no decoder is registered
+        /// for the program.
+        pub fn $decode(program_id: &[u8], data: &[u8]) -> Result<$name, ParseError> {
+            let program_id = super::to_program_id(program_id)?;
+            $(
+                if program_id == $program {
+                    return $unpack(data).map($name::$variant);
+                }
+            )+
+            Err(ParseError::UnknownProgram(program_id))
+        }
+    };
+}
+
+pub mod events;
+pub mod instructions;
+
+pub use events::{decode_event, DecodedEvent};
+pub use instructions::{decode_instruction, DecodedInstruction};
@@ -1,12 +1,20 @@
 //! Serum DEX V3 instructions.
 //!
-//! Serum uses a sequential little-endian u32 discriminator (first 4 bytes of
-//! instruction data) rather than Anchor's 8-byte sha256 discriminators.
+//! Serum does not use Anchor: instruction data is a `u8` layout version
+//! (always `0`), a sequential little-endian `u32` tag, then a packed
+//! little-endian payload. Enum fields (`Side`, `OrderType`,
+//! `SelfTradeBehavior`) are `u32` as well.
 
+use crate::common::bincode::{invalid, BincodeReader};
 use crate::common::ParseError;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Only layout version the program accepts.
+pub const VERSION: u8 = 0;
 
 // -----------------------------------------------------------------------------
-// Discriminators (little-endian u32)
+// Discriminators (little-endian u32, after the version byte)
 // -----------------------------------------------------------------------------
 pub const INITIALIZE_MARKET: [u8; 4] = [0, 0, 0, 0];
 pub const NEW_ORDER: [u8; 4] = [1, 0, 0, 0];
@@ -27,37 +35,192 @@ pub const INIT_OPEN_ORDERS: [u8; 4] = [15, 0, 0, 0];
 pub const PRUNE: [u8; 4] = [16, 0, 0, 0];
 pub const CONSUME_EVENTS_PERMISSIONED: [u8; 4] = [17, 0, 0, 0];
 
+// -----------------------------------------------------------------------------
+// Custom types
+// -----------------------------------------------------------------------------
+// The Borsh derives serve the Anchor-based forks (e.g. Aldrin), which encode
+// these enums with a `u8` tag instead of Serum's `u32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum Side {
+    Bid,
+    Ask,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum OrderType {
+    Limit,
+    ImmediateOrCancel,
+    PostOnly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum SelfTradeBehavior {
+    DecrementTake,
+    CancelProvide,
+    AbortTransaction,
+}
+
+impl TryFrom<u32> for Side {
+    type Error = ParseError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Bid),
+            1 => Ok(Self::Ask),
+            other => Err(invalid(format!("invalid side {other}"))),
+        }
+    }
+}
+
+impl TryFrom<u32> for OrderType {
+    type Error = ParseError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Limit),
+            1 => Ok(Self::ImmediateOrCancel),
+            2 => Ok(Self::PostOnly),
+            other => Err(invalid(format!("invalid order type {other}"))),
+        }
+    }
+}
+
+impl TryFrom<u32> for SelfTradeBehavior {
+    type Error = ParseError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::DecrementTake),
+            1 => Ok(Self::CancelProvide),
+            2 => Ok(Self::AbortTransaction),
+            other => Err(invalid(format!("invalid self-trade behavior {other}"))),
+        }
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Instruction enumeration
 // -----------------------------------------------------------------------------
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SerumInstruction {
-    InitializeMarket(RawPayload),
-    NewOrder(RawPayload),
-    MatchOrders(RawPayload),
-    ConsumeEvents(RawPayload),
-    CancelOrder(RawPayload),
-    SettleFunds(RawPayload),
-    CancelOrderByClientId(RawPayload),
-    DisableMarket(RawPayload),
-    SweepFees(RawPayload),
-    NewOrderV2(RawPayload),
-    NewOrderV3(RawPayload),
-    CancelOrderV2(RawPayload),
-    CancelOrderByClientIdV2(RawPayload),
-    SendTake(RawPayload),
-    CloseOpenOrders(RawPayload),
-    InitOpenOrders(RawPayload),
-    Prune(RawPayload),
-    ConsumeEventsPermissioned(RawPayload),
+    InitializeMarket(InitializeMarketInstruction),
+    NewOrder(NewOrderInstructionV1),
+    MatchOrders(MatchOrdersInstruction),
+    ConsumeEvents(ConsumeEventsInstruction),
+    CancelOrder(CancelOrderInstruction),
+    SettleFunds,
+    CancelOrderByClientId(CancelOrderByClientIdInstruction),
+    DisableMarket,
+    SweepFees,
+    NewOrderV2(NewOrderInstructionV2),
+    NewOrderV3(NewOrderInstructionV3),
+    CancelOrderV2(CancelOrderInstructionV2),
+    CancelOrderByClientIdV2(CancelOrderByClientIdV2Instruction),
+    SendTake(SendTakeInstruction),
+    CloseOpenOrders,
+    InitOpenOrders,
+    Prune(PruneInstruction),
+    ConsumeEventsPermissioned(ConsumeEventsPermissionedInstruction),
 }
 
 // -----------------------------------------------------------------------------
-// Payload struct
+// Payload structs
 // -----------------------------------------------------------------------------
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct RawPayload {
-    pub data: Vec<u8>,
+pub struct InitializeMarketInstruction {
+    pub coin_lot_size: u64,
+    pub pc_lot_size: u64,
+    pub fee_rate_bps: u16,
+    pub vault_signer_nonce: u64,
+    pub pc_dust_threshold: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewOrderInstructionV1 {
+    pub side: Side,
+    pub limit_price: u64,
+    pub max_qty: u64,
+    pub order_type: OrderType,
+    pub client_id: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewOrderInstructionV2 {
+    pub side: Side,
+    pub limit_price: u64,
+    pub max_qty: u64,
+    pub order_type: OrderType,
+    pub client_id: u64,
+    pub self_trade_behavior: SelfTradeBehavior,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewOrderInstructionV3 {
+    pub side: Side,
+    pub limit_price: u64,
+    pub max_coin_qty: u64,
+    pub max_native_pc_qty_including_fees: u64,
+    pub self_trade_behavior: SelfTradeBehavior,
+    pub order_type: OrderType,
+    pub client_order_id: u64,
+    pub limit: u16,
+    /// Expiry timestamp; `i64::MAX` when the payload predates the field.
+    pub max_ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CancelOrderInstruction {
+    pub side: Side,
+    pub order_id: u128,
+    pub owner: Pubkey,
+    pub owner_slot: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CancelOrderInstructionV2 {
+    pub side: Side,
+    pub order_id: u128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CancelOrderByClientIdInstruction {
+    pub client_order_id: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CancelOrderByClientIdV2Instruction {
+    pub client_order_id: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SendTakeInstruction {
+    pub side: Side,
+    pub limit_price: u64,
+    pub max_coin_qty: u64,
+    pub max_native_pc_qty_including_fees: u64,
+    pub min_coin_qty: u64,
+    pub min_native_pc_qty: u64,
+    pub limit: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchOrdersInstruction {
+    pub limit: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsumeEventsInstruction {
+    pub limit: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PruneInstruction {
+    pub limit: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsumeEventsPermissionedInstruction {
+    pub limit: u16,
 }
 
 // -----------------------------------------------------------------------------
@@ -67,31 +230,78 @@ impl<'a> TryFrom<&'a [u8]> for SerumInstruction {
     type Error = ParseError;
 
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 4 {
+        if data.len() < 5 {
             return Err(ParseError::TooShort(data.len()));
         }
-        let (disc, payload) = data.split_at(4);
-        let disc: [u8; 4] = disc.try_into().unwrap();
-        let raw = RawPayload { data: payload.to_vec() };
+        if data[0] != VERSION {
+            return Err(invalid(format!("unsupported serum instruction version {}", data[0])));
+        }
+        let disc: [u8; 4] = data[1..5].try_into().unwrap();
+        let mut r = BincodeReader::new(&data[5..]);
         Ok(match disc {
-            INITIALIZE_MARKET => Self::InitializeMarket(raw),
-            NEW_ORDER => Self::NewOrder(raw),
-            MATCH_ORDERS => Self::MatchOrders(raw),
-            CONSUME_EVENTS => Self::ConsumeEvents(raw),
-            CANCEL_ORDER => Self::CancelOrder(raw),
-            SETTLE_FUNDS => Self::SettleFunds(raw),
-            CANCEL_ORDER_BY_CLIENT_ID => Self::CancelOrderByClientId(raw),
-            DISABLE_MARKET => Self::DisableMarket(raw),
-            SWEEP_FEES => Self::SweepFees(raw),
-            NEW_ORDER_V2 => Self::NewOrderV2(raw),
-            NEW_ORDER_V3 => Self::NewOrderV3(raw),
-            CANCEL_ORDER_V2 => Self::CancelOrderV2(raw),
-            CANCEL_ORDER_BY_CLIENT_ID_V2 => Self::CancelOrderByClientIdV2(raw),
-            SEND_TAKE => Self::SendTake(raw),
-            CLOSE_OPEN_ORDERS => Self::CloseOpenOrders(raw),
-            INIT_OPEN_ORDERS => Self::InitOpenOrders(raw),
-            PRUNE => Self::Prune(raw),
-            CONSUME_EVENTS_PERMISSIONED => Self::ConsumeEventsPermissioned(raw),
+            INITIALIZE_MARKET => Self::InitializeMarket(InitializeMarketInstruction {
+                coin_lot_size: r.u64()?,
+                pc_lot_size: r.u64()?,
+                fee_rate_bps: r.u16()?,
+                vault_signer_nonce: r.u64()?,
+                pc_dust_threshold: r.u64()?,
+            }),
+            NEW_ORDER => Self::NewOrder(NewOrderInstructionV1 {
+                side: Side::try_from(r.u32()?)?,
+                limit_price: r.u64()?,
+                max_qty: r.u64()?,
+                order_type: OrderType::try_from(r.u32()?)?,
+                client_id: r.u64()?,
+            }),
+            MATCH_ORDERS => Self::MatchOrders(MatchOrdersInstruction { limit: r.u16()? }),
+            CONSUME_EVENTS => Self::ConsumeEvents(ConsumeEventsInstruction { limit: r.u16()? }),
+            CANCEL_ORDER => Self::CancelOrder(CancelOrderInstruction {
+                side: Side::try_from(r.u32()?)?,
+                order_id: r.u128()?,
+                owner: r.pubkey()?,
+                owner_slot: r.u8()?,
+            }),
+            SETTLE_FUNDS => Self::SettleFunds,
+            CANCEL_ORDER_BY_CLIENT_ID => Self::CancelOrderByClientId(CancelOrderByClientIdInstruction { client_order_id: r.u64()? }),
+            DISABLE_MARKET => Self::DisableMarket,
+            SWEEP_FEES => Self::SweepFees,
+            NEW_ORDER_V2 => Self::NewOrderV2(NewOrderInstructionV2 {
+                side: Side::try_from(r.u32()?)?,
+                limit_price: r.u64()?,
+                max_qty: r.u64()?,
+                order_type: OrderType::try_from(r.u32()?)?,
+                client_id: r.u64()?,
+                self_trade_behavior: SelfTradeBehavior::try_from(r.u32()?)?,
+            }),
+            NEW_ORDER_V3 => Self::NewOrderV3(NewOrderInstructionV3 {
+                side: Side::try_from(r.u32()?)?,
+                limit_price: r.u64()?,
+                max_coin_qty: r.u64()?,
+                max_native_pc_qty_including_fees: r.u64()?,
+                self_trade_behavior: SelfTradeBehavior::try_from(r.u32()?)?,
+                order_type: OrderType::try_from(r.u32()?)?,
+                client_order_id: r.u64()?,
+                limit: r.u16()?,
+                max_ts: if r.remaining().is_empty() { i64::MAX } else { r.i64()? },
+            }),
+            CANCEL_ORDER_V2 => Self::CancelOrderV2(CancelOrderInstructionV2 {
+                side: Side::try_from(r.u32()?)?,
+                order_id: r.u128()?,
+            }),
+            CANCEL_ORDER_BY_CLIENT_ID_V2 => Self::CancelOrderByClientIdV2(CancelOrderByClientIdV2Instruction { client_order_id: r.u64()? }),
+            SEND_TAKE => Self::SendTake(SendTakeInstruction {
+                side: Side::try_from(r.u32()?)?,
+                limit_price: r.u64()?,
+                max_coin_qty: r.u64()?,
+                max_native_pc_qty_including_fees: r.u64()?,
+                min_coin_qty: r.u64()?,
+                min_native_pc_qty: r.u64()?,
+                limit: r.u16()?,
+            }),
+            CLOSE_OPEN_ORDERS => Self::CloseOpenOrders,
+            INIT_OPEN_ORDERS => Self::InitOpenOrders,
+            PRUNE => Self::Prune(PruneInstruction { limit: r.u16()? }),
+            CONSUME_EVENTS_PERMISSIONED => Self::ConsumeEventsPermissioned(ConsumeEventsPermissionedInstruction { limit: r.u16()? }),
             _ => return Err(ParseError::Unknown([disc[0], disc[1], disc[2], disc[3], 0, 0, 0, 0])),
         })
     }
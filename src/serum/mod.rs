@@ -2,6 +2,7 @@ use substreams_solana::b58;
 
 pub mod accounts;
 pub mod instructions;
+pub mod state;
 
 /// Serum DEX V3 program
 ///
@@ -0,0 +1,666 @@
+//! Serum DEX V3 on-chain account state.
+//!
+//! Every account is framed by the 5-byte `b"serum"` head padding and the
+//! 7-byte `b"padding"` tail. The body starts with a `u64` of [`AccountFlags`]
+//! telling which kind of account it is; pubkeys are stored as `[u64; 4]`,
+//! which has the same bytes as a `Pubkey`. OpenBook v1 is a fork of Serum v3
+//! and shares these layouts.
+
+use crate::common::bincode::{invalid, BincodeReader};
+use crate::common::ParseError;
+use solana_program::pubkey::Pubkey;
+
+pub use super::instructions::{OrderType, SelfTradeBehavior, Side};
+
+pub const HEAD_PADDING: &[u8; 5] = b"serum";
+pub const TAIL_PADDING: &[u8; 7] = b"padding";
+
+/// Size of a [`MarketState`] account, without the permissioned extension.
+pub const MARKET_STATE_LEN: usize = 388;
+/// Size of a permissioned market account.
+pub const MARKET_STATE_V2_LEN: usize = 1476;
+/// Size of an [`OpenOrders`] account.
+pub const OPEN_ORDERS_LEN: usize = 3228;
+
+const REQUEST_LEN: usize = 80;
+const EVENT_LEN: usize = 88;
+const SLAB_NODE_LEN: usize = 72;
+
+// -----------------------------------------------------------------------------
+// Flags
+// -----------------------------------------------------------------------------
+/// Kind and status bits shared by every Serum account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountFlags {
+    pub bits: u64,
+}
+
+impl AccountFlags {
+    pub const INITIALIZED: u64 = 1 << 0;
+    pub const MARKET: u64 = 1 << 1;
+    pub const OPEN_ORDERS: u64 = 1 << 2;
+    pub const REQUEST_QUEUE: u64 = 1 << 3;
+    pub const EVENT_QUEUE: u64 = 1 << 4;
+    pub const BIDS: u64 = 1 << 5;
+    pub const ASKS: u64 = 1 << 6;
+    pub const DISABLED: u64 = 1 << 7;
+    pub const CLOSED: u64 = 1 << 8;
+    pub const PERMISSIONED: u64 = 1 << 9;
+    pub const CRANK_AUTHORITY_REQUIRED: u64 = 1 << 10;
+
+    pub fn contains(&self, flag: u64) -> bool {
+        self.bits & flag == flag
+    }
+}
+
+const EVENT_FILL: u8 = 1 << 0;
+const EVENT_OUT: u8 = 1 << 1;
+const EVENT_BID: u8 = 1 << 2;
+const EVENT_MAKER: u8 = 1 << 3;
+const EVENT_RELEASE_FUNDS: u8 = 1 << 4;
+
+const REQUEST_NEW_ORDER: u8 = 1 << 0;
+const REQUEST_CANCEL_ORDER: u8 = 1 << 1;
+const REQUEST_BID: u8 = 1 << 2;
+const REQUEST_POST_ONLY: u8 = 1 << 3;
+const REQUEST_IMMEDIATE_OR_CANCEL: u8 = 1 << 4;
+
+fn side(is_bid: bool) -> Side {
+    if is_bid {
+        Side::Bid
+    } else {
+        Side::Ask
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Framing
+// -----------------------------------------------------------------------------
+/// Strips the head and tail padding and returns the account body.
+fn body(data: &[u8]) -> Result<&[u8], ParseError> {
+    let framing = HEAD_PADDING.len() + TAIL_PADDING.len();
+    if data.len() < framing + 8 {
+        return Err(ParseError::TooShort(data.len()));
+    }
+    let (head, rest) = data.split_at(HEAD_PADDING.len());
+    let (body, tail) = rest.split_at(rest.len() - TAIL_PADDING.len());
+    if head != HEAD_PADDING || tail != TAIL_PADDING {
+        return Err(invalid("missing serum account padding".to_string()));
+    }
+    Ok(body)
+}
+
+/// Reads the account flags and checks that `kind` is set.
+fn read_flags(r: &mut BincodeReader, kind: u64) -> Result<AccountFlags, ParseError> {
+    let flags = AccountFlags { bits: r.u64()? };
+    if !flags.contains(AccountFlags::INITIALIZED | kind) {
+        return Err(invalid(format!("unexpected serum account flags {:#x}", flags.bits)));
+    }
+    Ok(flags)
+}
+
+// -----------------------------------------------------------------------------
+// Market
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarketState {
+    pub account_flags: AccountFlags,
+    pub own_address: Pubkey,
+    pub vault_signer_nonce: u64,
+    pub coin_mint: Pubkey,
+    pub pc_mint: Pubkey,
+    pub coin_vault: Pubkey,
+    pub coin_deposits_total: u64,
+    pub coin_fees_accrued: u64,
+    pub pc_vault: Pubkey,
+    pub pc_deposits_total: u64,
+    pub pc_fees_accrued: u64,
+    pub pc_dust_threshold: u64,
+    pub req_q: Pubkey,
+    pub event_q: Pubkey,
+    pub bids: Pubkey,
+    pub asks: Pubkey,
+    pub coin_lot_size: u64,
+    pub pc_lot_size: u64,
+    pub fee_rate_bps: u64,
+    pub referrer_rebates_accrued: u64,
+    /// Present on permissioned markets only.
+    pub authorities: Option<MarketAuthorities>,
+}
+
+/// Extra signers required by a permissioned market.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarketAuthorities {
+    pub open_orders_authority: Pubkey,
+    pub prune_authority: Pubkey,
+    pub consume_events_authority: Pubkey,
+}
+
+impl<'a> TryFrom<&'a [u8]> for MarketState {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut r = BincodeReader::new(body(data)?);
+        let account_flags = read_flags(&mut r, AccountFlags::MARKET)?;
+        Ok(Self {
+            account_flags,
+            own_address: r.pubkey()?,
+            vault_signer_nonce: r.u64()?,
+            coin_mint: r.pubkey()?,
+            pc_mint: r.pubkey()?,
+            coin_vault: r.pubkey()?,
+            coin_deposits_total: r.u64()?,
+            coin_fees_accrued: r.u64()?,
+            pc_vault: r.pubkey()?,
+            pc_deposits_total: r.u64()?,
+            pc_fees_accrued: r.u64()?,
+            pc_dust_threshold: r.u64()?,
+            req_q: r.pubkey()?,
+            event_q: r.pubkey()?,
+            bids: r.pubkey()?,
+            asks: r.pubkey()?,
+            coin_lot_size: r.u64()?,
+            pc_lot_size: r.u64()?,
+            fee_rate_bps: r.u64()?,
+            referrer_rebates_accrued: r.u64()?,
+            authorities: if account_flags.contains(AccountFlags::PERMISSIONED) {
+                Some(MarketAuthorities {
+                    open_orders_authority: r.pubkey()?,
+                    prune_authority: r.pubkey()?,
+                    consume_events_authority: r.pubkey()?,
+                })
+            } else {
+                None
+            },
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Open orders
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenOrders {
+    pub account_flags: AccountFlags,
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    pub native_coin_free: u64,
+    pub native_coin_total: u64,
+    pub native_pc_free: u64,
+    pub native_pc_total: u64,
+    /// Bit `i` is set when slot `i` is free.
+    pub free_slot_bits: u128,
+    /// Bit `i` is set when the order in slot `i` is a bid.
+    pub is_bid_bits: u128,
+    pub orders: [u128; 128],
+    pub client_order_ids: [u64; 128],
+    pub referrer_rebates_accrued: u64,
+}
+
+/// An order resting in one of the [`OpenOrders`] slots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpenOrder {
+    pub slot: u8,
+    pub side: Side,
+    pub order_id: u128,
+    pub client_order_id: u64,
+}
+
+impl OpenOrders {
+    /// Orders in the occupied slots, by slot index.
+    pub fn active_orders(&self) -> impl Iterator<Item = OpenOrder> + '_ {
+        (0..128u8).filter(|slot| self.free_slot_bits & (1 << slot) == 0).map(|slot| OpenOrder {
+            slot,
+            side: side(self.is_bid_bits & (1 << slot) != 0),
+            order_id: self.orders[slot as usize],
+            client_order_id: self.client_order_ids[slot as usize],
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for OpenOrders {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut r = BincodeReader::new(body(data)?);
+        let account_flags = read_flags(&mut r, AccountFlags::OPEN_ORDERS)?;
+        let market = r.pubkey()?;
+        let owner = r.pubkey()?;
+        let native_coin_free = r.u64()?;
+        let native_coin_total = r.u64()?;
+        let native_pc_free = r.u64()?;
+        let native_pc_total = r.u64()?;
+        let free_slot_bits = r.u128()?;
+        let is_bid_bits = r.u128()?;
+        let mut orders = [0u128; 128];
+        for order in orders.iter_mut() {
+            *order = r.u128()?;
+        }
+        let mut client_order_ids = [0u64; 128];
+        for id in client_order_ids.iter_mut() {
+            *id = r.u64()?;
+        }
+        Ok(Self {
+            account_flags,
+            market,
+            owner,
+            native_coin_free,
+            native_coin_total,
+            native_pc_free,
+            native_pc_total,
+            free_slot_bits,
+            is_bid_bits,
+            orders,
+            client_order_ids,
+            referrer_rebates_accrued: r.u64()?,
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Queues
+// -----------------------------------------------------------------------------
+/// Ring-buffer header of the request and event queues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueHeader {
+    pub account_flags: AccountFlags,
+    /// Slot of the oldest item.
+    pub head: u64,
+    /// Number of live items, starting at `head`.
+    pub count: u64,
+    /// Sequence number the next pushed item will get.
+    pub seq_num: u64,
+}
+
+impl QueueHeader {
+    /// Sequence number of the oldest live item.
+    pub fn first_seq_num(&self) -> u64 {
+        self.seq_num.wrapping_sub(self.count)
+    }
+}
+
+/// Splits a queue body into its header and the live items in push order.
+fn read_queue(data: &[u8], kind: u64, item_len: usize) -> Result<(QueueHeader, u64, Vec<&[u8]>), ParseError> {
+    let mut r = BincodeReader::new(body(data)?);
+    let header = QueueHeader {
+        account_flags: read_flags(&mut r, kind)?,
+        head: r.u64()?,
+        count: r.u64()?,
+        seq_num: r.u64()?,
+    };
+    let slots = r.remaining();
+    let capacity = (slots.len() / item_len) as u64;
+    if header.count > capacity || (capacity > 0 && header.head >= capacity) {
+        return Err(invalid(format!(
+            "queue head {} / count {} out of capacity {capacity}",
+            header.head, header.count
+        )));
+    }
+    let items = (0..header.count)
+        .map(|i| {
+            let start = ((header.head + i) % capacity) as usize * item_len;
+            &slots[start..start + item_len]
+        })
+        .collect();
+    Ok((header, capacity, items))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// A trade, seen from one of its two sides.
+    Fill(FillEvent),
+    /// An order left the book, or funds locked by a taker were released.
+    Out(OutEvent),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FillEvent {
+    pub side: Side,
+    pub maker: bool,
+    pub native_qty_paid: u64,
+    pub native_qty_received: u64,
+    pub native_fee_or_rebate: u64,
+    pub order_id: u128,
+    pub owner: Pubkey,
+    pub owner_slot: u8,
+    pub fee_tier: u8,
+    /// `0` when the order had no client id.
+    pub client_order_id: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutEvent {
+    pub side: Side,
+    pub release_funds: bool,
+    pub native_qty_unlocked: u64,
+    pub native_qty_still_locked: u64,
+    pub order_id: u128,
+    pub owner: Pubkey,
+    pub owner_slot: u8,
+    /// `0` when the order had no client id.
+    pub client_order_id: u64,
+}
+
+impl<'a> TryFrom<&'a [u8]> for Event {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut r = BincodeReader::new(data);
+        let flags = r.u8()?;
+        let owner_slot = r.u8()?;
+        let fee_tier = r.u8()?;
+        r.take(5)?;
+        let native_qty_released = r.u64()?;
+        let native_qty_paid = r.u64()?;
+        let native_fee_or_rebate = r.u64()?;
+        let order_id = r.u128()?;
+        let owner = r.pubkey()?;
+        let client_order_id = r.u64()?;
+        let side = side(flags & EVENT_BID != 0);
+        Ok(match flags & (EVENT_FILL | EVENT_OUT) {
+            EVENT_FILL => Self::Fill(FillEvent {
+                side,
+                maker: flags & EVENT_MAKER != 0,
+                native_qty_paid,
+                native_qty_received: native_qty_released,
+                native_fee_or_rebate,
+                order_id,
+                owner,
+                owner_slot,
+                fee_tier,
+                client_order_id,
+            }),
+            EVENT_OUT => Self::Out(OutEvent {
+                side,
+                release_funds: flags & EVENT_RELEASE_FUNDS != 0,
+                native_qty_unlocked: native_qty_released,
+                native_qty_still_locked: native_qty_paid,
+                order_id,
+                owner,
+                owner_slot,
+                client_order_id,
+            }),
+            _ => return Err(invalid(format!("invalid event flags {flags:#x}"))),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventQueue {
+    pub header: QueueHeader,
+    /// Number of event slots in the ring buffer.
+    pub capacity: u64,
+    /// Live events, oldest first; the first one has sequence number
+    /// [`QueueHeader::first_seq_num`].
+    pub events: Vec<Event>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for EventQueue {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let (header, capacity, items) = read_queue(data, AccountFlags::EVENT_QUEUE, EVENT_LEN)?;
+        let events = items.into_iter().map(Event::try_from).collect::<Result<_, _>>()?;
+        Ok(Self { header, capacity, events })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Request {
+    NewOrder(NewOrderRequest),
+    CancelOrder(CancelOrderRequest),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewOrderRequest {
+    pub side: Side,
+    pub order_type: OrderType,
+    pub self_trade_behavior: SelfTradeBehavior,
+    pub owner_slot: u8,
+    pub fee_tier: u8,
+    pub order_id: u128,
+    pub max_coin_qty: u64,
+    pub native_pc_qty_locked: u64,
+    pub owner: Pubkey,
+    pub client_order_id: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CancelOrderRequest {
+    pub side: Side,
+    pub order_id: u128,
+    pub cancel_id: u64,
+    pub expected_owner_slot: u8,
+    pub expected_owner: Pubkey,
+    pub client_order_id: u64,
+}
+
+impl<'a> TryFrom<&'a [u8]> for Request {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut r = BincodeReader::new(data);
+        let flags = r.u8()?;
+        let owner_slot = r.u8()?;
+        let fee_tier = r.u8()?;
+        let self_trade_behavior = r.u8()?;
+        r.take(4)?;
+        let max_coin_qty_or_cancel_id = r.u64()?;
+        let native_pc_qty_locked = r.u64()?;
+        let order_id = r.u128()?;
+        let owner = r.pubkey()?;
+        let client_order_id = r.u64()?;
+        let side = side(flags & REQUEST_BID != 0);
+        Ok(match flags & (REQUEST_NEW_ORDER | REQUEST_CANCEL_ORDER) {
+            REQUEST_NEW_ORDER => Self::NewOrder(NewOrderRequest {
+                side,
+                order_type: if flags & REQUEST_POST_ONLY != 0 {
+                    OrderType::PostOnly
+                } else if flags & REQUEST_IMMEDIATE_OR_CANCEL != 0 {
+                    OrderType::ImmediateOrCancel
+                } else {
+                    OrderType::Limit
+                },
+                self_trade_behavior: SelfTradeBehavior::try_from(u32::from(self_trade_behavior))?,
+                owner_slot,
+                fee_tier,
+                order_id,
+                max_coin_qty: max_coin_qty_or_cancel_id,
+                native_pc_qty_locked,
+                owner,
+                client_order_id,
+            }),
+            REQUEST_CANCEL_ORDER => Self::CancelOrder(CancelOrderRequest {
+                side,
+                order_id,
+                cancel_id: max_coin_qty_or_cancel_id,
+                expected_owner_slot: owner_slot,
+                expected_owner: owner,
+                client_order_id,
+            }),
+            _ => return Err(invalid(format!("invalid request flags {flags:#x}"))),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestQueue {
+    pub header: QueueHeader,
+    /// Number of request slots in the ring buffer.
+    pub capacity: u64,
+    /// Pending requests, oldest first.
+    pub requests: Vec<Request>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for RequestQueue {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let (header, capacity, items) = read_queue(data, AccountFlags::REQUEST_QUEUE, REQUEST_LEN)?;
+        let requests = items.into_iter().map(Request::try_from).collect::<Result<_, _>>()?;
+        Ok(Self { header, capacity, requests })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Orderbook slab
+// -----------------------------------------------------------------------------
+const NODE_INNER: u32 = 1;
+const NODE_LEAF: u32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlabHeader {
+    pub bump_index: u64,
+    pub free_list_len: u64,
+    pub free_list_head: u32,
+    pub root_node: u32,
+    pub leaf_count: u64,
+}
+
+/// A resting order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeafNode {
+    pub owner_slot: u8,
+    pub fee_tier: u8,
+    /// Order id: price in the upper 64 bits, sequence number in the lower.
+    pub key: u128,
+    pub owner: Pubkey,
+    /// Remaining size, in coin lots.
+    pub quantity: u64,
+    pub client_order_id: u64,
+}
+
+impl LeafNode {
+    /// Limit price, in pc lots per coin lot.
+    pub fn price(&self) -> u64 {
+        (self.key >> 64) as u64
+    }
+}
+
+/// One side of the orderbook: a crit-bit tree keyed by order id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Slab {
+    pub account_flags: AccountFlags,
+    pub header: SlabHeader,
+    /// Resting orders in ascending key order, so the best bid is the last
+    /// leaf of the bids and the best ask the first leaf of the asks.
+    pub leaves: Vec<LeafNode>,
+}
+
+impl Slab {
+    fn read(data: &[u8], kind: u64) -> Result<Self, ParseError> {
+        let mut r = BincodeReader::new(body(data)?);
+        let account_flags = read_flags(&mut r, kind)?;
+        let header = SlabHeader {
+            bump_index: r.u64()?,
+            free_list_len: r.u64()?,
+            free_list_head: r.u32()?,
+            root_node: r.u32()?,
+            leaf_count: r.u64()?,
+        };
+        let nodes = r.remaining();
+        let node_count = nodes.len() / SLAB_NODE_LEN;
+        let node = |index: u32| {
+            let start = index as usize * SLAB_NODE_LEN;
+            if index as usize >= node_count {
+                return Err(invalid(format!("slab node {index} out of {node_count}")));
+            }
+            Ok(BincodeReader::new(&nodes[start..start + SLAB_NODE_LEN]))
+        };
+
+        // In-order walk of the crit-bit tree; child 0 holds the lower keys.
+        let mut leaves = Vec::new();
+        let mut stack = Vec::new();
+        if header.leaf_count > 0 {
+            stack.push(header.root_node);
+        }
+        let mut visited = 0;
+        while let Some(index) = stack.pop() {
+            visited += 1;
+            if visited > node_count {
+                return Err(invalid("slab tree has a cycle".to_string()));
+            }
+            let mut r = node(index)?;
+            match r.u32()? {
+                NODE_INNER => {
+                    r.take(4 + 16)?; // prefix_len, key
+                    let left = r.u32()?;
+                    let right = r.u32()?;
+                    stack.push(right);
+                    stack.push(left);
+                }
+                NODE_LEAF => {
+                    let owner_slot = r.u8()?;
+                    let fee_tier = r.u8()?;
+                    r.take(2)?;
+                    leaves.push(LeafNode {
+                        owner_slot,
+                        fee_tier,
+                        key: r.u128()?,
+                        owner: r.pubkey()?,
+                        quantity: r.u64()?,
+                        client_order_id: r.u64()?,
+                    });
+                }
+                tag => return Err(invalid(format!("unexpected slab node tag {tag} in tree"))),
+            }
+        }
+        if leaves.len() as u64 != header.leaf_count {
+            return Err(invalid(format!("slab has {} leaves, header says {}", leaves.len(), header.leaf_count)));
+        }
+        Ok(Self { account_flags, header, leaves })
+    }
+
+    /// Decodes the bids account of a market.
+    pub fn bids(data: &[u8]) -> Result<Self, ParseError> {
+        Self::read(data, AccountFlags::BIDS)
+    }
+
+    /// Decodes the asks account of a market.
+    pub fn asks(data: &[u8]) -> Result<Self, ParseError> {
+        Self::read(data, AccountFlags::ASKS)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Dispatch
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SerumAccount {
+    Market(Box<MarketState>),
+    OpenOrders(Box<OpenOrders>),
+    RequestQueue(RequestQueue),
+    EventQueue(EventQueue),
+    Bids(Slab),
+    Asks(Slab),
+}
+
+impl<'a> TryFrom<&'a [u8]> for SerumAccount {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let flags = AccountFlags {
+            bits: BincodeReader::new(body(data)?).u64()?,
+        };
+        Ok(if flags.contains(AccountFlags::MARKET) {
+            Self::Market(Box::new(MarketState::try_from(data)?))
+        } else if flags.contains(AccountFlags::OPEN_ORDERS) {
+            Self::OpenOrders(Box::new(OpenOrders::try_from(data)?))
+        } else if flags.contains(AccountFlags::REQUEST_QUEUE) {
+            Self::RequestQueue(RequestQueue::try_from(data)?)
+        } else if flags.contains(AccountFlags::EVENT_QUEUE) {
+            Self::EventQueue(EventQueue::try_from(data)?)
+        } else if flags.contains(AccountFlags::BIDS) {
+            Self::Bids(Slab::bids(data)?)
+        } else if flags.contains(AccountFlags::ASKS) {
+            Self::Asks(Slab::asks(data)?)
+        } else {
+            return Err(invalid(format!("unknown serum account flags {:#x}", flags.bits)));
+        })
+    }
+}
+
+/// Decodes the data of an account owned by the Serum DEX program.
+pub fn unpack(data: &[u8]) -> Result<SerumAccount, ParseError> {
+    SerumAccount::try_from(data)
+}
@@ -1,32 +1,264 @@
 //! SPL Token on-chain accounts.
+//!
+//! Mints and token accounts use the fixed-size `Pack` layout, where a
+//! `COption<T>` is a little-endian `u32` tag followed by `T`, present even when
+//! the tag is `0`. The `*View` types read fields straight from the account data;
+//! [`Mint`] and [`Account`] are their owned counterparts.
 
-use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
+use crate::common::bincode::invalid;
+use crate::common::ParseError;
+
+/// Size of a mint account.
+pub const MINT_LEN: usize = 82;
+/// Size of a token account.
+pub const ACCOUNT_LEN: usize = 165;
+
+/// State of a token account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountState {
+    Uninitialized,
+    Initialized,
+    /// Frozen by the mint's freeze authority.
+    Frozen,
+}
+
+impl TryFrom<u8> for AccountState {
+    type Error = ParseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Uninitialized),
+            1 => Ok(Self::Initialized),
+            2 => Ok(Self::Frozen),
+            other => Err(invalid(format!("invalid AccountState {other}"))),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Layout helpers
+// -----------------------------------------------------------------------------
+fn check_len(data: &[u8], expected: usize) -> Result<(), ParseError> {
+    if data.len() != expected {
+        return Err(ParseError::InvalidLength { expected, got: data.len() });
+    }
+    Ok(())
+}
+
+fn check_coption(data: &[u8], offset: usize) -> Result<(), ParseError> {
+    match read_u32(data, offset) {
+        0 | 1 => Ok(()),
+        other => Err(invalid(format!("invalid COption tag {other}"))),
+    }
+}
+
+fn check_bool(data: &[u8], offset: usize) -> Result<(), ParseError> {
+    match data[offset] {
+        0 | 1 => Ok(()),
+        other => Err(invalid(format!("invalid bool {other}"))),
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Pubkey {
+    Pubkey::new_from_array(data[offset..offset + 32].try_into().unwrap())
+}
+
+/// Reads a `COption` whose tag was validated on construction.
+fn read_coption<T>(data: &[u8], offset: usize, read: impl FnOnce(&[u8], usize) -> T) -> Option<T> {
+    (read_u32(data, offset) == 1).then(|| read(data, offset + 4))
+}
+
+// -----------------------------------------------------------------------------
+// Mint
+// -----------------------------------------------------------------------------
+const MINT_AUTHORITY: usize = 0;
+const MINT_SUPPLY: usize = 36;
+const MINT_DECIMALS: usize = 44;
+const MINT_IS_INITIALIZED: usize = 45;
+const MINT_FREEZE_AUTHORITY: usize = 46;
+
+/// Borrowed view over the 82 bytes of a mint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MintView<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> MintView<'a> {
+    /// Validates the length and the `COption`/`bool` tags of a mint.
+    pub fn new(data: &'a [u8]) -> Result<Self, ParseError> {
+        check_len(data, MINT_LEN)?;
+        check_coption(data, MINT_AUTHORITY)?;
+        check_bool(data, MINT_IS_INITIALIZED)?;
+        check_coption(data, MINT_FREEZE_AUTHORITY)?;
+        Ok(Self { data })
+    }
+
+    /// `None` once minting has been disabled for good.
+    pub fn mint_authority(&self) -> Option<Pubkey> {
+        read_coption(self.data, MINT_AUTHORITY, read_pubkey)
+    }
+
+    pub fn supply(&self) -> u64 {
+        read_u64(self.data, MINT_SUPPLY)
+    }
+
+    pub fn decimals(&self) -> u8 {
+        self.data[MINT_DECIMALS]
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.data[MINT_IS_INITIALIZED] == 1
+    }
+
+    pub fn freeze_authority(&self) -> Option<Pubkey> {
+        read_coption(self.data, MINT_FREEZE_AUTHORITY, read_pubkey)
+    }
+}
+
 /// SPL Token Mint account
-#[derive(Debug, Clone, PartialEq, BorshSerialize, BorshDeserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Mint {
-    pub mint_authority_option: u32,
-    pub mint_authority: Pubkey,
+    pub mint_authority: Option<Pubkey>,
     pub supply: u64,
     pub decimals: u8,
     pub is_initialized: bool,
-    pub freeze_authority_option: u32,
-    pub freeze_authority: Pubkey,
+    pub freeze_authority: Option<Pubkey>,
+}
+
+impl From<MintView<'_>> for Mint {
+    fn from(view: MintView<'_>) -> Self {
+        Self {
+            mint_authority: view.mint_authority(),
+            supply: view.supply(),
+            decimals: view.decimals(),
+            is_initialized: view.is_initialized(),
+            freeze_authority: view.freeze_authority(),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Mint {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        MintView::new(data).map(Self::from)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Account
+// -----------------------------------------------------------------------------
+const ACCOUNT_MINT: usize = 0;
+const ACCOUNT_OWNER: usize = 32;
+const ACCOUNT_AMOUNT: usize = 64;
+const ACCOUNT_DELEGATE: usize = 72;
+const ACCOUNT_STATE: usize = 108;
+const ACCOUNT_IS_NATIVE: usize = 109;
+const ACCOUNT_DELEGATED_AMOUNT: usize = 121;
+const ACCOUNT_CLOSE_AUTHORITY: usize = 129;
+
+/// Borrowed view over the 165 bytes of a token account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountView<'a> {
+    data: &'a [u8],
+    state: AccountState,
+}
+
+impl<'a> AccountView<'a> {
+    /// Validates the length, the `COption` tags and the state of a token account.
+    pub fn new(data: &'a [u8]) -> Result<Self, ParseError> {
+        check_len(data, ACCOUNT_LEN)?;
+        check_coption(data, ACCOUNT_DELEGATE)?;
+        check_coption(data, ACCOUNT_IS_NATIVE)?;
+        check_coption(data, ACCOUNT_CLOSE_AUTHORITY)?;
+        let state = AccountState::try_from(data[ACCOUNT_STATE])?;
+        Ok(Self { data, state })
+    }
+
+    pub fn mint(&self) -> Pubkey {
+        read_pubkey(self.data, ACCOUNT_MINT)
+    }
+
+    pub fn owner(&self) -> Pubkey {
+        read_pubkey(self.data, ACCOUNT_OWNER)
+    }
+
+    pub fn amount(&self) -> u64 {
+        read_u64(self.data, ACCOUNT_AMOUNT)
+    }
+
+    pub fn delegate(&self) -> Option<Pubkey> {
+        read_coption(self.data, ACCOUNT_DELEGATE, read_pubkey)
+    }
+
+    pub fn state(&self) -> AccountState {
+        self.state
+    }
+
+    /// Rent-exempt reserve of a wrapped SOL account; `None` for other mints.
+    pub fn is_native(&self) -> Option<u64> {
+        read_coption(self.data, ACCOUNT_IS_NATIVE, read_u64)
+    }
+
+    pub fn delegated_amount(&self) -> u64 {
+        read_u64(self.data, ACCOUNT_DELEGATED_AMOUNT)
+    }
+
+    pub fn close_authority(&self) -> Option<Pubkey> {
+        read_coption(self.data, ACCOUNT_CLOSE_AUTHORITY, read_pubkey)
+    }
 }
 
 /// SPL Token Account
-#[derive(Debug, Clone, PartialEq, BorshSerialize, BorshDeserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Account {
     pub mint: Pubkey,
     pub owner: Pubkey,
     pub amount: u64,
-    pub delegate_option: u32,
-    pub delegate: Pubkey,
-    pub state: u8,
-    pub is_native_option: u32,
-    pub is_native: u64,
+    pub delegate: Option<Pubkey>,
+    pub state: AccountState,
+    pub is_native: Option<u64>,
     pub delegated_amount: u64,
-    pub close_authority_option: u32,
-    pub close_authority: Pubkey,
+    pub close_authority: Option<Pubkey>,
+}
+
+impl From<AccountView<'_>> for Account {
+    fn from(view: AccountView<'_>) -> Self {
+        Self {
+            mint: view.mint(),
+            owner: view.owner(),
+            amount: view.amount(),
+            delegate: view.delegate(),
+            state: view.state(),
+            is_native: view.is_native(),
+            delegated_amount: view.delegated_amount(),
+            close_authority: view.close_authority(),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Account {
+    type Error = ParseError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        AccountView::new(data).map(Self::from)
+    }
+}
+
+pub fn unpack_mint(data: &[u8]) -> Result<Mint, ParseError> {
+    Mint::try_from(data)
+}
+
+pub fn unpack_account(data: &[u8]) -> Result<Account, ParseError> {
+    Account::try_from(data)
 }
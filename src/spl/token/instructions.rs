@@ -1,6 +1,8 @@
 //! SPL Token on-chain instructions.
 
+use crate::common::bincode::invalid;
 use crate::common::ParseError;
+use solana_program::pubkey::Pubkey;
 
 // -----------------------------------------------------------------------------
 // Discriminators (single-byte index)
@@ -27,37 +29,128 @@ pub const INITIALIZE_ACCOUNT3: u8 = 18;
 pub const INITIALIZE_MULTISIG2: u8 = 19;
 pub const INITIALIZE_MINT2: u8 = 20;
 
+// -----------------------------------------------------------------------------
+// Authority types (`SetAuthority`)
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthorityType {
+    MintTokens,
+    FreezeAccount,
+    AccountOwner,
+    CloseAccount,
+}
+
+impl TryFrom<u8> for AuthorityType {
+    type Error = ParseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::MintTokens),
+            1 => Ok(Self::FreezeAccount),
+            2 => Ok(Self::AccountOwner),
+            3 => Ok(Self::CloseAccount),
+            other => Err(invalid(format!("invalid AuthorityType {other}"))),
+        }
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Instruction enumeration
 // -----------------------------------------------------------------------------
 #[derive(Debug, Clone, PartialEq)]
 pub enum TokenInstruction {
-    InitializeMint { decimals: u8, freeze_authority: bool },
+    InitializeMint {
+        decimals: u8,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+    },
     InitializeAccount,
-    InitializeMultisig { m: u8 },
-    Transfer { amount: u64 },
-    Approve { amount: u64 },
+    InitializeMultisig {
+        m: u8,
+    },
+    Transfer {
+        amount: u64,
+    },
+    Approve {
+        amount: u64,
+    },
     Revoke,
-    SetAuthority,
-    MintTo { amount: u64 },
-    Burn { amount: u64 },
+    SetAuthority {
+        authority_type: AuthorityType,
+        new_authority: Option<Pubkey>,
+    },
+    MintTo {
+        amount: u64,
+    },
+    Burn {
+        amount: u64,
+    },
     CloseAccount,
     FreezeAccount,
     ThawAccount,
-    TransferChecked { amount: u64, decimals: u8 },
-    ApproveChecked { amount: u64, decimals: u8 },
-    MintToChecked { amount: u64, decimals: u8 },
-    BurnChecked { amount: u64, decimals: u8 },
-    InitializeAccount2,
+    TransferChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    ApproveChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    MintToChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    BurnChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    InitializeAccount2 {
+        owner: Pubkey,
+    },
     SyncNative,
-    InitializeAccount3,
-    InitializeMultisig2 { m: u8 },
-    InitializeMint2 { decimals: u8, freeze_authority: bool },
+    InitializeAccount3 {
+        owner: Pubkey,
+    },
+    InitializeMultisig2 {
+        m: u8,
+    },
+    InitializeMint2 {
+        decimals: u8,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+    },
 }
 
 // -----------------------------------------------------------------------------
 // Parsing
 // -----------------------------------------------------------------------------
+/// Reads a 32-byte pubkey off the front of `input`; `data` is only used for the error.
+fn unpack_pubkey<'a>(input: &'a [u8], data: &[u8]) -> Result<(Pubkey, &'a [u8]), ParseError> {
+    if input.len() < 32 {
+        return Err(ParseError::TooShort(data.len()));
+    }
+    let (key, rest) = input.split_at(32);
+    Ok((Pubkey::new_from_array(key.try_into().unwrap()), rest))
+}
+
+/// Reads a `COption<Pubkey>` as packed in instruction data: a `u8` tag, followed
+/// by the pubkey only when the tag is `1`.
+fn unpack_pubkey_option<'a>(input: &'a [u8], data: &[u8]) -> Result<(Option<Pubkey>, &'a [u8]), ParseError> {
+    match input.split_first() {
+        Some((&0, rest)) => Ok((None, rest)),
+        Some((&1, rest)) => unpack_pubkey(rest, data).map(|(key, rest)| (Some(key), rest)),
+        Some((&other, _)) => Err(invalid(format!("invalid COption tag {other}"))),
+        None => Err(ParseError::TooShort(data.len())),
+    }
+}
+
+fn unpack_initialize_mint(rest: &[u8], data: &[u8]) -> Result<(u8, Pubkey, Option<Pubkey>), ParseError> {
+    let (&decimals, rest) = rest.split_first().ok_or(ParseError::TooShort(data.len()))?;
+    let (mint_authority, rest) = unpack_pubkey(rest, data)?;
+    let (freeze_authority, _) = unpack_pubkey_option(rest, data)?;
+    Ok((decimals, mint_authority, freeze_authority))
+}
+
 impl TryFrom<&[u8]> for TokenInstruction {
     type Error = ParseError;
 
@@ -68,12 +161,11 @@ impl TryFrom<&[u8]> for TokenInstruction {
         let rest = &data[1..];
         match data[0] {
             INITIALIZE_MINT => {
-                if rest.len() < 2 {
-                    return Err(ParseError::TooShort(data.len()));
-                }
+                let (decimals, mint_authority, freeze_authority) = unpack_initialize_mint(rest, data)?;
                 Ok(Self::InitializeMint {
-                    decimals: rest[0],
-                    freeze_authority: rest.len() > 34,
+                    decimals,
+                    mint_authority,
+                    freeze_authority,
                 })
             }
             INITIALIZE_ACCOUNT => Ok(Self::InitializeAccount),
@@ -100,7 +192,14 @@ impl TryFrom<&[u8]> for TokenInstruction {
                 })
             }
             REVOKE => Ok(Self::Revoke),
-            SET_AUTHORITY => Ok(Self::SetAuthority),
+            SET_AUTHORITY => {
+                let (&authority_type, rest) = rest.split_first().ok_or(ParseError::TooShort(data.len()))?;
+                let (new_authority, _) = unpack_pubkey_option(rest, data)?;
+                Ok(Self::SetAuthority {
+                    authority_type: AuthorityType::try_from(authority_type)?,
+                    new_authority,
+                })
+            }
             MINT_TO => {
                 if rest.len() < 8 {
                     return Err(ParseError::TooShort(data.len()));
@@ -156,9 +255,13 @@ impl TryFrom<&[u8]> for TokenInstruction {
                     decimals: rest[8],
                 })
             }
-            INITIALIZE_ACCOUNT2 => Ok(Self::InitializeAccount2),
+            INITIALIZE_ACCOUNT2 => Ok(Self::InitializeAccount2 {
+                owner: unpack_pubkey(rest, data)?.0,
+            }),
             SYNC_NATIVE => Ok(Self::SyncNative),
-            INITIALIZE_ACCOUNT3 => Ok(Self::InitializeAccount3),
+            INITIALIZE_ACCOUNT3 => Ok(Self::InitializeAccount3 {
+                owner: unpack_pubkey(rest, data)?.0,
+            }),
             INITIALIZE_MULTISIG2 => {
                 if rest.is_empty() {
                     return Err(ParseError::TooShort(data.len()));
@@ -166,12 +269,11 @@ impl TryFrom<&[u8]> for TokenInstruction {
                 Ok(Self::InitializeMultisig2 { m: rest[0] })
             }
             INITIALIZE_MINT2 => {
-                if rest.len() < 2 {
-                    return Err(ParseError::TooShort(data.len()));
-                }
+                let (decimals, mint_authority, freeze_authority) = unpack_initialize_mint(rest, data)?;
                 Ok(Self::InitializeMint2 {
-                    decimals: rest[0],
-                    freeze_authority: rest.len() > 34,
+                    decimals,
+                    mint_authority,
+                    freeze_authority,
                 })
             }
             other => Err(ParseError::SplUnknown(other)),
@@ -3,33 +3,12 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
-/// SPL Token 2022 Mint account
-#[derive(Debug, Clone, PartialEq, BorshSerialize, BorshDeserialize)]
-pub struct Mint {
-    pub mint_authority_option: u32,
-    pub mint_authority: Pubkey,
-    pub supply: u64,
-    pub decimals: u8,
-    pub is_initialized: bool,
-    pub freeze_authority_option: u32,
-    pub freeze_authority: Pubkey,
-}
+use super::extensions::read_optional_nonzero_pubkey;
+use crate::common::bincode::{invalid, BincodeReader};
+use crate::common::ParseError;
 
-/// SPL Token 2022 Account
-#[derive(Debug, Clone, PartialEq, BorshSerialize, BorshDeserialize)]
-pub struct Account {
-    pub mint: Pubkey,
-    pub owner: Pubkey,
-    pub amount: u64,
-    pub delegate_option: u32,
-    pub delegate: Pubkey,
-    pub state: u8,
-    pub is_native_option: u32,
-    pub is_native: u64,
-    pub delegated_amount: u64,
-    pub close_authority_option: u32,
-    pub close_authority: Pubkey,
-}
+/// The base mint and account layouts are shared with SPL Token.
+pub use crate::spl::token::accounts::{Account, AccountState, AccountView, Mint, MintView, ACCOUNT_LEN, MINT_LEN};
 
 /// SPL Token 2022 Multisig account
 #[derive(Debug, Clone, PartialEq, BorshSerialize, BorshDeserialize)]
@@ -39,3 +18,340 @@ pub struct Multisig {
     pub is_initialized: bool,
     pub signers: [Pubkey; 11],
 }
+
+// -----------------------------------------------------------------------------
+// Extensions (TLV)
+// -----------------------------------------------------------------------------
+//
+// Mints and token accounts with extensions are laid out as the base state,
+// zero-padded to `BASE_ACCOUNT_LENGTH`, followed by an `AccountType` byte and a
+// list of `(u16 type, u16 length, value)` entries.
+
+/// Length of a base token account, and of a mint padded for extensions.
+pub const BASE_ACCOUNT_LENGTH: usize = 165;
+/// Multisig accounts are the one layout longer than the base that carries no extensions.
+pub const MULTISIG_LENGTH: usize = 355;
+
+/// Byte following the padded base state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountType {
+    Uninitialized,
+    Mint,
+    Account,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Extension {
+    TransferFeeConfig(TransferFeeConfig),
+    TransferFeeAmount {
+        withheld_amount: u64,
+    },
+    MintCloseAuthority {
+        close_authority: Option<Pubkey>,
+    },
+    DefaultAccountState {
+        state: AccountState,
+    },
+    ImmutableOwner,
+    MemoTransfer {
+        require_incoming_transfer_memos: bool,
+    },
+    NonTransferable,
+    InterestBearingConfig(InterestBearingConfig),
+    CpiGuard {
+        lock_cpi: bool,
+    },
+    PermanentDelegate {
+        delegate: Option<Pubkey>,
+    },
+    NonTransferableAccount,
+    TransferHook {
+        authority: Option<Pubkey>,
+        program_id: Option<Pubkey>,
+    },
+    TransferHookAccount {
+        transferring: bool,
+    },
+    MetadataPointer {
+        authority: Option<Pubkey>,
+        metadata_address: Option<Pubkey>,
+    },
+    TokenMetadata(TokenMetadata),
+    GroupPointer {
+        authority: Option<Pubkey>,
+        group_address: Option<Pubkey>,
+    },
+    TokenGroup(TokenGroup),
+    GroupMemberPointer {
+        authority: Option<Pubkey>,
+        member_address: Option<Pubkey>,
+    },
+    TokenGroupMember(TokenGroupMember),
+    ScaledUiAmount(ScaledUiAmountConfig),
+    Pausable {
+        authority: Option<Pubkey>,
+        paused: bool,
+    },
+    PausableAccount,
+    /// Confidential extensions (ciphertexts) and types unknown to this crate.
+    Unknown {
+        extension_type: u16,
+        data: Vec<u8>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferFee {
+    /// First epoch the fee applies to.
+    pub epoch: u64,
+    pub maximum_fee: u64,
+    pub transfer_fee_basis_points: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferFeeConfig {
+    pub transfer_fee_config_authority: Option<Pubkey>,
+    pub withdraw_withheld_authority: Option<Pubkey>,
+    /// Fees withheld on the mint itself, harvested from token accounts.
+    pub withheld_amount: u64,
+    pub older_transfer_fee: TransferFee,
+    pub newer_transfer_fee: TransferFee,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterestBearingConfig {
+    pub rate_authority: Option<Pubkey>,
+    pub initialization_timestamp: i64,
+    /// Average rate, in basis points, from initialization to the last update.
+    pub pre_update_average_rate: i16,
+    pub last_update_timestamp: i64,
+    pub current_rate: i16,
+}
+
+/// Metadata stored in the mint itself (`spl-token-metadata-interface`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenMetadata {
+    pub update_authority: Option<Pubkey>,
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub additional_metadata: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenGroup {
+    pub update_authority: Option<Pubkey>,
+    pub mint: Pubkey,
+    pub size: u64,
+    pub max_size: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenGroupMember {
+    pub mint: Pubkey,
+    pub group: Pubkey,
+    pub member_number: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaledUiAmountConfig {
+    pub authority: Option<Pubkey>,
+    pub multiplier: f64,
+    pub new_multiplier_effective_timestamp: i64,
+    pub new_multiplier: f64,
+}
+
+fn read_transfer_fee(r: &mut BincodeReader) -> Result<TransferFee, ParseError> {
+    Ok(TransferFee {
+        epoch: r.u64()?,
+        maximum_fee: r.u64()?,
+        transfer_fee_basis_points: r.u16()?,
+    })
+}
+
+/// The metadata extension is Borsh-encoded, after the two fixed-size keys.
+fn read_token_metadata(value: &[u8]) -> Result<TokenMetadata, ParseError> {
+    let mut r = BincodeReader::new(value);
+    let update_authority = read_optional_nonzero_pubkey(&mut r)?;
+    let mint = r.pubkey()?;
+    let rest = &mut r.remaining();
+    Ok(TokenMetadata {
+        update_authority,
+        mint,
+        name: String::deserialize(rest)?,
+        symbol: String::deserialize(rest)?,
+        uri: String::deserialize(rest)?,
+        additional_metadata: Vec::<(String, String)>::deserialize(rest)?,
+    })
+}
+
+impl Extension {
+    fn unpack(extension_type: u16, value: &[u8]) -> Result<Self, ParseError> {
+        let r = &mut BincodeReader::new(value);
+        Ok(match extension_type {
+            1 => Self::TransferFeeConfig(TransferFeeConfig {
+                transfer_fee_config_authority: read_optional_nonzero_pubkey(r)?,
+                withdraw_withheld_authority: read_optional_nonzero_pubkey(r)?,
+                withheld_amount: r.u64()?,
+                older_transfer_fee: read_transfer_fee(r)?,
+                newer_transfer_fee: read_transfer_fee(r)?,
+            }),
+            2 => Self::TransferFeeAmount { withheld_amount: r.u64()? },
+            3 => Self::MintCloseAuthority {
+                close_authority: read_optional_nonzero_pubkey(r)?,
+            },
+            6 => Self::DefaultAccountState {
+                state: AccountState::try_from(r.u8()?)?,
+            },
+            7 => Self::ImmutableOwner,
+            8 => Self::MemoTransfer {
+                require_incoming_transfer_memos: r.bool()?,
+            },
+            9 => Self::NonTransferable,
+            10 => Self::InterestBearingConfig(InterestBearingConfig {
+                rate_authority: read_optional_nonzero_pubkey(r)?,
+                initialization_timestamp: r.i64()?,
+                pre_update_average_rate: i16::from_le_bytes(r.array()?),
+                last_update_timestamp: r.i64()?,
+                current_rate: i16::from_le_bytes(r.array()?),
+            }),
+            11 => Self::CpiGuard { lock_cpi: r.bool()? },
+            12 => Self::PermanentDelegate {
+                delegate: read_optional_nonzero_pubkey(r)?,
+            },
+            13 => Self::NonTransferableAccount,
+            14 => Self::TransferHook {
+                authority: read_optional_nonzero_pubkey(r)?,
+                program_id: read_optional_nonzero_pubkey(r)?,
+            },
+            15 => Self::TransferHookAccount { transferring: r.bool()? },
+            18 => Self::MetadataPointer {
+                authority: read_optional_nonzero_pubkey(r)?,
+                metadata_address: read_optional_nonzero_pubkey(r)?,
+            },
+            19 => Self::TokenMetadata(read_token_metadata(value)?),
+            20 => Self::GroupPointer {
+                authority: read_optional_nonzero_pubkey(r)?,
+                group_address: read_optional_nonzero_pubkey(r)?,
+            },
+            21 => Self::TokenGroup(TokenGroup {
+                update_authority: read_optional_nonzero_pubkey(r)?,
+                mint: r.pubkey()?,
+                size: r.u64()?,
+                max_size: r.u64()?,
+            }),
+            22 => Self::GroupMemberPointer {
+                authority: read_optional_nonzero_pubkey(r)?,
+                member_address: read_optional_nonzero_pubkey(r)?,
+            },
+            23 => Self::TokenGroupMember(TokenGroupMember {
+                mint: r.pubkey()?,
+                group: r.pubkey()?,
+                member_number: r.u64()?,
+            }),
+            25 => Self::ScaledUiAmount(ScaledUiAmountConfig {
+                authority: read_optional_nonzero_pubkey(r)?,
+                multiplier: f64::from_le_bytes(r.array()?),
+                new_multiplier_effective_timestamp: r.i64()?,
+                new_multiplier: f64::from_le_bytes(r.array()?),
+            }),
+            26 => Self::Pausable {
+                authority: read_optional_nonzero_pubkey(r)?,
+                paused: r.bool()?,
+            },
+            27 => Self::PausableAccount,
+            _ => Self::Unknown {
+                extension_type,
+                data: value.to_vec(),
+            },
+        })
+    }
+}
+
+/// Returns the account type of a Token-2022 mint or account, or `None` when
+/// `data` has no extension area.
+pub fn unpack_account_type(data: &[u8]) -> Result<Option<AccountType>, ParseError> {
+    if data.len() <= BASE_ACCOUNT_LENGTH || data.len() == MULTISIG_LENGTH {
+        return Ok(None);
+    }
+    match data[BASE_ACCOUNT_LENGTH] {
+        0 => Ok(Some(AccountType::Uninitialized)),
+        1 => Ok(Some(AccountType::Mint)),
+        2 => Ok(Some(AccountType::Account)),
+        other => Err(invalid(format!("invalid AccountType {other}"))),
+    }
+}
+
+/// Walks the TLV extension area of a Token-2022 mint or account.
+///
+/// Returns an empty list for base-sized mints, accounts and multisigs.
+pub fn unpack_extensions(data: &[u8]) -> Result<Vec<Extension>, ParseError> {
+    if unpack_account_type(data)?.is_none() {
+        return Ok(Vec::new());
+    }
+    let mut r = BincodeReader::new(&data[BASE_ACCOUNT_LENGTH + 1..]);
+    let mut extensions = Vec::new();
+    // Trailing bytes too short for a header are padding.
+    while r.remaining().len() >= 4 {
+        let extension_type = r.u16()?;
+        // An uninitialized type marks the end of the written entries.
+        if extension_type == 0 {
+            break;
+        }
+        let len = r.u16()? as usize;
+        let value = r.take(len).map_err(|_| ParseError::TooShort(data.len()))?;
+        extensions.push(Extension::unpack(extension_type, value)?);
+    }
+    Ok(extensions)
+}
+
+// -----------------------------------------------------------------------------
+// Base state
+// -----------------------------------------------------------------------------
+
+/// Returns the base mint bytes, with or without extensions.
+fn base_mint(data: &[u8]) -> Result<&[u8], ParseError> {
+    if data.len() == MINT_LEN {
+        return Ok(data);
+    }
+    match unpack_account_type(data)? {
+        Some(AccountType::Mint) => Ok(&data[..MINT_LEN]),
+        _ => Err(ParseError::InvalidLength {
+            expected: MINT_LEN,
+            got: data.len(),
+        }),
+    }
+}
+
+/// Returns the base token account bytes, with or without extensions.
+fn base_account(data: &[u8]) -> Result<&[u8], ParseError> {
+    if data.len() == ACCOUNT_LEN {
+        return Ok(data);
+    }
+    match unpack_account_type(data)? {
+        Some(AccountType::Account) => Ok(&data[..ACCOUNT_LEN]),
+        _ => Err(ParseError::InvalidLength {
+            expected: ACCOUNT_LEN,
+            got: data.len(),
+        }),
+    }
+}
+
+pub fn mint_view(data: &[u8]) -> Result<MintView<'_>, ParseError> {
+    MintView::new(base_mint(data)?)
+}
+
+pub fn account_view(data: &[u8]) -> Result<AccountView<'_>, ParseError> {
+    AccountView::new(base_account(data)?)
+}
+
+/// Decodes the base state of a mint; see [`unpack_extensions`] for the rest.
+pub fn unpack_mint(data: &[u8]) -> Result<Mint, ParseError> {
+    mint_view(data).map(Mint::from)
+}
+
+/// Decodes the base state of a token account; see [`unpack_extensions`] for the rest.
+pub fn unpack_account(data: &[u8]) -> Result<Account, ParseError> {
+    account_view(data).map(Account::from)
+}
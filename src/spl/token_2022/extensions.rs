@@ -0,0 +1,374 @@
+//! Token-2022 extension sub-instructions.
+//!
+//! Each extension has its own prefix byte in [`Token2022Instruction`], followed
+//! by a single-byte sub-instruction index and its payload. The token-metadata
+//! and token-group interfaces are different: they are addressed by an 8-byte
+//! discriminator at the start of the instruction data instead.
+//!
+//! [`Token2022Instruction`]: super::instructions::Token2022Instruction
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::common::bincode::BincodeReader;
+use crate::common::ParseError;
+use crate::spl::token::accounts::AccountState;
+
+/// Reads an `OptionalNonZeroPubkey`: 32 bytes, all zero meaning `None`.
+pub(crate) fn read_optional_nonzero_pubkey(r: &mut BincodeReader) -> Result<Option<Pubkey>, ParseError> {
+    Ok(Some(r.pubkey()?).filter(|key| *key != Pubkey::default()))
+}
+
+fn read_i16(r: &mut BincodeReader) -> Result<i16, ParseError> {
+    Ok(i16::from_le_bytes(r.array()?))
+}
+
+fn read_f64(r: &mut BincodeReader) -> Result<f64, ParseError> {
+    Ok(f64::from_le_bytes(r.array()?))
+}
+
+// -----------------------------------------------------------------------------
+// Transfer fee
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransferFeeInstruction {
+    InitializeTransferFeeConfig {
+        transfer_fee_config_authority: Option<Pubkey>,
+        withdraw_withheld_authority: Option<Pubkey>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    },
+    /// `fee` must match the fee computed by the program for `amount`.
+    TransferCheckedWithFee {
+        amount: u64,
+        decimals: u8,
+        fee: u64,
+    },
+    WithdrawWithheldTokensFromMint,
+    WithdrawWithheldTokensFromAccounts {
+        num_token_accounts: u8,
+    },
+    HarvestWithheldTokensToMint,
+    /// Takes effect two epochs after it is set.
+    SetTransferFee {
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    },
+}
+
+impl TransferFeeInstruction {
+    pub(crate) fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(match r.u8()? {
+            0 => Self::InitializeTransferFeeConfig {
+                transfer_fee_config_authority: r.option(|r| r.pubkey())?,
+                withdraw_withheld_authority: r.option(|r| r.pubkey())?,
+                transfer_fee_basis_points: r.u16()?,
+                maximum_fee: r.u64()?,
+            },
+            1 => Self::TransferCheckedWithFee {
+                amount: r.u64()?,
+                decimals: r.u8()?,
+                fee: r.u64()?,
+            },
+            2 => Self::WithdrawWithheldTokensFromMint,
+            3 => Self::WithdrawWithheldTokensFromAccounts { num_token_accounts: r.u8()? },
+            4 => Self::HarvestWithheldTokensToMint,
+            5 => Self::SetTransferFee {
+                transfer_fee_basis_points: r.u16()?,
+                maximum_fee: r.u64()?,
+            },
+            other => return Err(ParseError::SplUnknown(other)),
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Default account state
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DefaultAccountStateInstruction {
+    Initialize { state: AccountState },
+    Update { state: AccountState },
+}
+
+impl DefaultAccountStateInstruction {
+    pub(crate) fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(match r.u8()? {
+            0 => Self::Initialize {
+                state: AccountState::try_from(r.u8()?)?,
+            },
+            1 => Self::Update {
+                state: AccountState::try_from(r.u8()?)?,
+            },
+            other => return Err(ParseError::SplUnknown(other)),
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Required memo transfers / CPI guard
+// -----------------------------------------------------------------------------
+/// Sub-instructions shared by the memo-transfer and CPI-guard extensions, which
+/// only toggle a flag on the token account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToggleInstruction {
+    Enable,
+    Disable,
+}
+
+impl ToggleInstruction {
+    pub(crate) fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        match r.u8()? {
+            0 => Ok(Self::Enable),
+            1 => Ok(Self::Disable),
+            other => Err(ParseError::SplUnknown(other)),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Interest-bearing mint
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InterestBearingMintInstruction {
+    /// `rate` is in basis points per year.
+    Initialize {
+        rate_authority: Option<Pubkey>,
+        rate: i16,
+    },
+    UpdateRate {
+        rate: i16,
+    },
+}
+
+impl InterestBearingMintInstruction {
+    pub(crate) fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(match r.u8()? {
+            0 => Self::Initialize {
+                rate_authority: read_optional_nonzero_pubkey(r)?,
+                rate: read_i16(r)?,
+            },
+            1 => Self::UpdateRate { rate: read_i16(r)? },
+            other => return Err(ParseError::SplUnknown(other)),
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Transfer hook
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransferHookInstruction {
+    Initialize { authority: Option<Pubkey>, program_id: Option<Pubkey> },
+    Update { program_id: Option<Pubkey> },
+}
+
+impl TransferHookInstruction {
+    pub(crate) fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(match r.u8()? {
+            0 => Self::Initialize {
+                authority: read_optional_nonzero_pubkey(r)?,
+                program_id: read_optional_nonzero_pubkey(r)?,
+            },
+            1 => Self::Update {
+                program_id: read_optional_nonzero_pubkey(r)?,
+            },
+            other => return Err(ParseError::SplUnknown(other)),
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Pointer extensions
+// -----------------------------------------------------------------------------
+/// Sub-instructions shared by the metadata, group and group-member pointer
+/// extensions: each stores an authority and the address it points at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PointerInstruction {
+    Initialize { authority: Option<Pubkey>, address: Option<Pubkey> },
+    Update { address: Option<Pubkey> },
+}
+
+impl PointerInstruction {
+    pub(crate) fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(match r.u8()? {
+            0 => Self::Initialize {
+                authority: read_optional_nonzero_pubkey(r)?,
+                address: read_optional_nonzero_pubkey(r)?,
+            },
+            1 => Self::Update {
+                address: read_optional_nonzero_pubkey(r)?,
+            },
+            other => return Err(ParseError::SplUnknown(other)),
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Scaled UI amount
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScaledUiAmountInstruction {
+    Initialize { authority: Option<Pubkey>, multiplier: f64 },
+    UpdateMultiplier { multiplier: f64, effective_timestamp: i64 },
+}
+
+impl ScaledUiAmountInstruction {
+    pub(crate) fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(match r.u8()? {
+            0 => Self::Initialize {
+                authority: read_optional_nonzero_pubkey(r)?,
+                multiplier: read_f64(r)?,
+            },
+            1 => Self::UpdateMultiplier {
+                multiplier: read_f64(r)?,
+                effective_timestamp: r.i64()?,
+            },
+            other => return Err(ParseError::SplUnknown(other)),
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Pausable
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PausableInstruction {
+    Initialize { authority: Pubkey },
+    Pause,
+    Resume,
+}
+
+impl PausableInstruction {
+    pub(crate) fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(match r.u8()? {
+            0 => Self::Initialize { authority: r.pubkey()? },
+            1 => Self::Pause,
+            2 => Self::Resume,
+            other => return Err(ParseError::SplUnknown(other)),
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Confidential extensions
+// -----------------------------------------------------------------------------
+/// Sub-instruction of a confidential extension. Their payloads are ciphertexts
+/// and zero-knowledge proof references, so only the index is decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawExtensionInstruction {
+    pub instruction: u8,
+    pub data: Vec<u8>,
+}
+
+impl RawExtensionInstruction {
+    pub(crate) fn read(r: &mut BincodeReader) -> Result<Self, ParseError> {
+        Ok(Self {
+            instruction: r.u8()?,
+            data: r.remaining().to_vec(),
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Token metadata interface
+// -----------------------------------------------------------------------------
+pub const TOKEN_METADATA_INITIALIZE: [u8; 8] = [210, 225, 30, 162, 88, 184, 77, 141];
+pub const TOKEN_METADATA_UPDATE_FIELD: [u8; 8] = [221, 233, 49, 45, 181, 202, 220, 200];
+pub const TOKEN_METADATA_REMOVE_KEY: [u8; 8] = [234, 18, 32, 56, 89, 141, 37, 181];
+pub const TOKEN_METADATA_UPDATE_AUTHORITY: [u8; 8] = [215, 228, 166, 228, 84, 100, 86, 123];
+pub const TOKEN_METADATA_EMIT: [u8; 8] = [250, 166, 180, 250, 13, 12, 184, 70];
+
+/// Metadata field targeted by `UpdateField`.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum Field {
+    Name,
+    Symbol,
+    Uri,
+    /// Entry in the `additional_metadata` key/value list.
+    Key(String),
+}
+
+/// `spl-token-metadata-interface` instructions, as implemented by Token-2022
+/// for metadata stored in the mint itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenMetadataInstruction {
+    Initialize { name: String, symbol: String, uri: String },
+    UpdateField { field: Field, value: String },
+    RemoveKey { idempotent: bool, key: String },
+    UpdateAuthority { new_authority: Option<Pubkey> },
+    Emit { start: Option<u64>, end: Option<u64> },
+}
+
+impl TokenMetadataInstruction {
+    /// Returns `Ok(None)` when `data` does not start with a token-metadata discriminator.
+    pub(crate) fn unpack(data: &[u8]) -> Result<Option<Self>, ParseError> {
+        let Some((disc, mut rest)) = data.split_first_chunk::<8>() else {
+            return Ok(None);
+        };
+        let rest = &mut rest;
+        Ok(Some(match *disc {
+            TOKEN_METADATA_INITIALIZE => Self::Initialize {
+                name: String::deserialize(rest)?,
+                symbol: String::deserialize(rest)?,
+                uri: String::deserialize(rest)?,
+            },
+            TOKEN_METADATA_UPDATE_FIELD => Self::UpdateField {
+                field: Field::deserialize(rest)?,
+                value: String::deserialize(rest)?,
+            },
+            TOKEN_METADATA_REMOVE_KEY => Self::RemoveKey {
+                idempotent: bool::deserialize(rest)?,
+                key: String::deserialize(rest)?,
+            },
+            TOKEN_METADATA_UPDATE_AUTHORITY => Self::UpdateAuthority {
+                new_authority: read_optional_nonzero_pubkey(&mut BincodeReader::new(rest))?,
+            },
+            TOKEN_METADATA_EMIT => Self::Emit {
+                start: Option::<u64>::deserialize(rest)?,
+                end: Option::<u64>::deserialize(rest)?,
+            },
+            _ => return Ok(None),
+        }))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Token group interface
+// -----------------------------------------------------------------------------
+pub const TOKEN_GROUP_INITIALIZE_GROUP: [u8; 8] = [121, 113, 108, 39, 54, 51, 0, 4];
+pub const TOKEN_GROUP_UPDATE_GROUP_MAX_SIZE: [u8; 8] = [108, 37, 171, 143, 248, 30, 18, 110];
+pub const TOKEN_GROUP_UPDATE_GROUP_AUTHORITY: [u8; 8] = [161, 105, 88, 1, 237, 221, 216, 203];
+pub const TOKEN_GROUP_INITIALIZE_MEMBER: [u8; 8] = [152, 32, 222, 176, 223, 237, 116, 134];
+
+/// `spl-token-group-interface` instructions, as implemented by Token-2022 for
+/// group and member data stored in the mint itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenGroupInstruction {
+    InitializeGroup { update_authority: Option<Pubkey>, max_size: u64 },
+    UpdateGroupMaxSize { max_size: u64 },
+    UpdateGroupAuthority { new_authority: Option<Pubkey> },
+    InitializeMember,
+}
+
+impl TokenGroupInstruction {
+    /// Returns `Ok(None)` when `data` does not start with a token-group discriminator.
+    pub(crate) fn unpack(data: &[u8]) -> Result<Option<Self>, ParseError> {
+        let Some((disc, rest)) = data.split_first_chunk::<8>() else {
+            return Ok(None);
+        };
+        let r = &mut BincodeReader::new(rest);
+        Ok(Some(match *disc {
+            TOKEN_GROUP_INITIALIZE_GROUP => Self::InitializeGroup {
+                update_authority: read_optional_nonzero_pubkey(r)?,
+                max_size: r.u64()?,
+            },
+            TOKEN_GROUP_UPDATE_GROUP_MAX_SIZE => Self::UpdateGroupMaxSize { max_size: r.u64()? },
+            TOKEN_GROUP_UPDATE_GROUP_AUTHORITY => Self::UpdateGroupAuthority {
+                new_authority: read_optional_nonzero_pubkey(r)?,
+            },
+            TOKEN_GROUP_INITIALIZE_MEMBER => Self::InitializeMember,
+            _ => return Ok(None),
+        }))
+    }
+}
@@ -1,6 +1,13 @@
 //! SPL Token 2022 on-chain instructions.
 
+use crate::common::bincode::{invalid, BincodeReader};
 use crate::common::ParseError;
+use solana_program::pubkey::Pubkey;
+
+use super::extensions::{
+    DefaultAccountStateInstruction, InterestBearingMintInstruction, PausableInstruction, PointerInstruction, RawExtensionInstruction,
+    ScaledUiAmountInstruction, ToggleInstruction, TokenGroupInstruction, TokenMetadataInstruction, TransferFeeInstruction, TransferHookInstruction,
+};
 
 // -----------------------------------------------------------------------------
 // Discriminators (single-byte index)
@@ -47,56 +54,168 @@ pub const WITHDRAWAL_EXCESS_LAMPORTS: u8 = 38;
 pub const METADATA_POINTER_EXTENSION: u8 = 39;
 pub const GROUP_POINTER_EXTENSION: u8 = 40;
 pub const GROUP_MEMBER_POINTER_EXTENSION: u8 = 41;
-pub const TOKEN_METADATA_EXTENSION: u8 = 42;
+pub const CONFIDENTIAL_MINT_BURN_EXTENSION: u8 = 42;
+pub const SCALED_UI_AMOUNT_EXTENSION: u8 = 43;
+pub const PAUSABLE_EXTENSION: u8 = 44;
+
+// -----------------------------------------------------------------------------
+// Authority types (`SetAuthority`)
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthorityType {
+    MintTokens,
+    FreezeAccount,
+    AccountOwner,
+    CloseAccount,
+    TransferFeeConfig,
+    WithheldWithdraw,
+    CloseMint,
+    InterestRate,
+    PermanentDelegate,
+    ConfidentialTransferMint,
+    TransferHookProgramId,
+    ConfidentialTransferFeeConfig,
+    MetadataPointer,
+    GroupPointer,
+    GroupMemberPointer,
+    ScaledUiAmount,
+    Pause,
+}
+
+impl TryFrom<u8> for AuthorityType {
+    type Error = ParseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::MintTokens,
+            1 => Self::FreezeAccount,
+            2 => Self::AccountOwner,
+            3 => Self::CloseAccount,
+            4 => Self::TransferFeeConfig,
+            5 => Self::WithheldWithdraw,
+            6 => Self::CloseMint,
+            7 => Self::InterestRate,
+            8 => Self::PermanentDelegate,
+            9 => Self::ConfidentialTransferMint,
+            10 => Self::TransferHookProgramId,
+            11 => Self::ConfidentialTransferFeeConfig,
+            12 => Self::MetadataPointer,
+            13 => Self::GroupPointer,
+            14 => Self::GroupMemberPointer,
+            15 => Self::ScaledUiAmount,
+            16 => Self::Pause,
+            other => return Err(invalid(format!("invalid AuthorityType {other}"))),
+        })
+    }
+}
 
 // -----------------------------------------------------------------------------
 // Instruction enumeration
 // -----------------------------------------------------------------------------
 #[derive(Debug, Clone, PartialEq)]
 pub enum Token2022Instruction {
-    InitializeMint,
+    InitializeMint {
+        decimals: u8,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+    },
     InitializeAccount,
-    InitializeMultisig,
-    Transfer { amount: u64 },
-    Approve { amount: u64 },
+    InitializeMultisig {
+        m: u8,
+    },
+    Transfer {
+        amount: u64,
+    },
+    Approve {
+        amount: u64,
+    },
     Revoke,
-    SetAuthority,
-    MintTo { amount: u64 },
-    Burn { amount: u64 },
+    SetAuthority {
+        authority_type: AuthorityType,
+        new_authority: Option<Pubkey>,
+    },
+    MintTo {
+        amount: u64,
+    },
+    Burn {
+        amount: u64,
+    },
     CloseAccount,
     FreezeAccount,
     ThawAccount,
-    TransferChecked { amount: u64, decimals: u8 },
-    ApproveChecked { amount: u64, decimals: u8 },
-    MintToChecked { amount: u64, decimals: u8 },
-    BurnChecked { amount: u64, decimals: u8 },
-    InitializeAccount2,
+    TransferChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    ApproveChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    MintToChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    BurnChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    InitializeAccount2 {
+        owner: Pubkey,
+    },
     SyncNative,
-    InitializeAccount3,
-    InitializeMultisig2,
-    InitializeMint2,
-    GetAccountDataSize,
+    InitializeAccount3 {
+        owner: Pubkey,
+    },
+    InitializeMultisig2 {
+        m: u8,
+    },
+    InitializeMint2 {
+        decimals: u8,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+    },
+    /// `extension_types` are raw `ExtensionType` values.
+    GetAccountDataSize {
+        extension_types: Vec<u16>,
+    },
     InitializeImmutableOwner,
-    AmountToUiAmount,
-    UiAmountToAmount,
-    InitializeMintCloseAuthority,
-    TransferFeeExtension,
-    ConfidentialTransferExtension,
-    DefaultAccountStateExtension,
-    Reallocate,
-    MemoTransferExtension,
+    AmountToUiAmount {
+        amount: u64,
+    },
+    UiAmountToAmount {
+        ui_amount: String,
+    },
+    InitializeMintCloseAuthority {
+        close_authority: Option<Pubkey>,
+    },
+    TransferFeeExtension(TransferFeeInstruction),
+    ConfidentialTransferExtension(RawExtensionInstruction),
+    DefaultAccountStateExtension(DefaultAccountStateInstruction),
+    /// `extension_types` are raw `ExtensionType` values.
+    Reallocate {
+        extension_types: Vec<u16>,
+    },
+    MemoTransferExtension(ToggleInstruction),
     CreateNativeMint,
     InitializeNonTransferableMint,
-    InterestBearingMintExtension,
-    CpiGuardExtension,
-    InitializePermanentDelegate,
-    TransferHookExtension,
-    ConfidentialTransferFeeExtension,
+    InterestBearingMintExtension(InterestBearingMintInstruction),
+    CpiGuardExtension(ToggleInstruction),
+    InitializePermanentDelegate {
+        delegate: Pubkey,
+    },
+    TransferHookExtension(TransferHookInstruction),
+    ConfidentialTransferFeeExtension(RawExtensionInstruction),
     WithdrawalExcessLamports,
-    MetadataPointerExtension,
-    GroupPointerExtension,
-    GroupMemberPointerExtension,
-    TokenMetadataExtension,
+    MetadataPointerExtension(PointerInstruction),
+    GroupPointerExtension(PointerInstruction),
+    GroupMemberPointerExtension(PointerInstruction),
+    ConfidentialMintBurnExtension(RawExtensionInstruction),
+    ScaledUiAmountExtension(ScaledUiAmountInstruction),
+    PausableExtension(PausableInstruction),
+    /// `spl-token-metadata-interface` instruction (8-byte discriminator).
+    TokenMetadataExtension(TokenMetadataInstruction),
+    /// `spl-token-group-interface` instruction (8-byte discriminator).
+    TokenGroupExtension(TokenGroupInstruction),
 }
 
 // -----------------------------------------------------------------------------
@@ -106,14 +225,27 @@ impl TryFrom<&[u8]> for Token2022Instruction {
     type Error = ParseError;
 
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        if data.is_empty() {
-            return Err(ParseError::TooShort(data.len()));
+        // The program tries the native tag first and only then the interface
+        // discriminators. No interface discriminator starts with a valid
+        // native tag, so matching the interfaces first gives the same result.
+        if let Some(ix) = TokenMetadataInstruction::unpack(data)? {
+            return Ok(Self::TokenMetadataExtension(ix));
+        }
+        if let Some(ix) = TokenGroupInstruction::unpack(data)? {
+            return Ok(Self::TokenGroupExtension(ix));
         }
-        let rest = &data[1..];
-        match data[0] {
-            INITIALIZE_MINT => Ok(Self::InitializeMint),
+
+        let mut r = BincodeReader::new(data);
+        let disc = r.u8()?;
+        let rest = r.remaining();
+        match disc {
+            INITIALIZE_MINT => Ok(Self::InitializeMint {
+                decimals: r.u8()?,
+                mint_authority: r.pubkey()?,
+                freeze_authority: r.option(|r| r.pubkey())?,
+            }),
             INITIALIZE_ACCOUNT => Ok(Self::InitializeAccount),
-            INITIALIZE_MULTISIG => Ok(Self::InitializeMultisig),
+            INITIALIZE_MULTISIG => Ok(Self::InitializeMultisig { m: r.u8()? }),
             TRANSFER => {
                 if rest.len() < 8 {
                     return Err(ParseError::TooShort(data.len()));
@@ -131,7 +263,10 @@ impl TryFrom<&[u8]> for Token2022Instruction {
                 })
             }
             REVOKE => Ok(Self::Revoke),
-            SET_AUTHORITY => Ok(Self::SetAuthority),
+            SET_AUTHORITY => Ok(Self::SetAuthority {
+                authority_type: AuthorityType::try_from(r.u8()?)?,
+                new_authority: r.option(|r| r.pubkey())?,
+            }),
             MINT_TO => {
                 if rest.len() < 8 {
                     return Err(ParseError::TooShort(data.len()));
@@ -187,38 +322,60 @@ impl TryFrom<&[u8]> for Token2022Instruction {
                     decimals: rest[8],
                 })
             }
-            INITIALIZE_ACCOUNT2 => Ok(Self::InitializeAccount2),
+            INITIALIZE_ACCOUNT2 => Ok(Self::InitializeAccount2 { owner: r.pubkey()? }),
             SYNC_NATIVE => Ok(Self::SyncNative),
-            INITIALIZE_ACCOUNT3 => Ok(Self::InitializeAccount3),
-            INITIALIZE_MULTISIG2 => Ok(Self::InitializeMultisig2),
-            INITIALIZE_MINT2 => Ok(Self::InitializeMint2),
-            GET_ACCOUNT_DATA_SIZE => Ok(Self::GetAccountDataSize),
+            INITIALIZE_ACCOUNT3 => Ok(Self::InitializeAccount3 { owner: r.pubkey()? }),
+            INITIALIZE_MULTISIG2 => Ok(Self::InitializeMultisig2 { m: r.u8()? }),
+            INITIALIZE_MINT2 => Ok(Self::InitializeMint2 {
+                decimals: r.u8()?,
+                mint_authority: r.pubkey()?,
+                freeze_authority: r.option(|r| r.pubkey())?,
+            }),
+            GET_ACCOUNT_DATA_SIZE => Ok(Self::GetAccountDataSize {
+                extension_types: unpack_extension_types(rest, data)?,
+            }),
             INITIALIZE_IMMUTABLE_OWNER => Ok(Self::InitializeImmutableOwner),
-            AMOUNT_TO_UI_AMOUNT => Ok(Self::AmountToUiAmount),
-            UI_AMOUNT_TO_AMOUNT => Ok(Self::UiAmountToAmount),
-            INITIALIZE_MINT_CLOSE_AUTHORITY => Ok(Self::InitializeMintCloseAuthority),
-            TRANSFER_FEE_EXTENSION => Ok(Self::TransferFeeExtension),
-            CONFIDENTIAL_TRANSFER_EXTENSION => Ok(Self::ConfidentialTransferExtension),
-            DEFAULT_ACCOUNT_STATE_EXTENSION => Ok(Self::DefaultAccountStateExtension),
-            REALLOCATE => Ok(Self::Reallocate),
-            MEMO_TRANSFER_EXTENSION => Ok(Self::MemoTransferExtension),
+            AMOUNT_TO_UI_AMOUNT => Ok(Self::AmountToUiAmount { amount: r.u64()? }),
+            UI_AMOUNT_TO_AMOUNT => Ok(Self::UiAmountToAmount {
+                ui_amount: String::from_utf8(rest.to_vec()).map_err(|e| invalid(e.to_string()))?,
+            }),
+            INITIALIZE_MINT_CLOSE_AUTHORITY => Ok(Self::InitializeMintCloseAuthority {
+                close_authority: r.option(|r| r.pubkey())?,
+            }),
+            TRANSFER_FEE_EXTENSION => Ok(Self::TransferFeeExtension(TransferFeeInstruction::read(&mut r)?)),
+            CONFIDENTIAL_TRANSFER_EXTENSION => Ok(Self::ConfidentialTransferExtension(RawExtensionInstruction::read(&mut r)?)),
+            DEFAULT_ACCOUNT_STATE_EXTENSION => Ok(Self::DefaultAccountStateExtension(DefaultAccountStateInstruction::read(&mut r)?)),
+            REALLOCATE => Ok(Self::Reallocate {
+                extension_types: unpack_extension_types(rest, data)?,
+            }),
+            MEMO_TRANSFER_EXTENSION => Ok(Self::MemoTransferExtension(ToggleInstruction::read(&mut r)?)),
             CREATE_NATIVE_MINT => Ok(Self::CreateNativeMint),
             INITIALIZE_NON_TRANSFERABLE_MINT => Ok(Self::InitializeNonTransferableMint),
-            INTEREST_BEARING_MINT_EXTENSION => Ok(Self::InterestBearingMintExtension),
-            CPI_GUARD_EXTENSION => Ok(Self::CpiGuardExtension),
-            INITIALIZE_PERMANENT_DELEGATE => Ok(Self::InitializePermanentDelegate),
-            TRANSFER_HOOK_EXTENSION => Ok(Self::TransferHookExtension),
-            CONFIDENTIAL_TRANSFER_FEE_EXTENSION => Ok(Self::ConfidentialTransferFeeExtension),
+            INTEREST_BEARING_MINT_EXTENSION => Ok(Self::InterestBearingMintExtension(InterestBearingMintInstruction::read(&mut r)?)),
+            CPI_GUARD_EXTENSION => Ok(Self::CpiGuardExtension(ToggleInstruction::read(&mut r)?)),
+            INITIALIZE_PERMANENT_DELEGATE => Ok(Self::InitializePermanentDelegate { delegate: r.pubkey()? }),
+            TRANSFER_HOOK_EXTENSION => Ok(Self::TransferHookExtension(TransferHookInstruction::read(&mut r)?)),
+            CONFIDENTIAL_TRANSFER_FEE_EXTENSION => Ok(Self::ConfidentialTransferFeeExtension(RawExtensionInstruction::read(&mut r)?)),
             WITHDRAWAL_EXCESS_LAMPORTS => Ok(Self::WithdrawalExcessLamports),
-            METADATA_POINTER_EXTENSION => Ok(Self::MetadataPointerExtension),
-            GROUP_POINTER_EXTENSION => Ok(Self::GroupPointerExtension),
-            GROUP_MEMBER_POINTER_EXTENSION => Ok(Self::GroupMemberPointerExtension),
-            TOKEN_METADATA_EXTENSION => Ok(Self::TokenMetadataExtension),
+            METADATA_POINTER_EXTENSION => Ok(Self::MetadataPointerExtension(PointerInstruction::read(&mut r)?)),
+            GROUP_POINTER_EXTENSION => Ok(Self::GroupPointerExtension(PointerInstruction::read(&mut r)?)),
+            GROUP_MEMBER_POINTER_EXTENSION => Ok(Self::GroupMemberPointerExtension(PointerInstruction::read(&mut r)?)),
+            CONFIDENTIAL_MINT_BURN_EXTENSION => Ok(Self::ConfidentialMintBurnExtension(RawExtensionInstruction::read(&mut r)?)),
+            SCALED_UI_AMOUNT_EXTENSION => Ok(Self::ScaledUiAmountExtension(ScaledUiAmountInstruction::read(&mut r)?)),
+            PAUSABLE_EXTENSION => Ok(Self::PausableExtension(PausableInstruction::read(&mut r)?)),
             other => Err(ParseError::SplUnknown(other)),
         }
     }
 }
 
+/// Packed list of little-endian `u16` extension types filling the rest of the payload.
+fn unpack_extension_types(rest: &[u8], data: &[u8]) -> Result<Vec<u16>, ParseError> {
+    if rest.len() % 2 != 0 {
+        return Err(ParseError::TooShort(data.len()));
+    }
+    Ok(rest.chunks_exact(2).map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]])).collect())
+}
+
 pub fn unpack(data: &[u8]) -> Result<Token2022Instruction, ParseError> {
     Token2022Instruction::try_from(data)
 }
@@ -1,5 +1,6 @@
 use substreams_solana::b58;
 pub mod accounts;
+pub mod extensions;
 pub mod instructions;
 
 /// SPL Token 2022 Program
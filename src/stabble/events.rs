@@ -50,24 +50,10 @@ pub struct PoolUpdatedData {
 // -----------------------------------------------------------------------------
 // Borsh deserialisation helper
 // -----------------------------------------------------------------------------
-impl<'a> TryFrom<&'a [u8]> for StabbleEvent {
-    type Error = ParseError;
-
-    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() < 8 {
-            return Err(ParseError::TooShort(data.len()));
-        }
-
-        let (disc, payload) = data.split_at(8);
-        let discriminator: [u8; 8] = disc.try_into().expect("slice len 8");
-
-        Ok(match discriminator {
-            POOL_BALANCE_UPDATED_EVENT => Self::PoolBalanceUpdatedEvent(PoolBalanceUpdatedEvent::try_from_slice(payload)?),
-            POOL_UPDATED_EVENT => Self::PoolUpdatedEvent(PoolUpdatedEvent::try_from_slice(payload)?),
-            other => return Err(ParseError::Unknown(other)),
-        })
-    }
-}
+anchor_events!(StabbleEvent {
+    POOL_BALANCE_UPDATED_EVENT => PoolBalanceUpdatedEvent(PoolBalanceUpdatedEvent),
+    POOL_UPDATED_EVENT => PoolUpdatedEvent(PoolUpdatedEvent),
+});
 
 /// Convenience wrapper that forwards to `TryFrom`.
 pub fn unpack(data: &[u8]) -> Result<StabbleEvent, ParseError> {
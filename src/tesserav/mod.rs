@@ -1 +1,8 @@
+use substreams_solana::b58;
+
 pub mod instructions;
+
+/// Tessera V program
+///
+/// https://solscan.io/account/TessVdML9pBGgG9yGks7o4HewRaXVAMuoVj4x83GLQH
+pub const PROGRAM_ID: [u8; 32] = b58!("TessVdML9pBGgG9yGks7o4HewRaXVAMuoVj4x83GLQH");
@@ -1,4 +1,31 @@
-use substreams_solana_idls::aldrin::instructions;
+use substreams_solana_idls::aldrin::instructions::{self, *};
+
+#[test]
+fn parse_new_order_v3() {
+    let mut data = NEW_ORDER_V3.to_vec();
+    data.push(0); // side: Bid (Borsh u8 tag)
+    data.extend_from_slice(&1_500u64.to_le_bytes());
+    data.extend_from_slice(&10u64.to_le_bytes());
+    data.extend_from_slice(&15_045u64.to_le_bytes());
+    data.push(1); // self_trade_behavior: CancelProvide
+    data.push(0); // order_type: Limit
+    data.extend_from_slice(&42u64.to_le_bytes());
+    data.extend_from_slice(&65_535u16.to_le_bytes());
+    let parsed = instructions::unpack(&data).unwrap();
+    assert_eq!(
+        parsed,
+        AldrinInstruction::NewOrderV3(NewOrderInstructionV3 {
+            side: Side::Bid,
+            limit_price: 1_500,
+            max_coin_qty: 10,
+            max_native_pc_qty_including_fees: 15_045,
+            self_trade_behavior: SelfTradeBehavior::CancelProvide,
+            order_type: OrderType::Limit,
+            client_order_id: 42,
+            limit: 65_535,
+        })
+    );
+}
 
 #[test]
 fn unknown_discriminator() {
@@ -1,6 +1,46 @@
+use substreams::hex;
+use substreams_solana_idls::common::anchor::{is_event_cpi, split_event, EVENT_IX_TAG};
 use substreams_solana_idls::common::ParseError;
+use substreams_solana_idls::jupiter::v6::events::{self as v6_ev, JupiterV6Event};
 
 #[test]
 fn too_short_display_is_generic() {
     assert_eq!(ParseError::TooShort(5).to_string(), "payload too short: got 5 bytes");
 }
+
+#[test]
+fn split_event_strips_cpi_envelope() {
+    let mut data = EVENT_IX_TAG.to_vec();
+    data.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    assert!(is_event_cpi(&data));
+    let (disc, payload) = split_event(&data).unwrap();
+    assert_eq!(disc, [1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(payload, &[9, 10]);
+
+    // Log form: the discriminator comes first.
+    assert!(!is_event_cpi(&data[8..]));
+    assert_eq!(split_event(&data[8..]).unwrap(), (disc, &[9u8, 10][..]));
+}
+
+#[test]
+fn split_event_too_short() {
+    assert!(matches!(split_event(&[0u8; 7]), Err(ParseError::TooShort(7))));
+    // A bare envelope tag is read as a log-form discriminator.
+    assert_eq!(split_event(&EVENT_IX_TAG).unwrap(), (EVENT_IX_TAG, &[][..]));
+}
+
+#[test]
+fn event_decodes_in_both_forms() {
+    // https://solscan.io/tx/3tNX6MzVEnsDCJWSnM5xqBQNWXh22T3q2PkF1hys35P9kpxVuut6chgPbnFs5Cyp9ygU9EidQgvqbuak26u1bmQM
+    let cpi = hex!("e445a52e51cb9a1d494f4e7fb8d50ddc71337d91df2be75ff1c1ce88c1f5f0293ce7ee4562f6ee3e045fa07faad311dd069b8857feab8184fb687f634618c035dac439dc1aeb3b5598a0f0000000000108b5000000000000");
+    let from_cpi = v6_ev::unpack(&cpi).unwrap();
+    assert!(matches!(&from_cpi, JupiterV6Event::Fee(event) if event.amount == 46344));
+    assert_eq!(v6_ev::unpack(&cpi[8..]).unwrap(), from_cpi);
+}
+
+#[test]
+fn event_unknown_discriminator() {
+    let mut data = EVENT_IX_TAG.to_vec();
+    data.extend_from_slice(&[7u8; 8]);
+    assert!(matches!(v6_ev::unpack(&data), Err(ParseError::Unknown(disc)) if disc == [7u8; 8]));
+}
@@ -1,3 +1,4 @@
+use solana_program::pubkey::Pubkey;
 use substreams_solana_idls::drift::v2::events::{self, DriftEvent};
 use substreams_solana_idls::drift::v2::instructions;
 
@@ -14,3 +15,166 @@ fn event_unknown_returns_variant() {
     // drift events return Ok(Unknown)
     assert!(matches!(events::unpack(&[0u8; 24]).unwrap(), DriftEvent::Unknown));
 }
+
+fn order_params(market_type: instructions::MarketType) -> instructions::OrderParams {
+    instructions::OrderParams {
+        order_type: instructions::OrderType::Limit,
+        market_type,
+        direction: instructions::PositionDirection::Short,
+        user_order_id: 3,
+        base_asset_amount: 1_000_000_000,
+        price: 150_000_000,
+        market_index: 0,
+        reduce_only: false,
+        post_only: instructions::PostOnlyParam::MustPostOnly,
+        bit_flags: 0,
+        max_ts: Some(1_757_867_955),
+        trigger_price: None,
+        trigger_condition: instructions::OrderTriggerCondition::Above,
+        oracle_price_offset: Some(-25_000),
+        auction_duration: None,
+        auction_start_price: None,
+        auction_end_price: None,
+    }
+}
+
+#[test]
+fn decode_place_perp_order() {
+    let params = order_params(instructions::MarketType::Perp);
+    let mut data = instructions::PLACE_PERP_ORDER.to_vec();
+    data.extend(borsh::to_vec(&params).unwrap());
+    match instructions::unpack(&data).unwrap() {
+        instructions::DriftInstruction::PlacePerpOrder(ix) => assert_eq!(ix.params, params),
+        other => panic!("expected PlacePerpOrder, got {:?}", other),
+    }
+}
+
+#[test]
+fn decode_place_orders() {
+    let params = vec![order_params(instructions::MarketType::Perp), order_params(instructions::MarketType::Spot)];
+    let mut data = instructions::PLACE_ORDERS.to_vec();
+    data.extend(borsh::to_vec(&params).unwrap());
+    match instructions::unpack(&data).unwrap() {
+        instructions::DriftInstruction::PlaceOrders(ix) => assert_eq!(ix.params, params),
+        other => panic!("expected PlaceOrders, got {:?}", other),
+    }
+}
+
+#[test]
+fn decode_deposit() {
+    let mut data = instructions::DEPOSIT.to_vec();
+    data.extend_from_slice(&1u16.to_le_bytes());
+    data.extend_from_slice(&5_000_000u64.to_le_bytes());
+    data.push(0);
+    match instructions::unpack(&data).unwrap() {
+        instructions::DriftInstruction::Deposit(ix) => {
+            assert_eq!(ix.market_index, 1);
+            assert_eq!(ix.amount, 5_000_000);
+            assert!(!ix.reduce_only);
+        }
+        other => panic!("expected Deposit, got {:?}", other),
+    }
+}
+
+#[test]
+fn decode_fill_perp_order() {
+    let data = [instructions::FILL_PERP_ORDER.as_slice(), &[1, 7, 0, 0, 0, 0]].concat();
+    match instructions::unpack(&data).unwrap() {
+        instructions::DriftInstruction::FillPerpOrder(ix) => {
+            assert_eq!(ix.order_id, Some(7));
+            assert_eq!(ix.maker_order_id, None);
+        }
+        other => panic!("expected FillPerpOrder, got {:?}", other),
+    }
+}
+
+#[test]
+fn decode_liquidate_perp() {
+    let mut data = instructions::LIQUIDATE_PERP.to_vec();
+    data.extend_from_slice(&2u16.to_le_bytes());
+    data.extend_from_slice(&u64::MAX.to_le_bytes());
+    data.extend_from_slice(&[1]);
+    data.extend_from_slice(&99u64.to_le_bytes());
+    match instructions::unpack(&data).unwrap() {
+        instructions::DriftInstruction::LiquidatePerp(ix) => {
+            assert_eq!(ix.market_index, 2);
+            assert_eq!(ix.liquidator_max_base_asset_amount, u64::MAX);
+            assert_eq!(ix.limit_price, Some(99));
+        }
+        other => panic!("expected LiquidatePerp, got {:?}", other),
+    }
+}
+
+#[test]
+fn decode_order_action_record() {
+    let record = events::OrderActionRecord {
+        ts: 1_757_867_955,
+        action: events::OrderAction::Fill,
+        action_explanation: events::OrderActionExplanation::OrderFilledWithMatch,
+        market_index: 0,
+        market_type: events::MarketType::Perp,
+        filler: Some(Pubkey::new_from_array([1; 32])),
+        filler_reward: Some(10),
+        fill_record_id: Some(42),
+        base_asset_amount_filled: Some(1_000_000_000),
+        quote_asset_amount_filled: Some(150_000_000),
+        taker_fee: Some(52_500),
+        maker_fee: Some(-3_000),
+        referrer_reward: None,
+        quote_asset_amount_surplus: None,
+        spot_fulfillment_method_fee: None,
+        taker: Some(Pubkey::new_from_array([2; 32])),
+        taker_order_id: Some(11),
+        taker_order_direction: Some(events::PositionDirection::Long),
+        taker_order_base_asset_amount: Some(1_000_000_000),
+        taker_order_cumulative_base_asset_amount_filled: Some(1_000_000_000),
+        taker_order_cumulative_quote_asset_amount_filled: Some(150_000_000),
+        maker: Some(Pubkey::new_from_array([3; 32])),
+        maker_order_id: Some(12),
+        maker_order_direction: Some(events::PositionDirection::Short),
+        maker_order_base_asset_amount: Some(2_000_000_000),
+        maker_order_cumulative_base_asset_amount_filled: Some(1_000_000_000),
+        maker_order_cumulative_quote_asset_amount_filled: Some(150_000_000),
+        oracle_price: 149_900_000,
+        bit_flags: 0,
+        taker_existing_quote_entry_amount: None,
+        taker_existing_base_asset_amount: None,
+        maker_existing_quote_entry_amount: None,
+        maker_existing_base_asset_amount: None,
+        trigger_price: None,
+    };
+    let mut data = events::ORDER_ACTION_RECORD.to_vec();
+    data.extend(borsh::to_vec(&record).unwrap());
+    match events::unpack(&data).unwrap() {
+        DriftEvent::OrderActionRecord(event) => assert_eq!(*event, record),
+        other => panic!("expected OrderActionRecord, got {:?}", other),
+    }
+}
+
+#[test]
+fn decode_deposit_record() {
+    let record = events::DepositRecord {
+        ts: 1_757_867_955,
+        user_authority: Pubkey::new_from_array([1; 32]),
+        user: Pubkey::new_from_array([2; 32]),
+        direction: events::DepositDirection::Withdraw,
+        deposit_record_id: 7,
+        amount: 5_000_000,
+        market_index: 0,
+        oracle_price: 1_000_000,
+        market_deposit_balance: 1,
+        market_withdraw_balance: 2,
+        market_cumulative_deposit_interest: 3,
+        market_cumulative_borrow_interest: 4,
+        total_deposits_after: 5,
+        total_withdraws_after: 6,
+        explanation: events::DepositExplanation::Borrow,
+        transfer_user: None,
+    };
+    let mut data = events::DEPOSIT_RECORD.to_vec();
+    data.extend(borsh::to_vec(&record).unwrap());
+    match events::unpack(&data).unwrap() {
+        DriftEvent::DepositRecord(event) => assert_eq!(event, record),
+        other => panic!("expected DepositRecord, got {:?}", other),
+    }
+}
@@ -1,3 +1,4 @@
+use substreams::hex;
 use substreams_solana_idls::jupiter::dca::instructions as dca_ix;
 use substreams_solana_idls::jupiter::limit_order::instructions as lo_ix;
 use substreams_solana_idls::jupiter::v4::instructions as v4_ix;
@@ -42,3 +43,55 @@ fn limit_order_unknown_discriminator() {
 fn limit_order_too_short() {
     assert!(lo_ix::unpack(&[0u8; 4]).is_err());
 }
+
+#[test]
+fn v6_route_plan() {
+    // route: Whirlpool (a->b) then Meteora DLMM, 1 SOL in, 150 USDC quoted, 50 bps slippage
+    let bytes = hex!("e517cb977ae3ad2a0200000011016400012664010200ca9a3b0000000080d1f00800000000320000");
+
+    match v6_ix::unpack(&bytes).expect("decode route") {
+        v6_ix::JupiterV6Instruction::Route(ix) => {
+            assert_eq!(
+                ix.route_plan,
+                vec![
+                    v6_ix::RoutePlanStep {
+                        swap: v6_ix::Swap::Whirlpool { a_to_b: true },
+                        percent: 100,
+                        input_index: 0,
+                        output_index: 1,
+                    },
+                    v6_ix::RoutePlanStep {
+                        swap: v6_ix::Swap::MeteoraDlmm,
+                        percent: 100,
+                        input_index: 1,
+                        output_index: 2,
+                    },
+                ]
+            );
+            assert_eq!(ix.in_amount, 1_000_000_000);
+            assert_eq!(ix.quoted_out_amount, 150_000_000);
+            assert_eq!(ix.slippage_bps, 50);
+            assert_eq!(ix.platform_fee_bps, 0);
+        }
+        other => panic!("expected Route, got {other:?}"),
+    }
+}
+
+#[test]
+fn v6_shared_accounts_route_plan() {
+    // shared_accounts_route id=3: single SolFiV2 (quote->base) hop
+    let bytes = hex!("c1209b3341d69c8103010000005f0164000140420f0000000000e80300000000000064000a");
+
+    match v6_ix::unpack(&bytes).expect("decode shared_accounts_route") {
+        v6_ix::JupiterV6Instruction::SharedAccountsRoute(ix) => {
+            assert_eq!(ix.id, 3);
+            assert_eq!(ix.route_plan.len(), 1);
+            assert_eq!(ix.route_plan[0].swap, v6_ix::Swap::SolFiV2 { is_quote_to_base: true });
+            assert_eq!(ix.in_amount, 1_000_000);
+            assert_eq!(ix.quoted_out_amount, 1_000);
+            assert_eq!(ix.slippage_bps, 100);
+            assert_eq!(ix.platform_fee_bps, 10);
+        }
+        other => panic!("expected SharedAccountsRoute, got {other:?}"),
+    }
+}
@@ -20,3 +20,74 @@ fn m3_unknown_discriminator() {
 fn m3_too_short() {
     assert!(m3_ix::unpack(&[0u8; 4]).is_err());
 }
+
+mod m3_accounts {
+    use borsh::to_vec;
+    use solana_program::pubkey::Pubkey;
+    use substreams_solana_idls::magiceden::m3::accounts::{self, Allowlist, CurveType, MmmAccount, Pool, SellState};
+
+    fn pool() -> Pool {
+        Pool {
+            spot_price: 1_500_000_000,
+            curve_type: 1,
+            curve_delta: 250,
+            reinvest_fulfill_buy: true,
+            reinvest_fulfill_sell: false,
+            expiry: 0,
+            lp_fee_bp: 150,
+            referral: Pubkey::new_from_array([1; 32]),
+            referral_bp: 0,
+            buyside_creator_royalty_bp: 10_000,
+            cosigner_annotation: [0; 32],
+            sellside_asset_amount: 3,
+            lp_fee_earned: 42,
+            owner: Pubkey::new_from_array([2; 32]),
+            cosigner: Pubkey::default(),
+            uuid: Pubkey::new_from_array([3; 32]),
+            payment_mint: Pubkey::default(),
+            allowlists: std::array::from_fn(|i| Allowlist {
+                kind: (i == 0) as u8,
+                value: Pubkey::new_from_array([4; 32]),
+            }),
+            buyside_payment_amount: 5_000_000_000,
+            shared_escrow_account: Pubkey::default(),
+            shared_escrow_count: 0,
+        }
+    }
+
+    #[test]
+    fn decode_pool_with_reserved_space() {
+        let mut data = accounts::POOL_ACCOUNT.to_vec();
+        data.extend(to_vec(&pool()).unwrap());
+        data.extend_from_slice(&[0; 200]);
+        match accounts::unpack(&data).unwrap() {
+            MmmAccount::Pool(decoded) => {
+                assert_eq!(*decoded, pool());
+                assert_eq!(decoded.curve().unwrap(), CurveType::Exponential);
+            }
+            other => panic!("expected Pool, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn decode_sell_state() {
+        let state = SellState {
+            pool: Pubkey::new_from_array([1; 32]),
+            pool_owner: Pubkey::new_from_array([2; 32]),
+            asset_mint: Pubkey::new_from_array([3; 32]),
+            asset_amount: 1,
+            cosigner_annotation: [9; 32],
+        };
+        let mut data = accounts::SELL_STATE_ACCOUNT.to_vec();
+        data.extend(to_vec(&state).unwrap());
+        assert_eq!(accounts::unpack(&data).unwrap(), MmmAccount::SellState(state));
+    }
+
+    #[test]
+    fn reject_unknown_curve_and_account() {
+        let mut pool = pool();
+        pool.curve_type = 7;
+        assert!(pool.curve().is_err());
+        assert!(accounts::unpack(&[0; 16]).is_err());
+    }
+}
@@ -1,6 +1,10 @@
+#[path = "support/anchor_account.rs"]
+mod anchor_account;
 #[path = "meteora/instructions.rs"]
 mod meteora_instructions;
 #[path = "meteora/meteora_daam.rs"]
 mod meteora_meteora_daam;
 #[path = "meteora/meteora_dlmm.rs"]
 mod meteora_meteora_dlmm;
+#[path = "meteora/state.rs"]
+mod meteora_state;
@@ -1,8 +1,10 @@
 #![cfg(test)]
 #![allow(deprecated)]
 mod tests {
+    use solana_program::pubkey::Pubkey;
     use substreams::hex;
     use substreams_solana_idls::meteora::dlmm;
+    use substreams_solana_idls::meteora::dlmm::anchor_cpi_event::{AddLiquidityEvent, GoToABinEvent, MeteoraDlmmAnchorCpiEvent};
 
     #[test]
     fn unpack_dlmm_swap_event() {
@@ -25,4 +27,31 @@ mod tests {
             _ => panic!("Expected Swap event"),
         }
     }
+
+    #[test]
+    fn unpack_dlmm_add_liquidity_event() {
+        let event = AddLiquidityEvent {
+            lb_pair: Pubkey::new_unique(),
+            from: Pubkey::new_unique(),
+            position: Pubkey::new_unique(),
+            amounts: [1_000, 2_000],
+            active_bin_id: -42,
+        };
+        // Log form: discriminator followed by the payload.
+        let mut data = hex!("1f5e7d5ae3343dba").to_vec();
+        data.extend(borsh::to_vec(&event).unwrap());
+        assert_eq!(dlmm::anchor_cpi_event::unpack(&data).unwrap(), MeteoraDlmmAnchorCpiEvent::AddLiquidity(event));
+    }
+
+    #[test]
+    fn unpack_dlmm_go_to_a_bin_event() {
+        let event = GoToABinEvent {
+            lb_pair: Pubkey::new_unique(),
+            from_bin_id: 10,
+            to_bin_id: -10,
+        };
+        let mut data = hex!("e445a52e51cb9a1d3b8a4c448a83b043").to_vec();
+        data.extend(borsh::to_vec(&event).unwrap());
+        assert_eq!(dlmm::anchor_cpi_event::unpack(&data).unwrap(), MeteoraDlmmAnchorCpiEvent::GoToABin(event));
+    }
 }
@@ -0,0 +1,129 @@
+use borsh::to_vec;
+use substreams_solana_idls::meteora::dlmm::state::{self, BinArray, LbPair, MeteoraDlmmAccount, Observation, MAX_BIN_PER_ARRAY};
+
+use crate::anchor_account::{account, encoded_len, zeroed};
+
+fn lb_pair() -> LbPair {
+    match state::unpack(&zeroed(state::LB_PAIR, 904)).unwrap() {
+        MeteoraDlmmAccount::LbPair(pair) => *pair,
+        other => panic!("unexpected account {other:?}"),
+    }
+}
+
+fn bin_array() -> BinArray {
+    match state::unpack(&zeroed(state::BIN_ARRAY, 10136)).unwrap() {
+        MeteoraDlmmAccount::BinArray(array) => *array,
+        other => panic!("unexpected account {other:?}"),
+    }
+}
+
+#[test]
+fn account_sizes() {
+    assert_eq!(encoded_len(&lb_pair()), 904);
+    assert_eq!(encoded_len(&bin_array()), 10136);
+    let position = match state::unpack(&zeroed(state::POSITION_V2, 8120)).unwrap() {
+        MeteoraDlmmAccount::PositionV2(position) => position,
+        other => panic!("unexpected account {other:?}"),
+    };
+    assert_eq!(encoded_len(&position), 8120);
+}
+
+#[test]
+fn lb_pair_active_price() {
+    let mut pair = lb_pair();
+    pair.bin_step = 25;
+    pair.active_id = -100;
+    let data = account(state::LB_PAIR, &pair);
+    let MeteoraDlmmAccount::LbPair(decoded) = state::unpack(&data).unwrap() else {
+        panic!("expected LbPair");
+    };
+    assert_eq!(*decoded, pair);
+    let expected = 1.0025f64.powi(-100);
+    assert!((decoded.active_price() - expected).abs() < 1e-12);
+    assert_eq!(decoded.price_of_bin(0), 1.0);
+}
+
+#[test]
+fn bin_array_depth() {
+    let mut array = bin_array();
+    array.index = -2;
+    array.bins[3].amount_x = 500;
+    array.bins[3].price = 3u128 << 63;
+    array.bins[69].amount_y = 7;
+
+    let MeteoraDlmmAccount::BinArray(decoded) = state::unpack(&account(state::BIN_ARRAY, &array)).unwrap() else {
+        panic!("expected BinArray");
+    };
+    let bins: Vec<_> = decoded.non_empty_bins().map(|(id, bin)| (id, bin.amount_x, bin.amount_y)).collect();
+    assert_eq!(bins, vec![(-137, 500, 0), (-71, 0, 7)]);
+    assert_eq!(decoded.bins[3].price_f64(), 1.5);
+    assert_eq!(BinArray::index_of(-137), -2);
+    assert_eq!(BinArray::index_of(-71), -2);
+    assert_eq!(BinArray::index_of(-70), -1);
+    assert_eq!(BinArray::index_of(69), 0);
+}
+
+#[test]
+fn position_shares() {
+    let mut data = zeroed(state::POSITION_V2, 8120);
+    let MeteoraDlmmAccount::PositionV2(mut position) = state::unpack(&data).unwrap() else {
+        panic!("expected PositionV2");
+    };
+    position.lower_bin_id = 10;
+    position.upper_bin_id = 12;
+    position.liquidity_shares[1] = 42;
+    data = account(state::POSITION_V2, &*position);
+    let MeteoraDlmmAccount::PositionV2(decoded) = state::unpack(&data).unwrap() else {
+        panic!("expected PositionV2");
+    };
+    let shares: Vec<_> = decoded.shares().collect();
+    assert_eq!(shares, vec![(10, 0), (11, 42), (12, 0)]);
+    assert!(MAX_BIN_PER_ARRAY >= shares.len());
+
+    // Garbage bounds must not overflow.
+    position.lower_bin_id = i32::MIN;
+    position.upper_bin_id = i32::MAX;
+    assert_eq!(position.shares().count(), MAX_BIN_PER_ARRAY);
+    position.lower_bin_id = i32::MAX;
+    position.upper_bin_id = i32::MIN;
+    assert_eq!(position.shares().count(), 0);
+}
+
+#[test]
+fn oracle_samples() {
+    let mut data = state::ORACLE.to_vec();
+    for value in [1u64, 2, 3] {
+        data.extend(value.to_le_bytes());
+    }
+    let samples = [
+        Observation {
+            cumulative_active_bin_id: -5,
+            created_at: 100,
+            last_updated_at: 110,
+        },
+        Observation {
+            cumulative_active_bin_id: 9,
+            created_at: 120,
+            last_updated_at: 130,
+        },
+        Observation {
+            cumulative_active_bin_id: 0,
+            created_at: 0,
+            last_updated_at: 0,
+        },
+    ];
+    for sample in &samples {
+        data.extend(to_vec(sample).unwrap());
+    }
+    let MeteoraDlmmAccount::Oracle(oracle) = state::unpack(&data).unwrap() else {
+        panic!("expected Oracle");
+    };
+    assert_eq!((oracle.idx, oracle.active_size, oracle.length), (1, 2, 3));
+    assert_eq!(oracle.observations, samples[..2]);
+}
+
+#[test]
+fn unknown_discriminator() {
+    assert!(state::unpack(&[0u8; 16]).is_err());
+    assert!(state::unpack(&[1u8; 4]).is_err());
+}
@@ -1,42 +1,122 @@
+use solana_program::pubkey::Pubkey;
+use substreams::hex;
 use substreams_solana_idls::native::stake::instructions::{self as stake_ix, StakeInstruction};
+use substreams_solana_idls::native::stake::state as stake_state;
 use substreams_solana_idls::native::system::instructions::{self as system_ix, SystemInstruction};
 use substreams_solana_idls::native::vote::instructions::{self as vote_ix, VoteInstruction};
+use substreams_solana_idls::native::vote::state as vote_state;
 
 // ---- System ----
 
+const TOKEN_PROGRAM: [u8; 32] = hex!("06ddf6e1d765a193d9cbe146ceeb79ac1cb485ed5f5b37913a8cf5857eff00a9");
+
 #[test]
 fn system_create_account() {
-    let data = [0u8, 0, 0, 0, 1, 2, 3];
+    // 2_039_280 lamports, 165 bytes, owned by the SPL Token program
+    let data = hex!("00000000f01d1f0000000000a50000000000000006ddf6e1d765a193d9cbe146ceeb79ac1cb485ed5f5b37913a8cf5857eff00a9");
     let parsed = system_ix::unpack(&data).unwrap();
-    assert!(matches!(parsed, SystemInstruction::CreateAccount(_)));
+    assert_eq!(
+        parsed,
+        SystemInstruction::CreateAccount(system_ix::CreateAccountInstruction {
+            lamports: 2_039_280,
+            space: 165,
+            owner: Pubkey::new_from_array(TOKEN_PROGRAM),
+        })
+    );
 }
 
 #[test]
 fn system_assign() {
-    let data = [1u8, 0, 0, 0];
+    let mut data = vec![1u8, 0, 0, 0];
+    data.extend_from_slice(&TOKEN_PROGRAM);
     let parsed = system_ix::unpack(&data).unwrap();
-    assert!(matches!(parsed, SystemInstruction::Assign(_)));
+    assert_eq!(
+        parsed,
+        SystemInstruction::Assign(system_ix::AssignInstruction {
+            owner: Pubkey::new_from_array(TOKEN_PROGRAM)
+        })
+    );
 }
 
 #[test]
 fn system_transfer() {
-    let data = [2u8, 0, 0, 0, 0xAA];
+    let data = hex!("0200000040420f0000000000");
     let parsed = system_ix::unpack(&data).unwrap();
-    assert!(matches!(parsed, SystemInstruction::Transfer(_)));
+    assert_eq!(parsed, SystemInstruction::Transfer(system_ix::TransferInstruction { lamports: 1_000_000 }));
 }
 
 #[test]
 fn system_allocate() {
-    let data = [8u8, 0, 0, 0];
+    let data = [8u8, 0, 0, 0, 200, 0, 0, 0, 0, 0, 0, 0];
+    let parsed = system_ix::unpack(&data).unwrap();
+    assert_eq!(parsed, SystemInstruction::Allocate(system_ix::AllocateInstruction { space: 200 }));
+}
+
+#[test]
+fn system_create_account_with_seed() {
+    let base = Pubkey::new_from_array([7u8; 32]);
+    let mut data = vec![3u8, 0, 0, 0];
+    data.extend_from_slice(base.as_ref());
+    data.extend_from_slice(&5u64.to_le_bytes());
+    data.extend_from_slice(b"stake");
+    data.extend_from_slice(&1_000_000_000u64.to_le_bytes());
+    data.extend_from_slice(&200u64.to_le_bytes());
+    data.extend_from_slice(&TOKEN_PROGRAM);
     let parsed = system_ix::unpack(&data).unwrap();
-    assert!(matches!(parsed, SystemInstruction::Allocate(_)));
+    assert_eq!(
+        parsed,
+        SystemInstruction::CreateAccountWithSeed(system_ix::CreateAccountWithSeedInstruction {
+            base,
+            seed: "stake".to_string(),
+            lamports: 1_000_000_000,
+            space: 200,
+            owner: Pubkey::new_from_array(TOKEN_PROGRAM),
+        })
+    );
 }
 
 #[test]
 fn system_transfer_with_seed() {
-    let data = [11u8, 0, 0, 0, 0xFF];
+    let mut data = vec![11u8, 0, 0, 0];
+    data.extend_from_slice(&42u64.to_le_bytes());
+    data.extend_from_slice(&4u64.to_le_bytes());
+    data.extend_from_slice(b"seed");
+    data.extend_from_slice(&TOKEN_PROGRAM);
     let parsed = system_ix::unpack(&data).unwrap();
-    assert!(matches!(parsed, SystemInstruction::TransferWithSeed(_)));
+    assert_eq!(
+        parsed,
+        SystemInstruction::TransferWithSeed(system_ix::TransferWithSeedInstruction {
+            lamports: 42,
+            from_seed: "seed".to_string(),
+            from_owner: Pubkey::new_from_array(TOKEN_PROGRAM),
+        })
+    );
+}
+
+#[test]
+fn system_nonce_instructions() {
+    assert_eq!(system_ix::unpack(&[4u8, 0, 0, 0]).unwrap(), SystemInstruction::AdvanceNonceAccount);
+    assert_eq!(system_ix::unpack(&[12u8, 0, 0, 0]).unwrap(), SystemInstruction::UpgradeNonceAccount);
+
+    let mut data = vec![6u8, 0, 0, 0];
+    data.extend_from_slice(&[9u8; 32]);
+    assert_eq!(
+        system_ix::unpack(&data).unwrap(),
+        SystemInstruction::InitializeNonceAccount(system_ix::InitializeNonceAccountInstruction {
+            nonce_authority: Pubkey::new_from_array([9u8; 32])
+        })
+    );
+}
+
+#[test]
+fn system_truncated_payload() {
+    // Transfer with only 4 of the 8 lamport bytes
+    assert!(system_ix::unpack(&[2u8, 0, 0, 0, 1, 2, 3, 4]).is_err());
+    // Seed length larger than the remaining payload
+    let mut data = vec![11u8, 0, 0, 0];
+    data.extend_from_slice(&42u64.to_le_bytes());
+    data.extend_from_slice(&u64::MAX.to_le_bytes());
+    assert!(system_ix::unpack(&data).is_err());
 }
 
 #[test]
@@ -55,37 +135,126 @@ fn system_unknown() {
 
 #[test]
 fn stake_initialize() {
-    let data = [0u8, 0, 0, 0, 1];
+    let staker = Pubkey::new_from_array([1u8; 32]);
+    let withdrawer = Pubkey::new_from_array([2u8; 32]);
+    let mut data = vec![0u8, 0, 0, 0];
+    data.extend_from_slice(staker.as_ref());
+    data.extend_from_slice(withdrawer.as_ref());
+    data.extend_from_slice(&0i64.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.extend_from_slice(&[0u8; 32]);
+    let parsed = stake_ix::unpack(&data).unwrap();
+    assert_eq!(
+        parsed,
+        StakeInstruction::Initialize(stake_ix::InitializeInstruction {
+            authorized: stake_ix::Authorized { staker, withdrawer },
+            lockup: stake_ix::Lockup {
+                unix_timestamp: 0,
+                epoch: 0,
+                custodian: Pubkey::default(),
+            },
+        })
+    );
+}
+
+#[test]
+fn stake_authorize() {
+    let new_authority = Pubkey::new_from_array([3u8; 32]);
+    let mut data = vec![1u8, 0, 0, 0];
+    data.extend_from_slice(new_authority.as_ref());
+    data.extend_from_slice(&1u32.to_le_bytes());
     let parsed = stake_ix::unpack(&data).unwrap();
-    assert!(matches!(parsed, StakeInstruction::Initialize(_)));
+    assert_eq!(
+        parsed,
+        StakeInstruction::Authorize(stake_ix::AuthorizeInstruction {
+            new_authority,
+            stake_authorize: stake_ix::StakeAuthorize::Withdrawer,
+        })
+    );
 }
 
 #[test]
 fn stake_delegate() {
     let data = [2u8, 0, 0, 0];
     let parsed = stake_ix::unpack(&data).unwrap();
-    assert!(matches!(parsed, StakeInstruction::DelegateStake(_)));
+    assert_eq!(parsed, StakeInstruction::DelegateStake);
+}
+
+#[test]
+fn stake_split_and_withdraw() {
+    let parsed = stake_ix::unpack(&hex!("0300000000ca9a3b00000000")).unwrap();
+    assert_eq!(parsed, StakeInstruction::Split(stake_ix::SplitInstruction { lamports: 1_000_000_000 }));
+    let parsed = stake_ix::unpack(&hex!("0400000000e1f50500000000")).unwrap();
+    assert_eq!(parsed, StakeInstruction::Withdraw(stake_ix::WithdrawInstruction { lamports: 100_000_000 }));
 }
 
 #[test]
-fn stake_withdraw() {
+fn stake_withdraw_truncated() {
     let data = [4u8, 0, 0, 0, 0xBB];
-    let parsed = stake_ix::unpack(&data).unwrap();
-    assert!(matches!(parsed, StakeInstruction::Withdraw(_)));
+    assert!(stake_ix::unpack(&data).is_err());
 }
 
 #[test]
 fn stake_deactivate() {
     let data = [5u8, 0, 0, 0];
     let parsed = stake_ix::unpack(&data).unwrap();
-    assert!(matches!(parsed, StakeInstruction::Deactivate(_)));
+    assert_eq!(parsed, StakeInstruction::Deactivate);
+}
+
+#[test]
+fn stake_set_lockup() {
+    // unix_timestamp: Some(1_700_000_000), epoch: None, custodian: None
+    let mut data = vec![6u8, 0, 0, 0, 1];
+    data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+    data.extend_from_slice(&[0, 0]);
+    let parsed = stake_ix::unpack(&data).unwrap();
+    assert_eq!(
+        parsed,
+        StakeInstruction::SetLockup(stake_ix::SetLockupInstruction {
+            unix_timestamp: Some(1_700_000_000),
+            epoch: None,
+            custodian: None,
+        })
+    );
 }
 
 #[test]
 fn stake_set_lockup_checked() {
-    let data = [12u8, 0, 0, 0];
+    let data = [12u8, 0, 0, 0, 0, 1, 10, 0, 0, 0, 0, 0, 0, 0];
+    let parsed = stake_ix::unpack(&data).unwrap();
+    assert_eq!(
+        parsed,
+        StakeInstruction::SetLockupChecked(stake_ix::SetLockupCheckedInstruction {
+            unix_timestamp: None,
+            epoch: Some(10),
+        })
+    );
+}
+
+#[test]
+fn stake_authorize_checked_with_seed() {
+    let owner = Pubkey::new_from_array([4u8; 32]);
+    let mut data = vec![11u8, 0, 0, 0];
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&3u64.to_le_bytes());
+    data.extend_from_slice(b"abc");
+    data.extend_from_slice(owner.as_ref());
     let parsed = stake_ix::unpack(&data).unwrap();
-    assert!(matches!(parsed, StakeInstruction::SetLockupChecked(_)));
+    assert_eq!(
+        parsed,
+        StakeInstruction::AuthorizeCheckedWithSeed(stake_ix::AuthorizeCheckedWithSeedInstruction {
+            stake_authorize: stake_ix::StakeAuthorize::Staker,
+            authority_seed: "abc".to_string(),
+            authority_owner: owner,
+        })
+    );
+}
+
+#[test]
+fn stake_invalid_stake_authorize() {
+    let mut data = vec![10u8, 0, 0, 0];
+    data.extend_from_slice(&7u32.to_le_bytes());
+    assert!(stake_ix::unpack(&data).is_err());
 }
 
 #[test]
@@ -94,41 +263,209 @@ fn stake_too_short() {
     assert!(stake_ix::unpack(&data).is_err());
 }
 
+#[test]
+fn stake_state_delegated() {
+    let staker = Pubkey::new_from_array([1u8; 32]);
+    let withdrawer = Pubkey::new_from_array([2u8; 32]);
+    let voter = Pubkey::new_from_array([5u8; 32]);
+    let mut data = vec![2u8, 0, 0, 0];
+    data.extend_from_slice(&2_282_880u64.to_le_bytes());
+    data.extend_from_slice(staker.as_ref());
+    data.extend_from_slice(withdrawer.as_ref());
+    data.extend_from_slice(&0i64.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.extend_from_slice(&[0u8; 32]);
+    data.extend_from_slice(voter.as_ref());
+    data.extend_from_slice(&5_000_000_000u64.to_le_bytes());
+    data.extend_from_slice(&600u64.to_le_bytes());
+    data.extend_from_slice(&u64::MAX.to_le_bytes());
+    data.extend_from_slice(&0.25f64.to_le_bytes());
+    data.extend_from_slice(&123_456u64.to_le_bytes());
+    data.push(0);
+    data.resize(200, 0);
+
+    match stake_state::unpack(&data).unwrap() {
+        stake_state::StakeStateV2::Stake(meta, stake, flags) => {
+            assert_eq!(meta.rent_exempt_reserve, 2_282_880);
+            assert_eq!(meta.authorized, stake_ix::Authorized { staker, withdrawer });
+            assert_eq!(stake.delegation.voter_pubkey, voter);
+            assert_eq!(stake.delegation.stake, 5_000_000_000);
+            assert_eq!(stake.delegation.activation_epoch, 600);
+            assert_eq!(stake.delegation.deactivation_epoch, u64::MAX);
+            assert_eq!(stake.credits_observed, 123_456);
+            assert_eq!(flags.bits, 0);
+        }
+        other => panic!("expected Stake, got {other:?}"),
+    }
+}
+
+#[test]
+fn stake_state_uninitialized_and_invalid() {
+    assert_eq!(stake_state::unpack(&[0u8; 200]).unwrap(), stake_state::StakeStateV2::Uninitialized);
+    assert!(stake_state::unpack(&[9u8, 0, 0, 0]).is_err());
+    // Initialized tag without the Meta payload
+    assert!(stake_state::unpack(&[1u8, 0, 0, 0, 0]).is_err());
+}
+
 // ---- Vote ----
 
 #[test]
 fn vote_initialize() {
-    let data = [0u8, 0, 0, 0, 1, 2];
+    let node = Pubkey::new_from_array([1u8; 32]);
+    let voter = Pubkey::new_from_array([2u8; 32]);
+    let withdrawer = Pubkey::new_from_array([3u8; 32]);
+    let mut data = vec![0u8, 0, 0, 0];
+    data.extend_from_slice(node.as_ref());
+    data.extend_from_slice(voter.as_ref());
+    data.extend_from_slice(withdrawer.as_ref());
+    data.push(5);
     let parsed = vote_ix::unpack(&data).unwrap();
-    assert!(matches!(parsed, VoteInstruction::Initialize(_)));
+    assert_eq!(
+        parsed,
+        VoteInstruction::Initialize(vote_ix::InitializeInstruction {
+            node_pubkey: node,
+            authorized_voter: voter,
+            authorized_withdrawer: withdrawer,
+            commission: 5,
+        })
+    );
 }
 
 #[test]
 fn vote_vote() {
-    let data = [2u8, 0, 0, 0, 0xCC];
+    let mut data = vec![2u8, 0, 0, 0];
+    data.extend_from_slice(&2u64.to_le_bytes());
+    data.extend_from_slice(&100u64.to_le_bytes());
+    data.extend_from_slice(&101u64.to_le_bytes());
+    data.extend_from_slice(&[0xAA; 32]);
+    data.push(0);
     let parsed = vote_ix::unpack(&data).unwrap();
-    assert!(matches!(parsed, VoteInstruction::Vote(_)));
+    assert_eq!(
+        parsed,
+        VoteInstruction::Vote(vote_ix::Vote {
+            slots: vec![100, 101],
+            hash: [0xAA; 32],
+            timestamp: None,
+        })
+    );
 }
 
 #[test]
 fn vote_update_commission() {
-    let data = [5u8, 0, 0, 0];
+    let data = [5u8, 0, 0, 0, 10];
+    let parsed = vote_ix::unpack(&data).unwrap();
+    assert_eq!(
+        parsed,
+        VoteInstruction::UpdateCommission(vote_ix::UpdateCommissionInstruction { commission: 10 })
+    );
+}
+
+#[test]
+fn vote_authorize_with_seed() {
+    let owner = Pubkey::new_from_array([4u8; 32]);
+    let new_authority = Pubkey::new_from_array([6u8; 32]);
+    let mut data = vec![10u8, 0, 0, 0];
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(owner.as_ref());
+    data.extend_from_slice(&4u64.to_le_bytes());
+    data.extend_from_slice(b"seed");
+    data.extend_from_slice(new_authority.as_ref());
+    let parsed = vote_ix::unpack(&data).unwrap();
+    assert_eq!(
+        parsed,
+        VoteInstruction::AuthorizeWithSeed(vote_ix::AuthorizeWithSeedInstruction {
+            authorization_type: vote_ix::VoteAuthorize::Withdrawer,
+            current_authority_derived_key_owner: owner,
+            current_authority_derived_key_seed: "seed".to_string(),
+            new_authority,
+        })
+    );
+}
+
+#[test]
+fn vote_compact_update_vote_state() {
+    // root: None, offsets 5 and 1
+    let mut data = vec![12u8, 0, 0, 0];
+    data.extend_from_slice(&u64::MAX.to_le_bytes());
+    data.extend_from_slice(&[2, 5, 2, 1, 1]);
+    data.extend_from_slice(&[0xBB; 32]);
+    data.push(1);
+    data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
     let parsed = vote_ix::unpack(&data).unwrap();
-    assert!(matches!(parsed, VoteInstruction::UpdateCommission(_)));
+    assert_eq!(
+        parsed,
+        VoteInstruction::CompactUpdateVoteState(vote_ix::VoteStateUpdate {
+            lockouts: vec![
+                vote_ix::Lockout {
+                    slot: 5,
+                    confirmation_count: 2
+                },
+                vote_ix::Lockout {
+                    slot: 6,
+                    confirmation_count: 1
+                },
+            ],
+            root: None,
+            hash: [0xBB; 32],
+            timestamp: Some(1_700_000_000),
+        })
+    );
 }
 
 #[test]
 fn vote_tower_sync() {
-    let data = [14u8, 0, 0, 0, 0xDD];
+    // root: 1000, offsets 1 and 200 (varint c8 01)
+    let mut data = vec![14u8, 0, 0, 0];
+    data.extend_from_slice(&1000u64.to_le_bytes());
+    data.extend_from_slice(&[2, 1, 31, 0xC8, 0x01, 1]);
+    data.extend_from_slice(&[0xCC; 32]);
+    data.push(0);
+    data.extend_from_slice(&[0xDD; 32]);
     let parsed = vote_ix::unpack(&data).unwrap();
-    assert!(matches!(parsed, VoteInstruction::TowerSync(_)));
+    assert_eq!(
+        parsed,
+        VoteInstruction::TowerSync(vote_ix::TowerSync {
+            lockouts: vec![
+                vote_ix::Lockout {
+                    slot: 1001,
+                    confirmation_count: 31
+                },
+                vote_ix::Lockout {
+                    slot: 1201,
+                    confirmation_count: 1
+                },
+            ],
+            root: Some(1000),
+            hash: [0xCC; 32],
+            timestamp: None,
+            block_id: [0xDD; 32],
+        })
+    );
 }
 
 #[test]
 fn vote_tower_sync_switch() {
-    let data = [15u8, 0, 0, 0];
-    let parsed = vote_ix::unpack(&data).unwrap();
-    assert!(matches!(parsed, VoteInstruction::TowerSyncSwitch(_)));
+    let mut data = vec![15u8, 0, 0, 0];
+    data.extend_from_slice(&u64::MAX.to_le_bytes());
+    data.push(0);
+    data.extend_from_slice(&[0xCC; 32]);
+    data.push(0);
+    data.extend_from_slice(&[0xDD; 32]);
+    data.extend_from_slice(&[0xEE; 32]);
+    match vote_ix::unpack(&data).unwrap() {
+        VoteInstruction::TowerSyncSwitch(ix) => {
+            assert!(ix.tower_sync.lockouts.is_empty());
+            assert_eq!(ix.tower_sync.root, None);
+            assert_eq!(ix.proof_hash, [0xEE; 32]);
+        }
+        other => panic!("expected TowerSyncSwitch, got {other:?}"),
+    }
+}
+
+#[test]
+fn vote_tower_sync_truncated() {
+    let data = [14u8, 0, 0, 0, 0xDD];
+    assert!(vote_ix::unpack(&data).is_err());
 }
 
 #[test]
@@ -142,3 +479,70 @@ fn vote_unknown() {
     let data = [99u8, 0, 0, 0];
     assert!(vote_ix::unpack(&data).is_err());
 }
+
+#[test]
+fn vote_state_current() {
+    let node = Pubkey::new_from_array([1u8; 32]);
+    let withdrawer = Pubkey::new_from_array([3u8; 32]);
+    let voter = Pubkey::new_from_array([2u8; 32]);
+    let mut data = vec![2u8, 0, 0, 0];
+    data.extend_from_slice(node.as_ref());
+    data.extend_from_slice(withdrawer.as_ref());
+    data.push(7);
+    // votes: one landed vote
+    data.extend_from_slice(&1u64.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(&500u64.to_le_bytes());
+    data.extend_from_slice(&1u32.to_le_bytes());
+    // root_slot
+    data.push(1);
+    data.extend_from_slice(&499u64.to_le_bytes());
+    // authorized_voters
+    data.extend_from_slice(&1u64.to_le_bytes());
+    data.extend_from_slice(&600u64.to_le_bytes());
+    data.extend_from_slice(voter.as_ref());
+    // prior_voters
+    data.extend_from_slice(&[0u8; 48 * 32]);
+    data.extend_from_slice(&31u64.to_le_bytes());
+    data.push(1);
+    // epoch_credits
+    data.extend_from_slice(&1u64.to_le_bytes());
+    data.extend_from_slice(&600u64.to_le_bytes());
+    data.extend_from_slice(&2_000u64.to_le_bytes());
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    // last_timestamp
+    data.extend_from_slice(&500u64.to_le_bytes());
+    data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+    data.resize(3762, 0);
+
+    let state = vote_state::unpack(&data).unwrap();
+    assert_eq!(state.node_pubkey(), &node);
+    assert_eq!(state.authorized_withdrawer(), &withdrawer);
+    assert_eq!(state.commission(), 7);
+    assert_eq!(state.root_slot(), Some(499));
+    assert_eq!(
+        state.epoch_credits(),
+        &[vote_state::EpochCredits {
+            epoch: 600,
+            credits: 2_000,
+            prev_credits: 1_000,
+        }]
+    );
+    assert_eq!(state.last_timestamp().timestamp, 1_700_000_000);
+    match state {
+        vote_state::VoteStateVersions::Current(state) => {
+            assert_eq!(state.votes.len(), 1);
+            assert_eq!(state.votes[0].latency, 1);
+            assert_eq!(state.votes[0].lockout.slot, 500);
+            assert_eq!(state.authorized_voters, vec![vote_state::AuthorizedVoter { epoch: 600, voter }]);
+            assert!(state.prior_voters.is_empty);
+        }
+        other => panic!("expected Current, got {other:?}"),
+    }
+}
+
+#[test]
+fn vote_state_invalid() {
+    assert!(vote_state::unpack(&[7u8, 0, 0, 0]).is_err());
+    assert!(vote_state::unpack(&[2u8, 0, 0, 0, 0]).is_err());
+}
@@ -9,3 +9,150 @@ fn unknown_discriminator() {
 fn too_short() {
     assert!(instructions::unpack(&[0u8; 4]).is_err());
 }
+
+mod route_accounts {
+    use solana_program::pubkey::Pubkey;
+    use substreams_solana_idls::okx::v2::accounts::split_route_accounts;
+    use substreams_solana_idls::okx::v2::instructions::{Dex, Route, SwapArgs};
+
+    fn key(n: u8) -> Pubkey {
+        Pubkey::new_from_array([n; 32])
+    }
+
+    const AUTHORITY: u8 = 1;
+
+    /// Adapter accounts: program, authority, source, destination, then `extra` pool accounts.
+    fn hop(program: u8, source: u8, destination: u8, extra: &[u8]) -> Vec<Pubkey> {
+        [program, AUTHORITY, source, destination].iter().chain(extra).map(|&n| key(n)).collect()
+    }
+
+    fn args(routes: Vec<Route>) -> SwapArgs {
+        SwapArgs {
+            amount_in: 100,
+            expect_amount_out: 100,
+            min_return: 99,
+            amounts: vec![100],
+            routes: vec![routes],
+        }
+    }
+
+    #[test]
+    fn split_sequential_and_parallel_hops() {
+        let args = args(vec![
+            Route {
+                dexes: vec![Dex::RaydiumStableSwap],
+                weights: vec![100],
+            },
+            Route {
+                dexes: vec![Dex::ObricV2, Dex::ObricV2],
+                weights: vec![80, 20],
+            },
+            Route {
+                dexes: vec![Dex::PumpfunammBuy3],
+                weights: vec![100],
+            },
+        ]);
+        // Token accounts 10 -> 11 -> 12 -> 13; the Obric pools reference the
+        // authority and token accounts again as pool accounts.
+        let accounts = [
+            hop(50, 10, 11, &[60, 61, 62]),
+            hop(51, 11, 12, &[63, AUTHORITY, 11]),
+            hop(51, 11, 12, &[64, 65]),
+            hop(52, 12, 13, &[66]),
+        ]
+        .concat();
+
+        let hops = split_route_accounts(&args, &accounts).unwrap();
+        assert_eq!(hops.len(), 4);
+        assert_eq!(
+            hops.iter().map(|h| (h.hop, h.dex.clone(), h.weight, h.accounts.len())).collect::<Vec<_>>(),
+            [
+                (0, Dex::RaydiumStableSwap, 100, 7),
+                (1, Dex::ObricV2, 80, 7),
+                (1, Dex::ObricV2, 20, 6),
+                (2, Dex::PumpfunammBuy3, 100, 5),
+            ]
+        );
+        assert_eq!(hops[1].dex_program, key(51));
+        assert_eq!(hops[2].accounts[4], key(64));
+        assert_eq!((hops[3].swap_source_token, hops[3].swap_destination_token), (key(12), key(13)));
+        assert!(hops.iter().all(|h| h.swap_authority == key(AUTHORITY)));
+    }
+
+    #[test]
+    fn split_branches() {
+        let mut args = args(vec![Route {
+            dexes: vec![Dex::Whirlpool],
+            weights: vec![100],
+        }]);
+        args.amounts = vec![60, 40];
+        args.routes.push(vec![Route {
+            dexes: vec![Dex::MeteoraDlmm],
+            weights: vec![100],
+        }]);
+        let accounts = [hop(50, 10, 13, &[60]), hop(51, 10, 13, &[61, 62])].concat();
+
+        let hops = split_route_accounts(&args, &accounts).unwrap();
+        assert_eq!(hops.iter().map(|h| (h.branch, h.dex_program)).collect::<Vec<_>>(), [(0, key(50)), (1, key(51))]);
+        assert_eq!(hops[1].accounts.len(), 6);
+    }
+
+    #[test]
+    fn split_multi_hop_with_distinct_pools() {
+        let args = args(vec![
+            Route {
+                dexes: vec![Dex::Whirlpool],
+                weights: vec![100],
+            },
+            Route {
+                dexes: vec![Dex::MeteoraDlmm],
+                weights: vec![100],
+            },
+            Route {
+                dexes: vec![Dex::RaydiumClmmSwapV2],
+                weights: vec![100],
+            },
+        ]);
+        // Each hop has its own program, token accounts and number of pool accounts.
+        let accounts = [hop(50, 10, 11, &[60, 61, 62, 63]), hop(51, 11, 12, &[]), hop(52, 12, 13, &[64, 65])].concat();
+
+        let hops = split_route_accounts(&args, &accounts).unwrap();
+        assert_eq!(
+            hops.iter()
+                .map(|h| (h.hop, h.dex_program, h.swap_source_token, h.accounts.len()))
+                .collect::<Vec<_>>(),
+            [(0, key(50), key(10), 8), (1, key(51), key(11), 4), (2, key(52), key(12), 6)]
+        );
+
+        // A hop signed by another authority breaks the shared-authority assumption.
+        let mut accounts = accounts;
+        accounts[9] = key(2);
+        assert!(split_route_accounts(&args, &accounts).is_err());
+    }
+
+    #[test]
+    fn split_empty_routes() {
+        let mut args = args(Vec::new());
+        assert_eq!(split_route_accounts(&args, &[]).unwrap(), []);
+        args.routes = vec![vec![Route {
+            dexes: Vec::new(),
+            weights: Vec::new(),
+        }]];
+        assert_eq!(split_route_accounts(&args, &[]).unwrap(), []);
+    }
+
+    #[test]
+    fn missing_hop_accounts() {
+        let args = args(vec![
+            Route {
+                dexes: vec![Dex::Whirlpool],
+                weights: vec![100],
+            },
+            Route {
+                dexes: vec![Dex::Phoenix],
+                weights: vec![100],
+            },
+        ]);
+        assert!(split_route_accounts(&args, &hop(50, 10, 11, &[60, 61])).is_err());
+    }
+}
@@ -1,2 +1,4 @@
 #[path = "openbook/instructions.rs"]
 mod openbook_instructions;
+#[path = "openbook/state.rs"]
+mod openbook_state;
@@ -0,0 +1,181 @@
+use solana_program::pubkey::Pubkey;
+use substreams_solana_idls::openbook::state::{self, Event, OpenbookAccount, Side, SideAndOrderTree};
+
+const OWNER: Pubkey = Pubkey::new_from_array([7; 32]);
+
+fn account(discriminator: [u8; 8], len: usize) -> Vec<u8> {
+    let mut data = discriminator.to_vec();
+    data.resize(8 + len, 0);
+    data
+}
+
+fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+    data[8 + offset..8 + offset + bytes.len()].copy_from_slice(bytes);
+}
+
+fn market() -> state::Market {
+    let mut data = account(state::MARKET, state::MARKET_LEN);
+    put(&mut data, 1, &[9, 6]);
+    put(&mut data, 176, b"SOL-USDC");
+    // quote_lot_size, base_lot_size
+    put(&mut data, 440, &1i64.to_le_bytes());
+    put(&mut data, 448, &1_000_000i64.to_le_bytes());
+    put(&mut data, 472, &(-200i64).to_le_bytes());
+    match state::unpack(&data).unwrap() {
+        OpenbookAccount::Market(market) => *market,
+        other => panic!("expected Market, got {:?}", other),
+    }
+}
+
+const NODES: usize = 16 + 32 + 256 + 528;
+
+fn leaf(data: &mut [u8], handle: usize, key: u128, quantity: i64) {
+    let offset = NODES + handle * 88;
+    put(data, offset, &[2, 3]);
+    put(data, offset + 8, &key.to_le_bytes());
+    put(data, offset + 24, &OWNER.to_bytes());
+    put(data, offset + 56, &quantity.to_le_bytes());
+}
+
+fn key(side: Side, price_lots: i64, seq: u64) -> u128 {
+    let seq = if side == Side::Bid { !seq } else { seq };
+    ((price_lots as u128) << 64) | seq as u128
+}
+
+fn book(side: Side) -> state::BookSide {
+    let mut data = account(state::BOOK_SIDE, state::BOOK_SIDE_LEN);
+    // fixed root: inner node 0 with three leaves below it
+    put(&mut data, 0, &0u32.to_le_bytes());
+    put(&mut data, 4, &3u32.to_le_bytes());
+    put(&mut data, 304, &[side as u8]);
+    // inner 0 -> [inner 2, leaf 4]; inner 2 -> both orders at 100, lowest key left
+    put(&mut data, NODES, &[1]);
+    put(&mut data, NODES + 24, &2u32.to_le_bytes());
+    put(&mut data, NODES + 28, &4u32.to_le_bytes());
+    let (left, right) = if side == Side::Bid { (1u32, 3u32) } else { (3, 1) };
+    put(&mut data, NODES + 2 * 88, &[1]);
+    put(&mut data, NODES + 2 * 88 + 24, &left.to_le_bytes());
+    put(&mut data, NODES + 2 * 88 + 28, &right.to_le_bytes());
+    leaf(&mut data, 1, key(side, 100, 2), 5);
+    leaf(&mut data, 3, key(side, 100, 1), 10);
+    leaf(&mut data, 4, key(side, 110, 3), 1);
+    match state::unpack(&data).unwrap() {
+        OpenbookAccount::BookSide(book) => book,
+        other => panic!("expected BookSide, got {:?}", other),
+    }
+}
+
+#[test]
+fn decode_market() {
+    let market = market();
+    assert_eq!(market.name, "SOL-USDC");
+    assert_eq!(market.open_orders_admin, None);
+    assert_eq!(market.maker_fee, -200);
+    // 1 quote atom per base lot of 10^6 atoms: 1 lot = 0.001 USDC per SOL.
+    assert!((market.price_lots_to_ui(150_000) - 150.0).abs() < 1e-9);
+    assert!((market.base_lots_to_ui(2_000) - 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn decode_asks() {
+    let market = market();
+    let book = book(Side::Ask);
+    assert_eq!(book.side, Side::Ask);
+    assert!(book.oracle_pegged.is_empty());
+    let orders = market.l3(&book);
+    assert_eq!(
+        orders.iter().map(|o| (o.price_lots, o.base_lots)).collect::<Vec<_>>(),
+        [(100, 10), (100, 5), (110, 1)]
+    );
+    assert_eq!(orders[0].owner, OWNER);
+    assert_eq!(orders[0].owner_slot, 3);
+    let levels = market.l2(&book);
+    assert_eq!(levels.iter().map(|l| (l.price_lots, l.base_lots)).collect::<Vec<_>>(), [(100, 15), (110, 1)]);
+}
+
+#[test]
+fn decode_bids() {
+    let market = market();
+    let orders = market.l3(&book(Side::Bid));
+    assert_eq!(
+        orders.iter().map(|o| (o.price_lots, o.base_lots)).collect::<Vec<_>>(),
+        [(110, 1), (100, 10), (100, 5)]
+    );
+}
+
+#[test]
+fn decode_event_heap() {
+    let mut data = account(state::EVENT_HEAP, state::EVENT_HEAP_LEN);
+    // used_head = 5, count = 2, seq_num = 42
+    put(&mut data, 2, &5u16.to_le_bytes());
+    put(&mut data, 4, &2u16.to_le_bytes());
+    put(&mut data, 8, &42u64.to_le_bytes());
+    let node = |index: usize| 16 + index * 152;
+    put(&mut data, node(5), &9u16.to_le_bytes());
+    // fill: taker bid, maker out
+    put(&mut data, node(5) + 8, &[0, 0, 1, 4]);
+    put(&mut data, node(5) + 8 + 24, &OWNER.to_bytes());
+    put(&mut data, node(5) + 8 + 104, &150i64.to_le_bytes());
+    put(&mut data, node(5) + 8 + 120, &3i64.to_le_bytes());
+    // out: ask side
+    put(&mut data, node(9) + 8, &[1, 1, 2]);
+    put(&mut data, node(9) + 8 + 56, &7i64.to_le_bytes());
+
+    let heap = match state::unpack(&data).unwrap() {
+        OpenbookAccount::EventHeap(heap) => heap,
+        other => panic!("expected EventHeap, got {:?}", other),
+    };
+    assert_eq!(heap.seq_num, 42);
+    assert_eq!(heap.events.len(), 2);
+    match &heap.events[0] {
+        Event::Fill(fill) => {
+            assert_eq!(fill.taker_side, Side::Bid);
+            assert!(fill.maker_out);
+            assert_eq!(fill.maker_slot, 4);
+            assert_eq!(fill.maker, OWNER);
+            assert_eq!((fill.price, fill.quantity), (150, 3));
+        }
+        other => panic!("expected Fill, got {:?}", other),
+    }
+    match &heap.events[1] {
+        Event::Out(out) => assert_eq!((out.side, out.owner_slot, out.quantity), (Side::Ask, 2, 7)),
+        other => panic!("expected Out, got {:?}", other),
+    }
+}
+
+#[test]
+fn decode_open_orders_account() {
+    let mut data = account(state::OPEN_ORDERS_ACCOUNT, state::OPEN_ORDERS_ACCOUNT_LEN);
+    put(&mut data, 0, &OWNER.to_bytes());
+    put(&mut data, 64, b"main");
+    put(&mut data, 128, &1u32.to_le_bytes());
+    // position.bids_base_lots
+    put(&mut data, 136, &12i64.to_le_bytes());
+    for slot in 0..24 {
+        put(&mut data, 296 + slot * 40 + 32, &[1]);
+    }
+    let slot = 296 + 2 * 40;
+    put(&mut data, slot, &77u128.to_le_bytes());
+    put(&mut data, slot + 16, &5u64.to_le_bytes());
+    put(&mut data, slot + 32, &[0, 3]);
+
+    let account = match state::unpack(&data).unwrap() {
+        OpenbookAccount::OpenOrdersAccount(account) => account,
+        other => panic!("expected OpenOrdersAccount, got {:?}", other),
+    };
+    assert_eq!(account.owner, OWNER);
+    assert_eq!(account.name, "main");
+    assert_eq!(account.delegate, None);
+    assert_eq!(account.account_num, 1);
+    assert_eq!(account.position.bids_base_lots, 12);
+    assert_eq!(account.open_orders.len(), 1);
+    let order = &account.open_orders[0];
+    assert_eq!((order.slot, order.id, order.client_id), (2, 77, 5));
+    assert_eq!(order.side_and_tree, SideAndOrderTree::AskOraclePegged);
+    assert_eq!(order.side_and_tree.side(), Side::Ask);
+}
+
+#[test]
+fn reject_unknown_discriminator() {
+    assert!(state::unpack(&[0; 16]).is_err());
+}
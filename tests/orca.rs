@@ -1,2 +1,6 @@
+#[path = "support/anchor_account.rs"]
+mod anchor_account;
 #[path = "orca/instructions.rs"]
 mod orca_instructions;
+#[path = "orca/state.rs"]
+mod orca_state;
@@ -1,5 +1,12 @@
-use substreams_solana_idls::orca::whirlpool::events::{self, WhirlpoolEvent};
-use substreams_solana_idls::orca::whirlpool::instructions;
+use solana_program::pubkey::Pubkey;
+use substreams_solana_idls::orca::whirlpool::events::{self, Traded, WhirlpoolEvent};
+use substreams_solana_idls::orca::whirlpool::instructions::{self, *};
+
+fn ix_data(disc: [u8; 8], args: &impl borsh::BorshSerialize) -> Vec<u8> {
+    let mut data = disc.to_vec();
+    data.extend(borsh::to_vec(args).unwrap());
+    data
+}
 
 #[test]
 fn whirlpool_unknown() {
@@ -14,3 +21,78 @@ fn whirlpool_event_unknown_returns_variant() {
     // orca events return Ok(Unknown)
     assert!(matches!(events::parse_event(&[0u8; 24]).unwrap(), WhirlpoolEvent::Unknown));
 }
+
+#[test]
+fn whirlpool_position_lock_instructions() {
+    let lock = LockPositionInstruction {
+        lock_type: LockType::Permanent,
+    };
+    assert_eq!(
+        instructions::unpack(&ix_data(LOCK_POSITION, &lock)).unwrap(),
+        WhirlpoolInstruction::LockPosition(lock)
+    );
+
+    let reset = ResetPositionRangeInstruction {
+        new_tick_lower_index: -128,
+        new_tick_upper_index: 64,
+    };
+    assert_eq!(
+        instructions::unpack(&ix_data(RESET_POSITION_RANGE, &reset)).unwrap(),
+        WhirlpoolInstruction::ResetPositionRange(reset)
+    );
+
+    assert_eq!(
+        instructions::unpack(&TRANSFER_LOCKED_POSITION).unwrap(),
+        WhirlpoolInstruction::TransferLockedPosition
+    );
+}
+
+#[test]
+fn whirlpool_adaptive_fee_instructions() {
+    let tier = InitializeAdaptiveFeeTierInstruction {
+        fee_tier_index: 1024,
+        tick_spacing: 64,
+        initialize_pool_authority: Pubkey::new_unique(),
+        delegated_fee_authority: Pubkey::new_unique(),
+        default_base_fee_rate: 3000,
+        filter_period: 30,
+        decay_period: 600,
+        reduction_factor: 5000,
+        adaptive_fee_control_factor: 4000,
+        max_volatility_accumulator: 350_000,
+        tick_group_size: 64,
+        major_swap_threshold_ticks: 64,
+    };
+    assert_eq!(
+        instructions::unpack(&ix_data(INITIALIZE_ADAPTIVE_FEE_TIER, &tier)).unwrap(),
+        WhirlpoolInstruction::InitializeAdaptiveFeeTier(tier)
+    );
+
+    let pool = InitializePoolWithAdaptiveFeeInstruction {
+        initial_sqrt_price: 1 << 64,
+        trade_enable_timestamp: Some(1_700_000_000),
+    };
+    assert_eq!(
+        instructions::unpack(&ix_data(INITIALIZE_POOL_WITH_ADAPTIVE_FEE, &pool)).unwrap(),
+        WhirlpoolInstruction::InitializePoolWithAdaptiveFee(pool)
+    );
+}
+
+#[test]
+fn whirlpool_traded_event() {
+    let traded = Traded {
+        whirlpool: Pubkey::new_unique(),
+        a_to_b: true,
+        pre_sqrt_price: 1 << 64,
+        post_sqrt_price: (1 << 64) - 1_000,
+        input_amount: 1_000_000,
+        output_amount: 990_000,
+        input_transfer_fee: 0,
+        output_transfer_fee: 0,
+        lp_fee: 3_000,
+        protocol_fee: 390,
+    };
+    // sha256("event:Traded")[..8]
+    let data = ix_data([225, 202, 73, 175, 147, 43, 160, 150], &traded);
+    assert_eq!(events::parse_event(&data).unwrap(), WhirlpoolEvent::Traded(traded));
+}
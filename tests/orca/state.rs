@@ -0,0 +1,125 @@
+use solana_program::pubkey::Pubkey;
+use substreams_solana_idls::orca::whirlpool::state::{self, PositionRewardInfo, Tick, TickArray, Whirlpool, WhirlpoolAccount, WhirlpoolRewardInfo};
+
+use crate::anchor_account::{account, encoded_len};
+
+fn whirlpool() -> Whirlpool {
+    let reward = WhirlpoolRewardInfo {
+        mint: Pubkey::default(),
+        vault: Pubkey::default(),
+        authority: Pubkey::new_from_array([9; 32]),
+        emissions_per_second_x64: 0,
+        growth_global_x64: 0,
+    };
+    Whirlpool {
+        whirlpools_config: Pubkey::new_from_array([1; 32]),
+        whirlpool_bump: [255],
+        tick_spacing: 64,
+        fee_tier_index_seed: 1025u16.to_le_bytes(),
+        fee_rate: 3_000,
+        protocol_fee_rate: 1_300,
+        liquidity: 123_456_789,
+        sqrt_price: 1 << 64,
+        tick_current_index: -12,
+        protocol_fee_owed_a: 1,
+        protocol_fee_owed_b: 2,
+        token_mint_a: Pubkey::new_from_array([2; 32]),
+        token_vault_a: Pubkey::new_from_array([3; 32]),
+        fee_growth_global_a: 7 << 64,
+        token_mint_b: Pubkey::new_from_array([4; 32]),
+        token_vault_b: Pubkey::new_from_array([5; 32]),
+        fee_growth_global_b: 8 << 64,
+        reward_last_updated_timestamp: 1_757_867_955,
+        reward_infos: [reward.clone(), reward.clone(), reward],
+    }
+}
+
+#[test]
+fn decode_whirlpool() {
+    let data = account(state::WHIRLPOOL, &whirlpool());
+    // On-chain `Whirlpool::LEN`.
+    assert_eq!(encoded_len(&whirlpool()), 653);
+    match state::unpack(&data).unwrap() {
+        WhirlpoolAccount::Whirlpool(pool) => {
+            assert_eq!(*pool, whirlpool());
+            assert_eq!(pool.sqrt_price, 1 << 64);
+            assert_eq!(pool.tick_current_index, -12);
+            assert_eq!(pool.fee_tier_index(), 1025);
+            assert!(pool.is_initialized_with_adaptive_fee());
+        }
+        other => panic!("expected Whirlpool, got {:?}", other),
+    }
+}
+
+#[test]
+fn decode_position() {
+    let position = state::Position {
+        whirlpool: Pubkey::new_from_array([1; 32]),
+        position_mint: Pubkey::new_from_array([2; 32]),
+        liquidity: 1_000,
+        tick_lower_index: -128,
+        tick_upper_index: 128,
+        fee_growth_checkpoint_a: 1,
+        fee_owed_a: 2,
+        fee_growth_checkpoint_b: 3,
+        fee_owed_b: 4,
+        reward_infos: std::array::from_fn(|i| PositionRewardInfo {
+            growth_inside_checkpoint: i as u128,
+            amount_owed: 0,
+        }),
+    };
+    let data = account(state::POSITION, &position);
+    assert_eq!(encoded_len(&position), 216);
+    assert_eq!(state::unpack(&data).unwrap(), WhirlpoolAccount::Position(position));
+}
+
+#[test]
+fn decode_tick_array() {
+    let empty = Tick {
+        initialized: false,
+        liquidity_net: 0,
+        liquidity_gross: 0,
+        fee_growth_outside_a: 0,
+        fee_growth_outside_b: 0,
+        reward_growths_outside: [0; 3],
+    };
+    let mut ticks: [Tick; state::TICK_ARRAY_SIZE] = std::array::from_fn(|_| empty.clone());
+    ticks[3] = Tick {
+        initialized: true,
+        liquidity_net: -500,
+        liquidity_gross: 500,
+        ..empty
+    };
+    let array = TickArray {
+        start_tick_index: -5_632,
+        ticks,
+        whirlpool: Pubkey::new_from_array([1; 32]),
+    };
+    let data = account(state::TICK_ARRAY, &array);
+    assert_eq!(encoded_len(&array), 9_988);
+    match state::unpack(&data).unwrap() {
+        WhirlpoolAccount::TickArray(decoded) => {
+            let initialized: Vec<_> = decoded.initialized_ticks(64).map(|(index, tick)| (index, tick.liquidity_net)).collect();
+            assert_eq!(initialized, [(-5_632 + 3 * 64, -500)]);
+        }
+        other => panic!("expected TickArray, got {:?}", other),
+    }
+}
+
+#[test]
+fn decode_fee_tier_with_reserved_space() {
+    let tier = state::FeeTier {
+        whirlpools_config: Pubkey::new_from_array([1; 32]),
+        tick_spacing: 1,
+        default_fee_rate: 100,
+    };
+    let mut data = account(state::FEE_TIER, &tier);
+    data.extend_from_slice(&[0; 16]);
+    assert_eq!(state::unpack(&data).unwrap(), WhirlpoolAccount::FeeTier(tier));
+}
+
+#[test]
+fn reject_unknown_account() {
+    assert!(matches!(state::unpack(&[0; 16]), Err(substreams_solana_idls::common::ParseError::Unknown(_))));
+    assert!(state::unpack(&state::WHIRLPOOL).is_err());
+}
@@ -1,2 +1,6 @@
+#[path = "phoenix/events.rs"]
+mod phoenix_events;
 #[path = "phoenix/instructions.rs"]
 mod phoenix_instructions;
+#[path = "phoenix/state.rs"]
+mod phoenix_state;
@@ -0,0 +1,136 @@
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+use substreams_solana_idls::phoenix::events::{self, *};
+use substreams_solana_idls::phoenix::instructions::LOG;
+
+fn tagged<T: BorshSerialize>(tag: u8, event: &T) -> Vec<u8> {
+    let mut data = vec![tag];
+    data.extend_from_slice(&borsh::to_vec(event).unwrap());
+    data
+}
+
+fn header() -> AuditLogHeader {
+    AuditLogHeader {
+        instruction: 0,
+        sequence_number: 41,
+        timestamp: 1_700_000_000,
+        slot: 250_000_000,
+        market: Pubkey::new_unique(),
+        signer: Pubkey::new_unique(),
+        total_events: 3,
+    }
+}
+
+fn fill() -> FillEvent {
+    FillEvent {
+        index: 0,
+        maker_id: Pubkey::new_from_array([5; 32]),
+        order_sequence_number: 12,
+        price_in_ticks: 1_500,
+        base_lots_filled: 4,
+        base_lots_remaining: 0,
+    }
+}
+
+#[test]
+fn parse_single_event() {
+    let parsed = events::unpack(&tagged(FILL_EVENT, &fill())).unwrap();
+    assert_eq!(parsed, PhonenixEvent::Fill(fill()));
+}
+
+#[test]
+fn parse_log_batch() {
+    let summary = FillSummaryEvent {
+        index: 1,
+        client_order_id: 7,
+        total_base_lots_filled: 4,
+        total_quote_lots_filled: 6_000,
+        total_fee_in_quote_lots: 3,
+    };
+    let fee = FeeEvent {
+        index: 2,
+        fees_collected_in_quote_lots: 3,
+    };
+    let header = header();
+
+    let mut data = vec![LOG];
+    data.extend_from_slice(&tagged(HEADER_EVENT, &header));
+    data.extend_from_slice(&3u32.to_le_bytes());
+    data.extend_from_slice(&tagged(FILL_EVENT, &fill()));
+    data.extend_from_slice(&tagged(FILL_SUMMARY_EVENT, &summary));
+    data.extend_from_slice(&tagged(FEE_EVENT, &fee));
+
+    let log = events::unpack_log(&data).unwrap();
+    assert_eq!(log.header, header);
+    assert_eq!(
+        log.events,
+        vec![PhonenixEvent::Fill(fill()), PhonenixEvent::FillSummary(summary), PhonenixEvent::Fee(fee)]
+    );
+}
+
+#[test]
+fn parse_order_lifecycle_events() {
+    let place = PlaceEvent {
+        index: 0,
+        order_sequence_number: 3,
+        client_order_id: 9,
+        price_in_ticks: 100,
+        base_lots_placed: 5,
+    };
+    let tif = TimeInForceEvent {
+        index: 1,
+        order_sequence_number: 3,
+        last_valid_slot: 10,
+        last_valid_unix_timestamp_in_seconds: 0,
+    };
+    let expired = ExpiredOrderEvent {
+        index: 0,
+        maker_id: Pubkey::new_unique(),
+        order_sequence_number: 3,
+        price_in_ticks: 100,
+        base_lots_removed: 5,
+    };
+    let evict = EvictEvent {
+        index: 0,
+        maker_id: Pubkey::new_unique(),
+        order_sequence_number: 4,
+        price_in_ticks: 90,
+        base_lots_evicted: 1,
+    };
+    let reduce = ReduceEvent {
+        index: 0,
+        order_sequence_number: 5,
+        price_in_ticks: 95,
+        base_lots_removed: 2,
+        base_lots_remaining: 1,
+    };
+    assert_eq!(events::unpack(&tagged(PLACE_EVENT, &place)).unwrap(), PhonenixEvent::Place(place));
+    assert_eq!(events::unpack(&tagged(TIME_IN_FORCE_EVENT, &tif)).unwrap(), PhonenixEvent::TimeInForce(tif));
+    assert_eq!(
+        events::unpack(&tagged(EXPIRED_ORDER_EVENT, &expired)).unwrap(),
+        PhonenixEvent::ExpiredOrder(expired)
+    );
+    assert_eq!(events::unpack(&tagged(EVICT_EVENT, &evict)).unwrap(), PhonenixEvent::Evict(evict));
+    assert_eq!(events::unpack(&tagged(REDUCE_EVENT, &reduce)).unwrap(), PhonenixEvent::Reduce(reduce));
+}
+
+#[test]
+fn log_must_start_with_header() {
+    let mut data = vec![LOG];
+    data.extend_from_slice(&tagged(FILL_EVENT, &fill()));
+    data.extend_from_slice(&0u32.to_le_bytes());
+    assert!(events::unpack_log(&data).is_err());
+    assert!(events::unpack_log(&[0u8]).is_err());
+}
+
+#[test]
+fn event_unknown() {
+    assert!(events::unpack(&[0u8; 24]).is_err());
+}
+
+#[test]
+fn trailing_bytes() {
+    let mut data = tagged(FILL_EVENT, &fill());
+    data.push(0);
+    assert!(events::unpack(&data).is_err());
+}
@@ -1,18 +1,207 @@
-use substreams_solana_idls::phoenix::events;
-use substreams_solana_idls::phoenix::instructions::{self, PhonenixInstruction};
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+use substreams_solana_idls::phoenix::instructions::{self, *};
+
+fn ix<T: BorshSerialize>(disc: u8, args: &T) -> Vec<u8> {
+    let mut data = vec![disc];
+    data.extend_from_slice(&borsh::to_vec(args).unwrap());
+    data
+}
+
+fn ioc() -> OrderPacket {
+    OrderPacket::ImmediateOrCancel {
+        side: Side::Bid,
+        price_in_ticks: None,
+        num_base_lots: 0,
+        num_quote_lots: 1_000,
+        min_base_lots_to_fill: 9,
+        min_quote_lots_to_fill: 0,
+        self_trade_behavior: SelfTradeBehavior::CancelProvide,
+        match_limit: Some(20),
+        client_order_id: 7,
+        use_only_deposited_funds: false,
+        last_valid_slot: None,
+        last_valid_unix_timestamp_in_seconds: Some(1_700_000_000),
+    }
+}
+
+fn limit() -> OrderPacket {
+    OrderPacket::Limit {
+        side: Side::Ask,
+        price_in_ticks: 1_234,
+        num_base_lots: 50,
+        self_trade_behavior: SelfTradeBehavior::DecrementTake,
+        match_limit: None,
+        client_order_id: u128::MAX,
+        use_only_deposited_funds: true,
+        last_valid_slot: Some(300_000_000),
+        last_valid_unix_timestamp_in_seconds: None,
+        fail_silently_on_insufficient_funds: false,
+    }
+}
 
 #[test]
 fn parse_swap() {
-    let data = [0u8, 1, 2, 3]; // disc=0 is SWAP
-    let parsed = instructions::unpack(&data).unwrap();
-    assert!(matches!(parsed, PhonenixInstruction::Swap(_)));
+    let parsed = instructions::unpack(&ix(SWAP, &ioc())).unwrap();
+    assert_eq!(parsed, PhonenixInstruction::Swap(SwapInstruction { order_packet: ioc() }));
 }
 
 #[test]
 fn parse_swap_with_free_funds() {
-    let data = [1u8, 4, 5, 6]; // disc=1 is SWAP_WITH_FREE_FUNDS
+    let parsed = instructions::unpack(&ix(SWAP_WITH_FREE_FUNDS, &ioc())).unwrap();
+    assert_eq!(
+        parsed,
+        PhonenixInstruction::SwapWithFreeFunds(SwapWithFreeFundsInstruction { order_packet: ioc() })
+    );
+}
+
+#[test]
+fn parse_place_limit_order() {
+    let parsed = instructions::unpack(&ix(PLACE_LIMIT_ORDER, &limit())).unwrap();
+    assert_eq!(
+        parsed,
+        PhonenixInstruction::PlaceLimitOrder(PlaceLimitOrderInstruction { order_packet: limit() })
+    );
+}
+
+#[test]
+fn parse_post_only_without_fail_silently() {
+    let packet = OrderPacket::PostOnly {
+        side: Side::Bid,
+        price_in_ticks: 99,
+        num_base_lots: 3,
+        client_order_id: 1,
+        reject_post_only: true,
+        use_only_deposited_funds: false,
+        last_valid_slot: None,
+        last_valid_unix_timestamp_in_seconds: None,
+        fail_silently_on_insufficient_funds: false,
+    };
+    let mut data = ix(PLACE_LIMIT_ORDER_WITH_FREE_FUNDS, &packet);
+    data.pop(); // legacy packets end before `fail_silently_on_insufficient_funds`
     let parsed = instructions::unpack(&data).unwrap();
-    assert!(matches!(parsed, PhonenixInstruction::SwapWithFreeFunds(_)));
+    assert_eq!(
+        parsed,
+        PhonenixInstruction::PlaceLimitOrderWithFreeFunds(PlaceLimitOrderWithFreeFundsInstruction { order_packet: packet })
+    );
+}
+
+#[test]
+fn parse_cancel_multiple_orders_by_id() {
+    let params = CancelMultipleOrdersByIdParams {
+        orders: vec![
+            CancelOrderParams {
+                side: Side::Bid,
+                price_in_ticks: 10,
+                order_sequence_number: 1,
+            },
+            CancelOrderParams {
+                side: Side::Ask,
+                price_in_ticks: 12,
+                order_sequence_number: u64::MAX - 2,
+            },
+        ],
+    };
+    let parsed = instructions::unpack(&ix(CANCEL_MULTIPLE_ORDERS_BY_ID, &params)).unwrap();
+    assert_eq!(
+        parsed,
+        PhonenixInstruction::CancelMultipleOrdersById(CancelMultipleOrdersByIdInstruction { params })
+    );
+}
+
+#[test]
+fn parse_reduce_and_cancel_up_to() {
+    let reduce = ReduceOrderParams {
+        base_params: CancelOrderParams {
+            side: Side::Ask,
+            price_in_ticks: 5,
+            order_sequence_number: 8,
+        },
+        size: 2,
+    };
+    assert_eq!(
+        instructions::unpack(&ix(REDUCE_ORDER, &reduce)).unwrap(),
+        PhonenixInstruction::ReduceOrder(ReduceOrderInstruction { params: reduce })
+    );
+    let cancel = CancelUpToParams {
+        side: Side::Bid,
+        tick_limit: Some(100),
+        num_orders_to_search: None,
+        num_orders_to_cancel: Some(4),
+    };
+    assert_eq!(
+        instructions::unpack(&ix(FORCE_CANCEL_ORDERS, &cancel)).unwrap(),
+        PhonenixInstruction::ForceCancelOrders(ForceCancelOrdersInstruction { params: cancel })
+    );
+}
+
+#[test]
+fn parse_funds() {
+    let withdraw = WithdrawParams {
+        quote_lots_to_withdraw: None,
+        base_lots_to_withdraw: Some(5),
+    };
+    assert_eq!(
+        instructions::unpack(&ix(WITHDRAW_FUNDS, &withdraw)).unwrap(),
+        PhonenixInstruction::WithdrawFunds(WithdrawFundsInstruction {
+            withdraw_funds_params: withdraw
+        })
+    );
+    let deposit = DepositParams {
+        quote_lots_to_deposit: 10,
+        base_lots_to_deposit: 0,
+    };
+    assert_eq!(
+        instructions::unpack(&ix(DEPOSIT_FUNDS, &deposit)).unwrap(),
+        PhonenixInstruction::DepositFunds(DepositFundsInstruction { deposit_funds_params: deposit })
+    );
+}
+
+#[test]
+fn parse_place_multiple_post_only_orders() {
+    let packet = MultipleOrderPacket {
+        bids: vec![CondensedOrder {
+            price_in_ticks: 99,
+            size_in_base_lots: 1,
+            last_valid_slot: None,
+            last_valid_unix_timestamp_in_seconds: None,
+        }],
+        asks: vec![CondensedOrder {
+            price_in_ticks: 101,
+            size_in_base_lots: 2,
+            last_valid_slot: Some(5),
+            last_valid_unix_timestamp_in_seconds: None,
+        }],
+        client_order_id: None,
+        failed_multiple_limit_order_behavior: FailedMultipleLimitOrderBehavior::SkipOnInsufficientFundsAndAmendOnCross,
+    };
+    let parsed = instructions::unpack(&ix(PLACE_MULTIPLE_POST_ONLY_ORDERS, &packet)).unwrap();
+    assert_eq!(
+        parsed,
+        PhonenixInstruction::PlaceMultiplePostOnlyOrders(PlaceMultiplePostOnlyOrdersInstruction { multiple_order_packet: packet })
+    );
+}
+
+#[test]
+fn parse_admin_instructions() {
+    let successor = Pubkey::new_unique();
+    assert_eq!(
+        instructions::unpack(&ix(NAME_SUCCESSOR, &successor)).unwrap(),
+        PhonenixInstruction::NameSuccessor(NameSuccessorInstruction { successor })
+    );
+    assert_eq!(
+        instructions::unpack(&ix(CHANGE_MARKET_STATUS, &MarketStatus::Paused)).unwrap(),
+        PhonenixInstruction::ChangeMarketStatus(ChangeMarketStatusInstruction {
+            market_status: MarketStatus::Paused
+        })
+    );
+    assert_eq!(instructions::unpack(&[CANCEL_ALL_ORDERS]).unwrap(), PhonenixInstruction::CancelAllOrders);
+    assert_eq!(instructions::unpack(&[COLLECT_FEES]).unwrap(), PhonenixInstruction::CollectFees);
+}
+
+#[test]
+fn invalid_order_packet() {
+    assert!(instructions::unpack(&[SWAP, 3]).is_err());
 }
 
 #[test]
@@ -24,8 +213,3 @@ fn unknown_discriminator() {
 fn too_short() {
     assert!(instructions::unpack(&[]).is_err());
 }
-
-#[test]
-fn event_unknown() {
-    assert!(events::unpack(&[0u8; 24]).is_err());
-}
@@ -0,0 +1,136 @@
+use borsh::to_vec;
+use solana_program::pubkey::Pubkey;
+use substreams_solana_idls::phoenix::instructions::MarketSizeParams;
+use substreams_solana_idls::phoenix::state::{self, MarketHeader, Side, TokenParams};
+
+const TRADER_A: Pubkey = Pubkey::new_from_array([1; 32]);
+const TRADER_B: Pubkey = Pubkey::new_from_array([2; 32]);
+
+fn header() -> MarketHeader {
+    let token = |decimals| TokenParams {
+        decimals,
+        vault_bump: 0,
+        mint_key: Pubkey::default(),
+        vault_key: Pubkey::default(),
+    };
+    MarketHeader {
+        discriminant: state::MARKET_HEADER_DISCRIMINANT,
+        status: 1,
+        market_size_params: MarketSizeParams {
+            bids_size: 4,
+            asks_size: 2,
+            num_seats: 2,
+        },
+        base_params: token(9),
+        base_lot_size: 1_000_000,
+        quote_params: token(6),
+        quote_lot_size: 1,
+        tick_size_in_quote_atoms_per_base_unit: 1_000,
+        authority: Pubkey::default(),
+        fee_recipient: Pubkey::default(),
+        market_sequence_number: 0,
+        successor: Pubkey::default(),
+        raw_base_units_per_base_unit: 1,
+        padding1: 0,
+        padding2: [0; 32],
+    }
+}
+
+/// A sokoban tree with `nodes` as `(left, right, key/value)`, rooted at node 1.
+fn tree(capacity: usize, node_len: usize, nodes: &[(u32, u32, Vec<u8>)]) -> Vec<u8> {
+    let mut data = vec![0u8; 32 + capacity * node_len];
+    if !nodes.is_empty() {
+        data[..4].copy_from_slice(&1u32.to_le_bytes());
+    }
+    for (i, (left, right, payload)) in nodes.iter().enumerate() {
+        let node = &mut data[32 + i * node_len..32 + (i + 1) * node_len];
+        node[..4].copy_from_slice(&left.to_le_bytes());
+        node[4..8].copy_from_slice(&right.to_le_bytes());
+        node[16..16 + payload.len()].copy_from_slice(payload);
+    }
+    data
+}
+
+fn order(price_in_ticks: u64, seq: u64, trader_index: u64, lots: u64) -> Vec<u8> {
+    [price_in_ticks, seq, trader_index, lots, 0, 0].iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn trader(key: Pubkey, base_lots_free: u64) -> Vec<u8> {
+    let mut data = key.to_bytes().to_vec();
+    for v in [0u64, 0, 0, base_lots_free] {
+        data.extend_from_slice(&v.to_le_bytes());
+    }
+    data
+}
+
+fn market() -> Vec<u8> {
+    let mut data = to_vec(&header()).unwrap();
+    assert_eq!(data.len(), state::MARKET_HEADER_LEN);
+    data.extend_from_slice(&[0; 256]);
+    for v in [1_000u64, 1_000, 7, 5, 0, 0] {
+        data.extend_from_slice(&v.to_le_bytes());
+    }
+    // Two bids at 150 (the older one has the larger inverted sequence
+    // number) and one at 140; one ask.
+    data.extend(tree(
+        4,
+        64,
+        &[(2, 3, order(150, !3, 1, 10)), (0, 0, order(140, !4, 2, 5)), (0, 0, order(150, !1, 2, 20))],
+    ));
+    data.extend(tree(2, 64, &[(0, 0, order(160, 2, 1, 8))]));
+    data.extend(tree(2, 144, &[(0, 2, trader(TRADER_A, 3)), (0, 0, trader(TRADER_B, 0))]));
+    data
+}
+
+#[test]
+fn decode_market_orders() {
+    let market = state::unpack(&market()).unwrap();
+    assert_eq!(market.taker_fee_bps, 5);
+    assert_eq!(market.traders.len(), 2);
+    assert_eq!(market.traders[0].trader, TRADER_A);
+    assert_eq!(market.traders[0].base_lots_free, 3);
+
+    let bids = market.orders(Side::Bid);
+    assert_eq!(bids.len(), 3);
+    assert_eq!((bids[0].price_in_ticks, bids[0].trader), (150, TRADER_B));
+    assert_eq!((bids[1].price_in_ticks, bids[1].trader), (150, TRADER_A));
+    assert_eq!(bids[2].price_in_ticks, 140);
+    let asks = market.orders(Side::Ask);
+    assert_eq!(asks.len(), 1);
+    assert_eq!((asks[0].num_base_lots, asks[0].trader), (8, TRADER_A));
+}
+
+#[test]
+fn aggregate_market_levels() {
+    let market = state::unpack(&market()).unwrap();
+    let bids = market.levels(Side::Bid);
+    assert_eq!(bids.len(), 2);
+    assert_eq!((bids[0].price_in_ticks, bids[0].num_base_lots), (150, 30));
+    // 150 ticks * 1_000 quote atoms / 10^6 = 0.15 quote per base unit.
+    assert!((bids[0].price - 0.15).abs() < 1e-12);
+    // 30 lots * 10^6 atoms / 10^9 = 0.03 base units.
+    assert!((bids[0].size - 0.03).abs() < 1e-12);
+    assert_eq!(market.levels(Side::Ask)[0].price_in_ticks, 160);
+}
+
+#[test]
+fn reject_order_without_seat() {
+    let mut data = market();
+    // Point the ask at seat 3, which does not exist.
+    let ask = state::MARKET_HEADER_LEN + 304 + 32 + 4 * 64 + 32 + 16 + 16;
+    data[ask..ask + 8].copy_from_slice(&3u64.to_le_bytes());
+    assert!(state::unpack(&data).is_err());
+}
+
+#[test]
+fn reject_foreign_or_oversized_header() {
+    let mut data = market();
+    data[..8].copy_from_slice(&0u64.to_le_bytes());
+    assert!(state::unpack(&data).is_err());
+
+    let mut header = header();
+    header.market_size_params.bids_size = u64::MAX;
+    let mut data = to_vec(&header).unwrap();
+    data.extend_from_slice(&[0; 304]);
+    assert!(state::unpack(&data).is_err());
+}
@@ -1,9 +1,9 @@
 use solana_program::pubkey::Pubkey;
+use substreams_solana_idls::common::anchor::EVENT_IX_TAG;
 use substreams_solana_idls::pumpfun::bonding_curve::events::*;
 
 #[test]
 fn parse_create_event() {
-    let outer_disc = [0u8; 8];
     let event = CreateEvent {
         name: "TestCoin".into(),
         symbol: "TC".into(),
@@ -18,7 +18,7 @@ fn parse_create_event() {
         real_token_reserves: 793_100_000_000_000,
         token_total_supply: 1_000_000_000_000_000,
     };
-    let mut data = outer_disc.to_vec();
+    let mut data = EVENT_IX_TAG.to_vec();
     data.extend_from_slice(&CREATE);
     data.extend(borsh::to_vec(&event).unwrap());
 
@@ -1,3 +1,11 @@
+#[path = "support/anchor_account.rs"]
+mod anchor_account;
+#[path = "raydium/amm_v4_state.rs"]
+mod raydium_amm_v4_state;
+#[path = "raydium/clmm_state.rs"]
+mod raydium_clmm_state;
+#[path = "raydium/cpmm_state.rs"]
+mod raydium_cpmm_state;
 #[path = "raydium/events.rs"]
 mod raydium_events;
 #[path = "raydium/instructions.rs"]
@@ -0,0 +1,38 @@
+use borsh::to_vec;
+use substreams_solana_idls::raydium::amm::v4::state::{self, AmmStatus, RaydiumAmmAccount, AMM_INFO_LEN, TARGET_ORDERS_LEN};
+
+#[test]
+fn amm_info_roundtrip() {
+    let mut data = vec![0u8; AMM_INFO_LEN];
+    data[..8].copy_from_slice(&6u64.to_le_bytes());
+    // coin_vault follows the 16 header words, `Fees` and `StateData`.
+    let coin_vault = 16 * 8 + 64 + 144;
+    data[coin_vault..coin_vault + 32].copy_from_slice(&[7; 32]);
+    data[AMM_INFO_LEN - 32..AMM_INFO_LEN - 24].copy_from_slice(&1_000u64.to_le_bytes());
+
+    let RaydiumAmmAccount::AmmInfo(info) = state::unpack(&data).unwrap() else {
+        panic!("expected AmmInfo");
+    };
+    assert_eq!(info.status(), Some(AmmStatus::SwapOnly));
+    assert_eq!(info.coin_vault.to_bytes(), [7; 32]);
+    assert_eq!(info.lp_amount, 1_000);
+    assert_eq!(to_vec(&*info).unwrap(), data);
+}
+
+#[test]
+fn target_orders_roundtrip() {
+    let mut data = vec![0u8; TARGET_ORDERS_LEN];
+    data[TARGET_ORDERS_LEN - 16..].copy_from_slice(&u128::MAX.to_le_bytes());
+
+    let RaydiumAmmAccount::TargetOrders(orders) = state::unpack(&data).unwrap() else {
+        panic!("expected TargetOrders");
+    };
+    assert_eq!(orders.free_slot_bits, u128::MAX);
+    assert_eq!(to_vec(&*orders).unwrap(), data);
+}
+
+#[test]
+fn unknown_length() {
+    let err = state::unpack(&[0u8; 100]).unwrap_err().to_string();
+    assert!(err.contains("752") && err.contains("2208"), "{err}");
+}
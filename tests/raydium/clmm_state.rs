@@ -0,0 +1,74 @@
+use substreams_solana_idls::raydium::clmm::v3::state::{self, RaydiumClmmAccount};
+
+use crate::anchor_account::{account, encoded_len, zeroed};
+
+fn serialized_len(account: &RaydiumClmmAccount) -> usize {
+    match account {
+        RaydiumClmmAccount::PoolState(a) => encoded_len(a),
+        RaydiumClmmAccount::AmmConfig(a) => encoded_len(a),
+        RaydiumClmmAccount::ObservationState(a) => encoded_len(a),
+        RaydiumClmmAccount::TickArrayState(a) => encoded_len(a),
+        RaydiumClmmAccount::TickArrayBitmapExtension(a) => encoded_len(a),
+        RaydiumClmmAccount::PersonalPositionState(a) => encoded_len(a),
+        RaydiumClmmAccount::ProtocolPositionState(a) => encoded_len(a),
+    }
+}
+
+#[test]
+fn account_sizes() {
+    for (discriminator, len) in [
+        (state::POOL_STATE, 1544),
+        (state::AMM_CONFIG, 117),
+        (state::OBSERVATION_STATE, 4483),
+        (state::TICK_ARRAY_STATE, 10240),
+        (state::TICK_ARRAY_BITMAP_EXTENSION, 1832),
+        (state::PERSONAL_POSITION_STATE, 281),
+        (state::PROTOCOL_POSITION_STATE, 225),
+    ] {
+        let account = state::unpack(&zeroed(discriminator, len)).unwrap();
+        assert_eq!(serialized_len(&account), len, "{account:?}");
+    }
+}
+
+#[test]
+fn pool_state_price() {
+    let RaydiumClmmAccount::PoolState(mut pool) = state::unpack(&zeroed(state::POOL_STATE, 1544)).unwrap() else {
+        panic!("expected PoolState");
+    };
+    pool.mint_decimals_0 = 9;
+    pool.mint_decimals_1 = 6;
+    // sqrt(0.25) in Q64.64: 0.25 atoms of token 1 per atom of token 0.
+    pool.sqrt_price_x64 = 1 << 63;
+    pool.tick_current = -13_864;
+
+    let RaydiumClmmAccount::PoolState(decoded) = state::unpack(&account(state::POOL_STATE, &*pool)).unwrap() else {
+        panic!("expected PoolState");
+    };
+    assert_eq!(decoded, pool);
+    assert_eq!(decoded.price(), 250.0);
+}
+
+#[test]
+fn tick_array_ticks() {
+    let RaydiumClmmAccount::TickArrayState(mut array) = state::unpack(&zeroed(state::TICK_ARRAY_STATE, 10240)).unwrap() else {
+        panic!("expected TickArrayState");
+    };
+    array.start_tick_index = -600;
+    array.ticks[2].tick = -580;
+    array.ticks[2].liquidity_gross = 1_000;
+    array.ticks[2].liquidity_net = -1_000;
+    array.initialized_tick_count = 1;
+
+    let RaydiumClmmAccount::TickArrayState(decoded) = state::unpack(&account(state::TICK_ARRAY_STATE, &*array)).unwrap() else {
+        panic!("expected TickArrayState");
+    };
+    let ticks: Vec<_> = decoded.initialized_ticks().map(|tick| (tick.tick, tick.liquidity_net)).collect();
+    assert_eq!(ticks, vec![(-580, -1_000)]);
+    assert_eq!(decoded.tick_index(2, 10), -580);
+}
+
+#[test]
+fn unknown_discriminator() {
+    assert!(state::unpack(&[0u8; 16]).is_err());
+    assert!(state::unpack(&[1u8; 4]).is_err());
+}
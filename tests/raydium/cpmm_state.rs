@@ -0,0 +1,73 @@
+use borsh::to_vec;
+use substreams_solana_idls::raydium::cpmm::state::{self, RaydiumCpmmAccount};
+
+use crate::anchor_account::{encoded_len, zeroed};
+
+fn serialized_len(account: &RaydiumCpmmAccount) -> usize {
+    match account {
+        RaydiumCpmmAccount::PoolState(a) => encoded_len(a),
+        RaydiumCpmmAccount::AmmConfig(a) => encoded_len(a),
+        RaydiumCpmmAccount::ObservationState(a) => encoded_len(a),
+        RaydiumCpmmAccount::Permission(a) => encoded_len(a),
+    }
+}
+
+#[test]
+fn account_sizes() {
+    for (discriminator, len) in [
+        (state::POOL_STATE, 637),
+        (state::AMM_CONFIG, 236),
+        (state::OBSERVATION_STATE, 4075),
+        (state::PERMISSION, 280),
+    ] {
+        let account = state::unpack(&zeroed(discriminator, len)).unwrap();
+        assert_eq!(serialized_len(&account), len, "{account:?}");
+    }
+}
+
+// Offsets in a `PoolState` account, discriminator included.
+const OPEN_TIME: usize = 8 + 10 * 32 + 5 + 5 * 8;
+const CREATOR_FEE_ON: usize = OPEN_TIME + 16;
+
+#[test]
+fn pool_state_v1_layout() {
+    // v1 pools carry zeroed padding where v2 stores creator fees.
+    let mut data = zeroed(state::POOL_STATE, 637);
+    data[OPEN_TIME..OPEN_TIME + 8].copy_from_slice(&1_700_000_000u64.to_le_bytes());
+    data[8 + 10 * 32 + 5..][..8].copy_from_slice(&5_000u64.to_le_bytes());
+
+    let RaydiumCpmmAccount::PoolState(pool) = state::unpack(&data).unwrap() else {
+        panic!("expected PoolState");
+    };
+    assert_eq!(pool.open_time, 1_700_000_000);
+    assert_eq!(pool.lp_supply, 5_000);
+    assert!(!pool.enable_creator_fee);
+    assert_eq!((pool.creator_fees_token_0, pool.creator_fees_token_1), (0, 0));
+}
+
+#[test]
+fn pool_state_v2_creator_fees() {
+    let mut data = zeroed(state::POOL_STATE, 637);
+    data[CREATOR_FEE_ON] = 1;
+    data[CREATOR_FEE_ON + 1] = 1;
+    data[CREATOR_FEE_ON + 8..][..8].copy_from_slice(&30u64.to_le_bytes());
+    data[CREATOR_FEE_ON + 16..][..8].copy_from_slice(&40u64.to_le_bytes());
+
+    let RaydiumCpmmAccount::PoolState(mut pool) = state::unpack(&data).unwrap() else {
+        panic!("expected PoolState");
+    };
+    assert_eq!(pool.creator_fee_on, 1);
+    assert!(pool.enable_creator_fee);
+    assert_eq!((pool.creator_fees_token_0, pool.creator_fees_token_1), (30, 40));
+    assert_eq!(to_vec(&*pool).unwrap(), data[8..]);
+
+    pool.protocol_fees_token_0 = 10;
+    pool.fund_fees_token_1 = 5;
+    assert_eq!(pool.vault_amount_without_fee(1_000, 2_000), (960, 1_955));
+}
+
+#[test]
+fn unknown_discriminator() {
+    assert!(state::unpack(&[0u8; 16]).is_err());
+    assert!(state::unpack(&[1u8; 4]).is_err());
+}
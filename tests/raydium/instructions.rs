@@ -59,3 +59,64 @@ fn stable_unknown() {
 fn launchpad_unknown() {
     assert!(substreams_solana_idls::raydium::launchpad::instructions::unpack(&[0u8; 16]).is_err());
 }
+
+#[test]
+fn launchpad_initialize_v2() {
+    use substreams_solana_idls::raydium::launchpad::instructions as lp;
+
+    let instr = lp::InitializeV2Instruction {
+        base_mint_param: lp::MintParams {
+            decimals: 6,
+            name: "Bonk Cat".into(),
+            symbol: "BCAT".into(),
+            uri: "https://example.com/bcat.json".into(),
+        },
+        curve_param: lp::CurveParams::Constant {
+            data: lp::ConstantCurve {
+                supply: 1_000_000_000_000_000,
+                total_base_sell: 793_100_000_000_000,
+                total_quote_fund_raising: 85_000_000_000,
+                migrate_type: 1,
+            },
+        },
+        vesting_param: lp::VestingParams {
+            total_locked_amount: 0,
+            cliff_period: 0,
+            unlock_period: 0,
+        },
+        amm_fee_on: lp::AmmCreatorFeeOn::BothToken,
+    };
+    let mut data = lp::INITIALIZE_V2.to_vec();
+    data.extend(borsh::to_vec(&instr).unwrap());
+    assert_eq!(lp::unpack(&data).unwrap(), lp::RaydiumLaunchpadInstruction::InitializeV2(instr));
+}
+
+#[test]
+fn launchpad_migration_and_vesting() {
+    use substreams_solana_idls::raydium::launchpad::instructions as lp;
+
+    assert_eq!(lp::unpack(&lp::MIGRATE_TO_CPSWAP).unwrap(), lp::RaydiumLaunchpadInstruction::MigrateToCpswap);
+    assert_eq!(lp::unpack(&lp::CLAIM_VESTED_TOKEN).unwrap(), lp::RaydiumLaunchpadInstruction::ClaimVestedToken);
+
+    let mut data = lp::CREATE_VESTING_ACCOUNT.to_vec();
+    data.extend_from_slice(&5_000u64.to_le_bytes());
+    assert_eq!(
+        lp::unpack(&data).unwrap(),
+        lp::RaydiumLaunchpadInstruction::CreateVestingAccount(lp::CreateVestingAccountInstruction { share_amount: 5_000 })
+    );
+}
+
+#[test]
+fn launchpad_update_platform_config() {
+    use substreams_solana_idls::raydium::launchpad::instructions as lp;
+
+    let mut data = lp::UPDATE_PLATFORM_CONFIG.to_vec();
+    data.push(3); // FeeRate
+    data.extend_from_slice(&250u64.to_le_bytes());
+    assert_eq!(
+        lp::unpack(&data).unwrap(),
+        lp::RaydiumLaunchpadInstruction::UpdatePlatformConfig(lp::UpdatePlatformConfigInstruction {
+            param: lp::PlatformConfigParam::FeeRate(250)
+        })
+    );
+}
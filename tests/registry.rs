@@ -0,0 +1,48 @@
+use substreams::hex;
+use substreams_solana_idls::common::ParseError;
+use substreams_solana_idls::{decode_event, decode_instruction, jupiter, spl, DecodedEvent, DecodedInstruction};
+
+#[test]
+fn instruction_dispatches_by_program_id() {
+    let mut data = vec![spl::token::instructions::TRANSFER];
+    data.extend_from_slice(&100u64.to_le_bytes());
+    let ix = decode_instruction(&spl::token::PROGRAM_ID, &data).unwrap();
+    assert_eq!(
+        ix,
+        DecodedInstruction::SplToken(spl::token::instructions::TokenInstruction::Transfer { amount: 100 })
+    );
+
+    // Same bytes, different program: routed to Token-2022.
+    let ix = decode_instruction(&spl::token_2022::PROGRAM_ID, &data).unwrap();
+    assert!(matches!(ix, DecodedInstruction::SplToken2022(_)));
+}
+
+#[test]
+fn instruction_propagates_protocol_errors() {
+    assert!(matches!(decode_instruction(&jupiter::v6::PROGRAM_ID, &[0u8; 4]), Err(ParseError::TooShort(4))));
+}
+
+#[test]
+fn event_dispatches_by_program_id() {
+    // https://solscan.io/tx/3tNX6MzVEnsDCJWSnM5xqBQNWXh22T3q2PkF1hys35P9kpxVuut6chgPbnFs5Cyp9ygU9EidQgvqbuak26u1bmQM
+    let bytes = hex!("e445a52e51cb9a1d494f4e7fb8d50ddc71337d91df2be75ff1c1ce88c1f5f0293ce7ee4562f6ee3e045fa07faad311dd069b8857feab8184fb687f634618c035dac439dc1aeb3b5598a0f0000000000108b5000000000000");
+    match decode_event(&jupiter::v6::PROGRAM_ID, &bytes).unwrap() {
+        DecodedEvent::JupiterV6(jupiter::v6::events::JupiterV6Event::Fee(event)) => assert_eq!(event.amount, 46344),
+        other => panic!("expected JupiterV6 fee event, got {other:?}"),
+    }
+}
+
+#[test]
+fn unknown_program() {
+    let program_id = [7u8; 32];
+    assert!(matches!(decode_instruction(&program_id, &[0u8; 8]), Err(ParseError::UnknownProgram(id)) if id == program_id));
+    assert!(matches!(decode_event(&spl::token::PROGRAM_ID, &[0u8; 16]), Err(ParseError::UnknownProgram(_))));
+}
+
+#[test]
+fn invalid_program_id_length() {
+    assert!(matches!(
+        decode_instruction(&[1u8; 31], &[0u8; 8]),
+        Err(ParseError::InvalidLength { expected: 32, got: 31 })
+    ));
+}
@@ -1,2 +1,4 @@
 #[path = "serum/instructions.rs"]
 mod serum_instructions;
+#[path = "serum/state.rs"]
+mod serum_state;
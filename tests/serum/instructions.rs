@@ -1,84 +1,215 @@
-use substreams_solana_idls::serum::instructions::{self, SerumInstruction};
+use solana_program::pubkey::Pubkey;
+use substreams_solana_idls::serum::instructions::{self, *};
+
+fn ix(tag: u32, payload: &[u8]) -> Vec<u8> {
+    let mut data = vec![instructions::VERSION];
+    data.extend_from_slice(&tag.to_le_bytes());
+    data.extend_from_slice(payload);
+    data
+}
+
+fn new_order_v3_payload() -> Vec<u8> {
+    let mut p = Vec::new();
+    p.extend_from_slice(&1u32.to_le_bytes()); // side: Ask
+    p.extend_from_slice(&1_500u64.to_le_bytes()); // limit_price
+    p.extend_from_slice(&10u64.to_le_bytes()); // max_coin_qty
+    p.extend_from_slice(&15_045u64.to_le_bytes()); // max_native_pc_qty_including_fees
+    p.extend_from_slice(&2u32.to_le_bytes()); // self_trade_behavior: AbortTransaction
+    p.extend_from_slice(&1u32.to_le_bytes()); // order_type: ImmediateOrCancel
+    p.extend_from_slice(&42u64.to_le_bytes()); // client_order_id
+    p.extend_from_slice(&65_535u16.to_le_bytes()); // limit
+    p
+}
 
 #[test]
 fn parse_initialize_market() {
-    let mut data = vec![0u8, 0, 0, 0]; // disc=0 LE u32
-    data.extend_from_slice(&[1, 2, 3]);
-    let parsed = instructions::unpack(&data).unwrap();
-    assert!(matches!(parsed, SerumInstruction::InitializeMarket(_)));
+    let mut p = Vec::new();
+    p.extend_from_slice(&100u64.to_le_bytes());
+    p.extend_from_slice(&10u64.to_le_bytes());
+    p.extend_from_slice(&22u16.to_le_bytes());
+    p.extend_from_slice(&3u64.to_le_bytes());
+    p.extend_from_slice(&5u64.to_le_bytes());
+    let parsed = instructions::unpack(&ix(0, &p)).unwrap();
+    assert_eq!(
+        parsed,
+        SerumInstruction::InitializeMarket(InitializeMarketInstruction {
+            coin_lot_size: 100,
+            pc_lot_size: 10,
+            fee_rate_bps: 22,
+            vault_signer_nonce: 3,
+            pc_dust_threshold: 5,
+        })
+    );
 }
 
 #[test]
 fn parse_new_order() {
-    let data = [1u8, 0, 0, 0, 0xAA];
-    let parsed = instructions::unpack(&data).unwrap();
-    assert!(matches!(parsed, SerumInstruction::NewOrder(_)));
+    let mut p = Vec::new();
+    p.extend_from_slice(&0u32.to_le_bytes());
+    p.extend_from_slice(&7u64.to_le_bytes());
+    p.extend_from_slice(&3u64.to_le_bytes());
+    p.extend_from_slice(&2u32.to_le_bytes());
+    p.extend_from_slice(&9u64.to_le_bytes());
+    let parsed = instructions::unpack(&ix(1, &p)).unwrap();
+    assert_eq!(
+        parsed,
+        SerumInstruction::NewOrder(NewOrderInstructionV1 {
+            side: Side::Bid,
+            limit_price: 7,
+            max_qty: 3,
+            order_type: OrderType::PostOnly,
+            client_id: 9,
+        })
+    );
 }
 
 #[test]
 fn parse_match_orders() {
-    let data = [2u8, 0, 0, 0];
-    let parsed = instructions::unpack(&data).unwrap();
-    assert!(matches!(parsed, SerumInstruction::MatchOrders(_)));
+    let parsed = instructions::unpack(&ix(2, &5u16.to_le_bytes())).unwrap();
+    assert_eq!(parsed, SerumInstruction::MatchOrders(MatchOrdersInstruction { limit: 5 }));
 }
 
 #[test]
 fn parse_consume_events() {
-    let data = [3u8, 0, 0, 0];
-    let parsed = instructions::unpack(&data).unwrap();
-    assert!(matches!(parsed, SerumInstruction::ConsumeEvents(_)));
+    let parsed = instructions::unpack(&ix(3, &10u16.to_le_bytes())).unwrap();
+    assert_eq!(parsed, SerumInstruction::ConsumeEvents(ConsumeEventsInstruction { limit: 10 }));
 }
 
 #[test]
 fn parse_cancel_order() {
-    let data = [4u8, 0, 0, 0, 1, 2];
-    let parsed = instructions::unpack(&data).unwrap();
-    assert!(matches!(parsed, SerumInstruction::CancelOrder(_)));
+    let mut p = Vec::new();
+    p.extend_from_slice(&1u32.to_le_bytes());
+    p.extend_from_slice(&(u128::MAX - 1).to_le_bytes());
+    p.extend_from_slice(&[7u8; 32]);
+    p.push(4);
+    let parsed = instructions::unpack(&ix(4, &p)).unwrap();
+    assert_eq!(
+        parsed,
+        SerumInstruction::CancelOrder(CancelOrderInstruction {
+            side: Side::Ask,
+            order_id: u128::MAX - 1,
+            owner: Pubkey::new_from_array([7u8; 32]),
+            owner_slot: 4,
+        })
+    );
 }
 
 #[test]
-fn parse_settle_funds() {
-    let data = [5u8, 0, 0, 0];
-    let parsed = instructions::unpack(&data).unwrap();
-    assert!(matches!(parsed, SerumInstruction::SettleFunds(_)));
+fn parse_unit_variants() {
+    assert_eq!(instructions::unpack(&ix(5, &[])).unwrap(), SerumInstruction::SettleFunds);
+    assert_eq!(instructions::unpack(&ix(7, &[])).unwrap(), SerumInstruction::DisableMarket);
+    assert_eq!(instructions::unpack(&ix(8, &[])).unwrap(), SerumInstruction::SweepFees);
+    assert_eq!(instructions::unpack(&ix(14, &[])).unwrap(), SerumInstruction::CloseOpenOrders);
+    assert_eq!(instructions::unpack(&ix(15, &[])).unwrap(), SerumInstruction::InitOpenOrders);
 }
 
 #[test]
 fn parse_new_order_v3() {
-    let data = [10u8, 0, 0, 0, 0xFF];
-    let parsed = instructions::unpack(&data).unwrap();
-    assert!(matches!(parsed, SerumInstruction::NewOrderV3(_)));
+    let parsed = instructions::unpack(&ix(10, &new_order_v3_payload())).unwrap();
+    assert_eq!(
+        parsed,
+        SerumInstruction::NewOrderV3(NewOrderInstructionV3 {
+            side: Side::Ask,
+            limit_price: 1_500,
+            max_coin_qty: 10,
+            max_native_pc_qty_including_fees: 15_045,
+            self_trade_behavior: SelfTradeBehavior::AbortTransaction,
+            order_type: OrderType::ImmediateOrCancel,
+            client_order_id: 42,
+            limit: 65_535,
+            max_ts: i64::MAX,
+        })
+    );
+}
+
+#[test]
+fn parse_new_order_v3_with_max_ts() {
+    let mut p = new_order_v3_payload();
+    p.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+    match instructions::unpack(&ix(10, &p)).unwrap() {
+        SerumInstruction::NewOrderV3(order) => assert_eq!(order.max_ts, 1_700_000_000),
+        other => panic!("unexpected {other:?}"),
+    }
 }
 
 #[test]
 fn parse_cancel_order_v2() {
-    let data = [11u8, 0, 0, 0, 1];
-    let parsed = instructions::unpack(&data).unwrap();
-    assert!(matches!(parsed, SerumInstruction::CancelOrderV2(_)));
+    let mut p = Vec::new();
+    p.extend_from_slice(&0u32.to_le_bytes());
+    p.extend_from_slice(&12345u128.to_le_bytes());
+    let parsed = instructions::unpack(&ix(11, &p)).unwrap();
+    assert_eq!(
+        parsed,
+        SerumInstruction::CancelOrderV2(CancelOrderInstructionV2 {
+            side: Side::Bid,
+            order_id: 12345,
+        })
+    );
+}
+
+#[test]
+fn parse_cancel_order_by_client_id_v2() {
+    let parsed = instructions::unpack(&ix(12, &42u64.to_le_bytes())).unwrap();
+    assert_eq!(
+        parsed,
+        SerumInstruction::CancelOrderByClientIdV2(CancelOrderByClientIdV2Instruction { client_order_id: 42 })
+    );
 }
 
 #[test]
-fn parse_close_open_orders() {
-    let data = [14u8, 0, 0, 0];
-    let parsed = instructions::unpack(&data).unwrap();
-    assert!(matches!(parsed, SerumInstruction::CloseOpenOrders(_)));
+fn parse_send_take() {
+    let mut p = Vec::new();
+    p.extend_from_slice(&0u32.to_le_bytes());
+    for v in [1u64, 2, 3, 4, 5] {
+        p.extend_from_slice(&v.to_le_bytes());
+    }
+    p.extend_from_slice(&6u16.to_le_bytes());
+    let parsed = instructions::unpack(&ix(13, &p)).unwrap();
+    assert_eq!(
+        parsed,
+        SerumInstruction::SendTake(SendTakeInstruction {
+            side: Side::Bid,
+            limit_price: 1,
+            max_coin_qty: 2,
+            max_native_pc_qty_including_fees: 3,
+            min_coin_qty: 4,
+            min_native_pc_qty: 5,
+            limit: 6,
+        })
+    );
 }
 
 #[test]
 fn parse_consume_events_permissioned() {
-    let data = [17u8, 0, 0, 0, 5];
-    let parsed = instructions::unpack(&data).unwrap();
-    assert!(matches!(parsed, SerumInstruction::ConsumeEventsPermissioned(_)));
+    let parsed = instructions::unpack(&ix(17, &5u16.to_le_bytes())).unwrap();
+    assert_eq!(
+        parsed,
+        SerumInstruction::ConsumeEventsPermissioned(ConsumeEventsPermissionedInstruction { limit: 5 })
+    );
 }
 
 #[test]
-fn unknown_discriminator() {
-    let data = [99u8, 0, 0, 0];
+fn invalid_enum_value() {
+    let mut p = new_order_v3_payload();
+    p[..4].copy_from_slice(&2u32.to_le_bytes());
+    assert!(instructions::unpack(&ix(10, &p)).is_err());
+}
+
+#[test]
+fn unsupported_version() {
+    let mut data = ix(5, &[]);
+    data[0] = 1;
     assert!(instructions::unpack(&data).is_err());
 }
 
+#[test]
+fn unknown_discriminator() {
+    assert!(instructions::unpack(&ix(99, &[])).is_err());
+}
+
 #[test]
 fn too_short() {
     assert!(instructions::unpack(&[0u8, 0]).is_err());
     assert!(instructions::unpack(&[]).is_err());
+    assert!(instructions::unpack(&ix(10, &[0u8; 10])).is_err());
 }
@@ -0,0 +1,231 @@
+use solana_program::pubkey::Pubkey;
+use substreams_solana_idls::serum::state::{self, *};
+
+fn frame(body: &[u8]) -> Vec<u8> {
+    let mut data = HEAD_PADDING.to_vec();
+    data.extend_from_slice(body);
+    data.extend_from_slice(TAIL_PADDING);
+    data
+}
+
+fn key(byte: u8) -> [u8; 32] {
+    [byte; 32]
+}
+
+fn event(flags: u8, owner_slot: u8, released: u64, paid: u64, fee: u64, order_id: u128, client_order_id: u64) -> Vec<u8> {
+    let mut e = vec![flags, owner_slot, 1, 0, 0, 0, 0, 0];
+    e.extend_from_slice(&released.to_le_bytes());
+    e.extend_from_slice(&paid.to_le_bytes());
+    e.extend_from_slice(&fee.to_le_bytes());
+    e.extend_from_slice(&order_id.to_le_bytes());
+    e.extend_from_slice(&key(9));
+    e.extend_from_slice(&client_order_id.to_le_bytes());
+    assert_eq!(e.len(), 88);
+    e
+}
+
+fn queue(flags: u64, head: u64, count: u64, seq_num: u64, slots: &[Vec<u8>]) -> Vec<u8> {
+    let mut body = Vec::new();
+    for v in [flags, head, count, seq_num] {
+        body.extend_from_slice(&v.to_le_bytes());
+    }
+    for slot in slots {
+        body.extend_from_slice(slot);
+    }
+    frame(&body)
+}
+
+#[test]
+fn parse_market_state() {
+    let mut body = Vec::new();
+    body.extend_from_slice(&(AccountFlags::INITIALIZED | AccountFlags::MARKET).to_le_bytes());
+    body.extend_from_slice(&key(1)); // own_address
+    body.extend_from_slice(&2u64.to_le_bytes()); // vault_signer_nonce
+    body.extend_from_slice(&key(3)); // coin_mint
+    body.extend_from_slice(&key(4)); // pc_mint
+    body.extend_from_slice(&key(5)); // coin_vault
+    body.extend_from_slice(&[0u8; 16]);
+    body.extend_from_slice(&key(6)); // pc_vault
+    body.extend_from_slice(&[0u8; 24]);
+    for k in [7, 8, 9, 10] {
+        body.extend_from_slice(&key(k)); // req_q, event_q, bids, asks
+    }
+    for v in [100u64, 10, 22, 0] {
+        body.extend_from_slice(&v.to_le_bytes());
+    }
+    let data = frame(&body);
+    assert_eq!(data.len(), state::MARKET_STATE_LEN);
+
+    let SerumAccount::Market(market) = state::unpack(&data).unwrap() else {
+        panic!("expected a market")
+    };
+    assert_eq!(market.coin_mint, Pubkey::new_from_array(key(3)));
+    assert_eq!(market.event_q, Pubkey::new_from_array(key(8)));
+    assert_eq!(market.asks, Pubkey::new_from_array(key(10)));
+    assert_eq!(market.coin_lot_size, 100);
+    assert_eq!(market.fee_rate_bps, 22);
+    assert_eq!(market.authorities, None);
+}
+
+#[test]
+fn parse_open_orders() {
+    let mut body = Vec::new();
+    body.extend_from_slice(&(AccountFlags::INITIALIZED | AccountFlags::OPEN_ORDERS).to_le_bytes());
+    body.extend_from_slice(&key(1));
+    body.extend_from_slice(&key(2));
+    for v in [1u64, 2, 3, 4] {
+        body.extend_from_slice(&v.to_le_bytes());
+    }
+    body.extend_from_slice(&(!0b101u128).to_le_bytes()); // slots 0 and 2 in use
+    body.extend_from_slice(&0b001u128.to_le_bytes()); // slot 0 is a bid
+    for i in 0..128u128 {
+        body.extend_from_slice(&(i + 1000).to_le_bytes());
+    }
+    for i in 0..128u64 {
+        body.extend_from_slice(&i.to_le_bytes());
+    }
+    body.extend_from_slice(&0u64.to_le_bytes());
+    let data = frame(&body);
+    assert_eq!(data.len(), state::OPEN_ORDERS_LEN);
+
+    let open_orders = OpenOrders::try_from(data.as_slice()).unwrap();
+    assert_eq!(open_orders.owner, Pubkey::new_from_array(key(2)));
+    let active: Vec<_> = open_orders.active_orders().collect();
+    assert_eq!(
+        active,
+        vec![
+            OpenOrder {
+                slot: 0,
+                side: Side::Bid,
+                order_id: 1000,
+                client_order_id: 0
+            },
+            OpenOrder {
+                slot: 2,
+                side: Side::Ask,
+                order_id: 1002,
+                client_order_id: 2
+            },
+        ]
+    );
+}
+
+#[test]
+fn parse_event_queue_wraps_around() {
+    let fill = event(0b1101, 3, 50, 7_500, 4, 77, 42); // fill, bid, maker
+    let out = event(0b10010, 4, 10, 0, 0, 78, 0); // out, ask, release funds
+    let data = queue(AccountFlags::INITIALIZED | AccountFlags::EVENT_QUEUE, 2, 2, 120, &[out, vec![0u8; 88], fill]);
+
+    let queue = EventQueue::try_from(data.as_slice()).unwrap();
+    assert_eq!(queue.capacity, 3);
+    assert_eq!(queue.header.first_seq_num(), 118);
+    assert_eq!(
+        queue.events,
+        vec![
+            Event::Fill(FillEvent {
+                side: Side::Bid,
+                maker: true,
+                native_qty_paid: 7_500,
+                native_qty_received: 50,
+                native_fee_or_rebate: 4,
+                order_id: 77,
+                owner: Pubkey::new_from_array(key(9)),
+                owner_slot: 3,
+                fee_tier: 1,
+                client_order_id: 42,
+            }),
+            Event::Out(OutEvent {
+                side: Side::Ask,
+                release_funds: true,
+                native_qty_unlocked: 10,
+                native_qty_still_locked: 0,
+                order_id: 78,
+                owner: Pubkey::new_from_array(key(9)),
+                owner_slot: 4,
+                client_order_id: 0,
+            }),
+        ]
+    );
+}
+
+#[test]
+fn parse_request_queue() {
+    let mut request = vec![0b1001, 5, 0, 1, 0, 0, 0, 0]; // new order, post only, cancel provide
+    request.extend_from_slice(&20u64.to_le_bytes());
+    request.extend_from_slice(&0u64.to_le_bytes());
+    request.extend_from_slice(&99u128.to_le_bytes());
+    request.extend_from_slice(&key(9));
+    request.extend_from_slice(&7u64.to_le_bytes());
+    let data = queue(AccountFlags::INITIALIZED | AccountFlags::REQUEST_QUEUE, 0, 1, 1, &[request]);
+
+    let SerumAccount::RequestQueue(queue) = state::unpack(&data).unwrap() else {
+        panic!("expected a request queue")
+    };
+    assert_eq!(
+        queue.requests,
+        vec![Request::NewOrder(NewOrderRequest {
+            side: Side::Ask,
+            order_type: OrderType::PostOnly,
+            self_trade_behavior: SelfTradeBehavior::CancelProvide,
+            owner_slot: 5,
+            fee_tier: 0,
+            order_id: 99,
+            max_coin_qty: 20,
+            native_pc_qty_locked: 0,
+            owner: Pubkey::new_from_array(key(9)),
+            client_order_id: 7,
+        })]
+    );
+}
+
+fn leaf(price: u64, seq: u64, quantity: u64) -> Vec<u8> {
+    let mut node = 2u32.to_le_bytes().to_vec();
+    node.extend_from_slice(&[1, 0, 0, 0]);
+    node.extend_from_slice(&((u128::from(price) << 64) | u128::from(seq)).to_le_bytes());
+    node.extend_from_slice(&key(9));
+    node.extend_from_slice(&quantity.to_le_bytes());
+    node.extend_from_slice(&0u64.to_le_bytes());
+    node
+}
+
+#[test]
+fn parse_slab_in_key_order() {
+    let mut body = Vec::new();
+    body.extend_from_slice(&(AccountFlags::INITIALIZED | AccountFlags::ASKS).to_le_bytes());
+    body.extend_from_slice(&3u64.to_le_bytes()); // bump_index
+    body.extend_from_slice(&0u64.to_le_bytes()); // free_list_len
+    body.extend_from_slice(&0u32.to_le_bytes()); // free_list_head
+    body.extend_from_slice(&2u32.to_le_bytes()); // root_node
+    body.extend_from_slice(&2u64.to_le_bytes()); // leaf_count
+    body.extend_from_slice(&leaf(105, 1, 3)); // node 0
+    body.extend_from_slice(&leaf(101, 2, 4)); // node 1
+    let mut inner = 1u32.to_le_bytes().to_vec();
+    inner.extend_from_slice(&[0u8; 20]);
+    inner.extend_from_slice(&1u32.to_le_bytes()); // lower child
+    inner.extend_from_slice(&0u32.to_le_bytes()); // upper child
+    inner.extend_from_slice(&[0u8; 40]);
+    body.extend_from_slice(&inner); // node 2
+    let data = frame(&body);
+
+    let SerumAccount::Asks(asks) = state::unpack(&data).unwrap() else {
+        panic!("expected asks")
+    };
+    let prices: Vec<_> = asks.leaves.iter().map(|leaf| (leaf.price(), leaf.quantity)).collect();
+    assert_eq!(prices, vec![(101, 4), (105, 3)]);
+    assert!(Slab::bids(&data).is_err());
+}
+
+#[test]
+fn rejects_bad_framing() {
+    let mut data = queue(AccountFlags::INITIALIZED | AccountFlags::EVENT_QUEUE, 0, 0, 0, &[]);
+    assert!(state::unpack(&data).is_ok());
+    data[0] = b'S';
+    assert!(state::unpack(&data).is_err());
+    assert!(state::unpack(&[0u8; 4]).is_err());
+}
+
+#[test]
+fn rejects_count_over_capacity() {
+    let data = queue(AccountFlags::INITIALIZED | AccountFlags::EVENT_QUEUE, 0, 2, 2, &[vec![0u8; 88]]);
+    assert!(EventQueue::try_from(data.as_slice()).is_err());
+}
@@ -1,2 +1,4 @@
+#[path = "spl/accounts.rs"]
+mod spl_accounts;
 #[path = "spl/instructions.rs"]
 mod spl_instructions;
@@ -0,0 +1,252 @@
+// ---- token ----
+
+use solana_program::pubkey::Pubkey;
+use substreams_solana_idls::common::ParseError;
+use substreams_solana_idls::spl::token::accounts::{self as token_accounts, AccountState, AccountView, MintView};
+use substreams_solana_idls::spl::token_2022::accounts::{self as accounts_2022, AccountType, Extension};
+
+fn mint_data() -> Vec<u8> {
+    let mut data = Vec::with_capacity(token_accounts::MINT_LEN);
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(&[1u8; 32]);
+    data.extend_from_slice(&1_000_000_000u64.to_le_bytes());
+    data.push(6);
+    data.push(1);
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&[0u8; 32]);
+    data
+}
+
+fn account_data() -> Vec<u8> {
+    let mut data = Vec::with_capacity(token_accounts::ACCOUNT_LEN);
+    data.extend_from_slice(&[1u8; 32]);
+    data.extend_from_slice(&[2u8; 32]);
+    data.extend_from_slice(&500u64.to_le_bytes());
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(&[3u8; 32]);
+    data.push(2); // Frozen
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(&2_039_280u64.to_le_bytes());
+    data.extend_from_slice(&100u64.to_le_bytes());
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&[0u8; 32]);
+    data
+}
+
+#[test]
+fn token_mint() {
+    let data = mint_data();
+    let mint = token_accounts::unpack_mint(&data).unwrap();
+    assert_eq!(
+        mint,
+        token_accounts::Mint {
+            mint_authority: Some(Pubkey::new_from_array([1u8; 32])),
+            supply: 1_000_000_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: None,
+        }
+    );
+
+    let view = MintView::new(&data).unwrap();
+    assert_eq!(view.supply(), 1_000_000_000);
+    assert_eq!(view.freeze_authority(), None);
+}
+
+#[test]
+fn token_account() {
+    let data = account_data();
+    let account = token_accounts::unpack_account(&data).unwrap();
+    assert_eq!(
+        account,
+        token_accounts::Account {
+            mint: Pubkey::new_from_array([1u8; 32]),
+            owner: Pubkey::new_from_array([2u8; 32]),
+            amount: 500,
+            delegate: Some(Pubkey::new_from_array([3u8; 32])),
+            state: AccountState::Frozen,
+            is_native: Some(2_039_280),
+            delegated_amount: 100,
+            close_authority: None,
+        }
+    );
+
+    let view = AccountView::new(&data).unwrap();
+    assert_eq!(view.owner(), Pubkey::new_from_array([2u8; 32]));
+    assert_eq!(view.state(), AccountState::Frozen);
+}
+
+#[test]
+fn token_invalid_layouts() {
+    assert!(matches!(
+        token_accounts::unpack_mint(&[0u8; 81]),
+        Err(ParseError::InvalidLength { expected: 82, got: 81 })
+    ));
+    assert!(matches!(
+        token_accounts::unpack_account(&[0u8; 170]),
+        Err(ParseError::InvalidLength { expected: 165, got: 170 })
+    ));
+
+    let mut data = mint_data();
+    data[0] = 2; // COption tag
+    assert!(token_accounts::unpack_mint(&data).is_err());
+
+    let mut data = account_data();
+    data[108] = 3; // AccountState
+    assert!(token_accounts::unpack_account(&data).is_err());
+}
+
+// ---- token_2022 ----
+
+fn push_tlv(data: &mut Vec<u8>, extension_type: u16, value: &[u8]) {
+    data.extend_from_slice(&extension_type.to_le_bytes());
+    data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    data.extend_from_slice(value);
+}
+
+fn borsh_string(out: &mut Vec<u8>, value: &str) {
+    out.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    out.extend_from_slice(value.as_bytes());
+}
+
+fn mint_with_extensions() -> Vec<u8> {
+    let mut data = vec![0u8; accounts_2022::BASE_ACCOUNT_LENGTH];
+    data.push(1); // AccountType::Mint
+
+    // MetadataPointer: no authority, points at the mint itself
+    let mut value = vec![0u8; 32];
+    value.extend_from_slice(&[1u8; 32]);
+    push_tlv(&mut data, 18, &value);
+
+    // TransferFeeConfig
+    let mut value = [2u8; 32].to_vec();
+    value.extend_from_slice(&[3u8; 32]);
+    value.extend_from_slice(&42u64.to_le_bytes());
+    for (epoch, bps) in [(500u64, 100u16), (510, 250)] {
+        value.extend_from_slice(&epoch.to_le_bytes());
+        value.extend_from_slice(&1_000_000u64.to_le_bytes());
+        value.extend_from_slice(&bps.to_le_bytes());
+    }
+    push_tlv(&mut data, 1, &value);
+
+    // TokenMetadata
+    let mut value = [4u8; 32].to_vec();
+    value.extend_from_slice(&[1u8; 32]);
+    borsh_string(&mut value, "Token");
+    borsh_string(&mut value, "TKN");
+    borsh_string(&mut value, "https://example.com/token.json");
+    value.extend_from_slice(&1u32.to_le_bytes());
+    borsh_string(&mut value, "color");
+    borsh_string(&mut value, "blue");
+    push_tlv(&mut data, 19, &value);
+
+    // ConfidentialTransferMint, kept raw
+    push_tlv(&mut data, 4, &[9u8; 65]);
+
+    // Realloc padding
+    data.extend_from_slice(&[0u8; 16]);
+    data
+}
+
+#[test]
+fn token_2022_mint_extensions() {
+    let data = mint_with_extensions();
+    assert_eq!(accounts_2022::unpack_account_type(&data).unwrap(), Some(AccountType::Mint));
+
+    let extensions = accounts_2022::unpack_extensions(&data).unwrap();
+    assert_eq!(extensions.len(), 4);
+    assert_eq!(
+        extensions[0],
+        Extension::MetadataPointer {
+            authority: None,
+            metadata_address: Some(Pubkey::new_from_array([1u8; 32])),
+        }
+    );
+    match &extensions[1] {
+        Extension::TransferFeeConfig(config) => {
+            assert_eq!(config.transfer_fee_config_authority, Some(Pubkey::new_from_array([2u8; 32])));
+            assert_eq!(config.withheld_amount, 42);
+            assert_eq!(config.older_transfer_fee.transfer_fee_basis_points, 100);
+            assert_eq!(config.newer_transfer_fee.epoch, 510);
+            assert_eq!(config.newer_transfer_fee.transfer_fee_basis_points, 250);
+        }
+        other => panic!("expected TransferFeeConfig, got {other:?}"),
+    }
+    match &extensions[2] {
+        Extension::TokenMetadata(metadata) => {
+            assert_eq!(metadata.update_authority, Some(Pubkey::new_from_array([4u8; 32])));
+            assert_eq!(metadata.mint, Pubkey::new_from_array([1u8; 32]));
+            assert_eq!(metadata.symbol, "TKN");
+            assert_eq!(metadata.uri, "https://example.com/token.json");
+            assert_eq!(metadata.additional_metadata, vec![("color".to_string(), "blue".to_string())]);
+        }
+        other => panic!("expected TokenMetadata, got {other:?}"),
+    }
+    assert_eq!(
+        extensions[3],
+        Extension::Unknown {
+            extension_type: 4,
+            data: vec![9u8; 65],
+        }
+    );
+}
+
+#[test]
+fn token_2022_base_state() {
+    // Without extensions, the layouts are the SPL Token ones.
+    assert_eq!(accounts_2022::unpack_mint(&mint_data()).unwrap().decimals, 6);
+    assert_eq!(accounts_2022::unpack_account(&account_data()).unwrap().amount, 500);
+
+    let mut data = mint_with_extensions();
+    data[..token_accounts::MINT_LEN].copy_from_slice(&mint_data());
+    let mint = accounts_2022::unpack_mint(&data).unwrap();
+    assert_eq!(mint.mint_authority, Some(Pubkey::new_from_array([1u8; 32])));
+    assert_eq!(accounts_2022::mint_view(&data).unwrap().supply(), 1_000_000_000);
+
+    // A mint is not an account.
+    assert!(accounts_2022::unpack_account(&data).is_err());
+}
+
+#[test]
+fn token_2022_account_extensions() {
+    let mut data = account_data();
+    data.push(2); // AccountType::Account
+    push_tlv(&mut data, 7, &[]);
+    push_tlv(&mut data, 2, &7u64.to_le_bytes());
+    push_tlv(&mut data, 6, &[2]);
+    push_tlv(&mut data, 15, &[0]);
+
+    assert_eq!(accounts_2022::unpack_account_type(&data).unwrap(), Some(AccountType::Account));
+    assert_eq!(accounts_2022::account_view(&data).unwrap().state(), AccountState::Frozen);
+    assert_eq!(
+        accounts_2022::unpack_extensions(&data).unwrap(),
+        vec![
+            Extension::ImmutableOwner,
+            Extension::TransferFeeAmount { withheld_amount: 7 },
+            Extension::DefaultAccountState { state: AccountState::Frozen },
+            Extension::TransferHookAccount { transferring: false },
+        ]
+    );
+}
+
+#[test]
+fn token_2022_no_extensions() {
+    assert!(accounts_2022::unpack_extensions(&[0u8; 82]).unwrap().is_empty());
+    assert!(accounts_2022::unpack_extensions(&[0u8; 165]).unwrap().is_empty());
+    assert!(accounts_2022::unpack_extensions(&[0u8; accounts_2022::MULTISIG_LENGTH]).unwrap().is_empty());
+    assert_eq!(accounts_2022::unpack_account_type(&[0u8; 165]).unwrap(), None);
+}
+
+#[test]
+fn token_2022_truncated_extension() {
+    let mut data = vec![0u8; accounts_2022::BASE_ACCOUNT_LENGTH];
+    data.push(1);
+    data.extend_from_slice(&18u16.to_le_bytes());
+    data.extend_from_slice(&64u16.to_le_bytes());
+    data.extend_from_slice(&[1u8; 10]);
+    assert!(accounts_2022::unpack_extensions(&data).is_err());
+
+    let mut data = vec![0u8; accounts_2022::BASE_ACCOUNT_LENGTH];
+    data.push(7);
+    assert!(accounts_2022::unpack_account_type(&data).is_err());
+}
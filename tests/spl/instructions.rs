@@ -1,5 +1,6 @@
 // ---- token ----
 
+use solana_program::pubkey::Pubkey;
 use substreams_solana_idls::common::ParseError;
 
 #[test]
@@ -39,6 +40,93 @@ fn token_mint_to() {
     assert_eq!(ix, substreams_solana_idls::spl::token::instructions::TokenInstruction::MintTo { amount: 500 });
 }
 
+#[test]
+fn token_initialize_mint() {
+    let mint_authority = [1u8; 32];
+    let freeze_authority = [2u8; 32];
+    let mut data = vec![substreams_solana_idls::spl::token::instructions::INITIALIZE_MINT, 6];
+    data.extend_from_slice(&mint_authority);
+    data.push(1);
+    data.extend_from_slice(&freeze_authority);
+    let ix = substreams_solana_idls::spl::token::instructions::unpack(&data).unwrap();
+    assert_eq!(
+        ix,
+        substreams_solana_idls::spl::token::instructions::TokenInstruction::InitializeMint {
+            decimals: 6,
+            mint_authority: Pubkey::new_from_array(mint_authority),
+            freeze_authority: Some(Pubkey::new_from_array(freeze_authority)),
+        }
+    );
+}
+
+#[test]
+fn token_initialize_mint2_without_freeze_authority() {
+    let mut data = vec![substreams_solana_idls::spl::token::instructions::INITIALIZE_MINT2, 9];
+    data.extend_from_slice(&[1u8; 32]);
+    data.push(0);
+    let ix = substreams_solana_idls::spl::token::instructions::unpack(&data).unwrap();
+    assert_eq!(
+        ix,
+        substreams_solana_idls::spl::token::instructions::TokenInstruction::InitializeMint2 {
+            decimals: 9,
+            mint_authority: Pubkey::new_from_array([1u8; 32]),
+            freeze_authority: None,
+        }
+    );
+}
+
+#[test]
+fn token_initialize_mint_truncated_freeze_authority() {
+    let mut data = vec![substreams_solana_idls::spl::token::instructions::INITIALIZE_MINT, 6];
+    data.extend_from_slice(&[1u8; 32]);
+    data.push(1);
+    data.extend_from_slice(&[2u8; 8]);
+    assert!(matches!(
+        substreams_solana_idls::spl::token::instructions::unpack(&data),
+        Err(ParseError::TooShort(43))
+    ));
+}
+
+#[test]
+fn token_initialize_account3() {
+    let mut data = vec![substreams_solana_idls::spl::token::instructions::INITIALIZE_ACCOUNT3];
+    data.extend_from_slice(&[3u8; 32]);
+    let ix = substreams_solana_idls::spl::token::instructions::unpack(&data).unwrap();
+    assert_eq!(
+        ix,
+        substreams_solana_idls::spl::token::instructions::TokenInstruction::InitializeAccount3 {
+            owner: Pubkey::new_from_array([3u8; 32]),
+        }
+    );
+}
+
+#[test]
+fn token_set_authority() {
+    use substreams_solana_idls::spl::token::instructions::{AuthorityType, TokenInstruction, SET_AUTHORITY};
+
+    let ix = substreams_solana_idls::spl::token::instructions::unpack(&[SET_AUTHORITY, 0, 0]).unwrap();
+    assert_eq!(
+        ix,
+        TokenInstruction::SetAuthority {
+            authority_type: AuthorityType::MintTokens,
+            new_authority: None,
+        }
+    );
+
+    let mut data = vec![SET_AUTHORITY, 2, 1];
+    data.extend_from_slice(&[4u8; 32]);
+    let ix = substreams_solana_idls::spl::token::instructions::unpack(&data).unwrap();
+    assert_eq!(
+        ix,
+        TokenInstruction::SetAuthority {
+            authority_type: AuthorityType::AccountOwner,
+            new_authority: Some(Pubkey::new_from_array([4u8; 32])),
+        }
+    );
+
+    assert!(substreams_solana_idls::spl::token::instructions::unpack(&[SET_AUTHORITY, 9, 0]).is_err());
+}
+
 // ---- token_2022 ----
 
 #[test]
@@ -77,6 +165,213 @@ fn token_2022_transfer_checked() {
     );
 }
 
+#[test]
+fn token_2022_set_authority_extension_type() {
+    use substreams_solana_idls::spl::token_2022::instructions::{AuthorityType, Token2022Instruction, SET_AUTHORITY};
+
+    let mut data = vec![SET_AUTHORITY, 12, 1];
+    data.extend_from_slice(&[5u8; 32]);
+    let ix = substreams_solana_idls::spl::token_2022::instructions::unpack(&data).unwrap();
+    assert_eq!(
+        ix,
+        Token2022Instruction::SetAuthority {
+            authority_type: AuthorityType::MetadataPointer,
+            new_authority: Some(Pubkey::new_from_array([5u8; 32])),
+        }
+    );
+}
+
+#[test]
+fn token_2022_transfer_checked_with_fee() {
+    use substreams_solana_idls::spl::token_2022::extensions::TransferFeeInstruction;
+    use substreams_solana_idls::spl::token_2022::instructions::{Token2022Instruction, TRANSFER_FEE_EXTENSION};
+
+    let mut data = vec![TRANSFER_FEE_EXTENSION, 1];
+    data.extend_from_slice(&1_000_000u64.to_le_bytes());
+    data.push(6);
+    data.extend_from_slice(&5_000u64.to_le_bytes());
+    let ix = substreams_solana_idls::spl::token_2022::instructions::unpack(&data).unwrap();
+    assert_eq!(
+        ix,
+        Token2022Instruction::TransferFeeExtension(TransferFeeInstruction::TransferCheckedWithFee {
+            amount: 1_000_000,
+            decimals: 6,
+            fee: 5_000,
+        })
+    );
+}
+
+#[test]
+fn token_2022_initialize_transfer_fee_config() {
+    use substreams_solana_idls::spl::token_2022::extensions::TransferFeeInstruction;
+    use substreams_solana_idls::spl::token_2022::instructions::{Token2022Instruction, TRANSFER_FEE_EXTENSION};
+
+    let mut data = vec![TRANSFER_FEE_EXTENSION, 0, 1];
+    data.extend_from_slice(&[6u8; 32]);
+    data.push(0);
+    data.extend_from_slice(&250u16.to_le_bytes());
+    data.extend_from_slice(&u64::MAX.to_le_bytes());
+    let ix = substreams_solana_idls::spl::token_2022::instructions::unpack(&data).unwrap();
+    assert_eq!(
+        ix,
+        Token2022Instruction::TransferFeeExtension(TransferFeeInstruction::InitializeTransferFeeConfig {
+            transfer_fee_config_authority: Some(Pubkey::new_from_array([6u8; 32])),
+            withdraw_withheld_authority: None,
+            transfer_fee_basis_points: 250,
+            maximum_fee: u64::MAX,
+        })
+    );
+
+    let mut data = vec![TRANSFER_FEE_EXTENSION, 5];
+    data.extend_from_slice(&100u16.to_le_bytes());
+    assert!(matches!(
+        substreams_solana_idls::spl::token_2022::instructions::unpack(&data),
+        Err(ParseError::TooShort(4))
+    ));
+}
+
+#[test]
+fn token_2022_interest_bearing_update_rate() {
+    use substreams_solana_idls::spl::token_2022::extensions::InterestBearingMintInstruction;
+    use substreams_solana_idls::spl::token_2022::instructions::{Token2022Instruction, INTEREST_BEARING_MINT_EXTENSION};
+
+    let mut data = vec![INTEREST_BEARING_MINT_EXTENSION, 1];
+    data.extend_from_slice(&(-150i16).to_le_bytes());
+    let ix = substreams_solana_idls::spl::token_2022::instructions::unpack(&data).unwrap();
+    assert_eq!(
+        ix,
+        Token2022Instruction::InterestBearingMintExtension(InterestBearingMintInstruction::UpdateRate { rate: -150 })
+    );
+}
+
+#[test]
+fn token_2022_transfer_hook_initialize() {
+    use substreams_solana_idls::spl::token_2022::extensions::TransferHookInstruction;
+    use substreams_solana_idls::spl::token_2022::instructions::{Token2022Instruction, TRANSFER_HOOK_EXTENSION};
+
+    let mut data = vec![TRANSFER_HOOK_EXTENSION, 0];
+    data.extend_from_slice(&[0u8; 32]);
+    data.extend_from_slice(&[7u8; 32]);
+    let ix = substreams_solana_idls::spl::token_2022::instructions::unpack(&data).unwrap();
+    assert_eq!(
+        ix,
+        Token2022Instruction::TransferHookExtension(TransferHookInstruction::Initialize {
+            authority: None,
+            program_id: Some(Pubkey::new_from_array([7u8; 32])),
+        })
+    );
+}
+
+#[test]
+fn token_2022_metadata_pointer_initialize() {
+    use substreams_solana_idls::spl::token_2022::extensions::PointerInstruction;
+    use substreams_solana_idls::spl::token_2022::instructions::{Token2022Instruction, METADATA_POINTER_EXTENSION};
+
+    let mut data = vec![METADATA_POINTER_EXTENSION, 0];
+    data.extend_from_slice(&[8u8; 32]);
+    data.extend_from_slice(&[9u8; 32]);
+    let ix = substreams_solana_idls::spl::token_2022::instructions::unpack(&data).unwrap();
+    assert_eq!(
+        ix,
+        Token2022Instruction::MetadataPointerExtension(PointerInstruction::Initialize {
+            authority: Some(Pubkey::new_from_array([8u8; 32])),
+            address: Some(Pubkey::new_from_array([9u8; 32])),
+        })
+    );
+}
+
+#[test]
+fn token_2022_get_account_data_size() {
+    use substreams_solana_idls::spl::token_2022::instructions::{Token2022Instruction, GET_ACCOUNT_DATA_SIZE};
+
+    let ix = substreams_solana_idls::spl::token_2022::instructions::unpack(&[GET_ACCOUNT_DATA_SIZE, 1, 0, 18, 0]).unwrap();
+    assert_eq!(ix, Token2022Instruction::GetAccountDataSize { extension_types: vec![1, 18] });
+    assert!(substreams_solana_idls::spl::token_2022::instructions::unpack(&[GET_ACCOUNT_DATA_SIZE, 1]).is_err());
+}
+
+#[test]
+fn token_2022_token_metadata_initialize() {
+    use substreams_solana_idls::spl::token_2022::extensions::{TokenMetadataInstruction, TOKEN_METADATA_INITIALIZE};
+    use substreams_solana_idls::spl::token_2022::instructions::Token2022Instruction;
+
+    let mut data = TOKEN_METADATA_INITIALIZE.to_vec();
+    for field in ["Token", "TKN", "https://example.com/token.json"] {
+        data.extend_from_slice(&(field.len() as u32).to_le_bytes());
+        data.extend_from_slice(field.as_bytes());
+    }
+    let ix = substreams_solana_idls::spl::token_2022::instructions::unpack(&data).unwrap();
+    assert_eq!(
+        ix,
+        Token2022Instruction::TokenMetadataExtension(TokenMetadataInstruction::Initialize {
+            name: "Token".to_string(),
+            symbol: "TKN".to_string(),
+            uri: "https://example.com/token.json".to_string(),
+        })
+    );
+}
+
+#[test]
+fn token_2022_token_metadata_update_field_and_remove_key() {
+    use substreams_solana_idls::spl::token_2022::extensions::{Field, TokenMetadataInstruction, TOKEN_METADATA_REMOVE_KEY, TOKEN_METADATA_UPDATE_FIELD};
+    use substreams_solana_idls::spl::token_2022::instructions::Token2022Instruction;
+
+    let mut data = TOKEN_METADATA_UPDATE_FIELD.to_vec();
+    data.push(3);
+    data.extend_from_slice(&5u32.to_le_bytes());
+    data.extend_from_slice(b"color");
+    data.extend_from_slice(&4u32.to_le_bytes());
+    data.extend_from_slice(b"blue");
+    let ix = substreams_solana_idls::spl::token_2022::instructions::unpack(&data).unwrap();
+    assert_eq!(
+        ix,
+        Token2022Instruction::TokenMetadataExtension(TokenMetadataInstruction::UpdateField {
+            field: Field::Key("color".to_string()),
+            value: "blue".to_string(),
+        })
+    );
+
+    let mut data = TOKEN_METADATA_REMOVE_KEY.to_vec();
+    data.push(1);
+    data.extend_from_slice(&5u32.to_le_bytes());
+    data.extend_from_slice(b"color");
+    let ix = substreams_solana_idls::spl::token_2022::instructions::unpack(&data).unwrap();
+    assert_eq!(
+        ix,
+        Token2022Instruction::TokenMetadataExtension(TokenMetadataInstruction::RemoveKey {
+            idempotent: true,
+            key: "color".to_string(),
+        })
+    );
+}
+
+#[test]
+fn token_2022_token_group_initialize() {
+    use substreams_solana_idls::spl::token_2022::extensions::{TokenGroupInstruction, TOKEN_GROUP_INITIALIZE_GROUP};
+    use substreams_solana_idls::spl::token_2022::instructions::Token2022Instruction;
+
+    let mut data = TOKEN_GROUP_INITIALIZE_GROUP.to_vec();
+    data.extend_from_slice(&[1u8; 32]);
+    data.extend_from_slice(&100u64.to_le_bytes());
+    let ix = substreams_solana_idls::spl::token_2022::instructions::unpack(&data).unwrap();
+    assert_eq!(
+        ix,
+        Token2022Instruction::TokenGroupExtension(TokenGroupInstruction::InitializeGroup {
+            update_authority: Some(Pubkey::new_from_array([1u8; 32])),
+            max_size: 100,
+        })
+    );
+}
+
+#[test]
+fn token_2022_unknown_extension_sub_instruction() {
+    use substreams_solana_idls::spl::token_2022::instructions::CPI_GUARD_EXTENSION;
+
+    assert!(matches!(
+        substreams_solana_idls::spl::token_2022::instructions::unpack(&[CPI_GUARD_EXTENSION, 7]),
+        Err(ParseError::SplUnknown(7))
+    ));
+}
+
 // ---- token_swap ----
 
 #[test]
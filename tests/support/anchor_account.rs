@@ -0,0 +1,23 @@
+//! Builders for Anchor account data, shared by the account-state tests.
+#![allow(dead_code)]
+
+use borsh::{to_vec, BorshSerialize};
+
+/// `value` serialized behind its account discriminator.
+pub fn account<T: BorshSerialize>(discriminator: [u8; 8], value: &T) -> Vec<u8> {
+    let mut data = discriminator.to_vec();
+    data.extend(to_vec(value).unwrap());
+    data
+}
+
+/// A zero-filled account of `len` bytes, discriminator included.
+pub fn zeroed(discriminator: [u8; 8], len: usize) -> Vec<u8> {
+    let mut data = vec![0u8; len];
+    data[..8].copy_from_slice(&discriminator);
+    data
+}
+
+/// Length of `value` as an account, discriminator included.
+pub fn encoded_len<T: BorshSerialize>(value: &T) -> usize {
+    8 + to_vec(value).unwrap().len()
+}